@@ -0,0 +1,172 @@
+//! Command-line tool of [Iftree](https://docs.rs/iftree).
+//!
+//! Previews what the attribute macro `iftree::include_file_tree` generates for a
+//! configuration, without rebuilding your crate.
+
+use std::env;
+use std::process;
+
+const USAGE: &str = "\
+Usage: cargo iftree [--manifest-dir <folder>] [--type <definition>] <configuration>
+
+Previews the selected files, the forest with sanitized identifiers, identifier
+collisions, and the generated code for a configuration like
+\"paths = '/my_assets/**'\".
+
+Options:
+  --manifest-dir <folder>  Folder of \"Cargo.toml\" [default: current folder]
+  --type <definition>      Asset type [default: \"pub struct Asset { relative_path: &'static str }\"]
+  --help                   Print this help";
+
+const DEFAULT_TYPE: &str = "pub struct Asset { relative_path: &'static str }";
+
+#[derive(Debug, Eq, PartialEq)]
+enum Command {
+    Help,
+    Preview(Arguments),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Arguments {
+    configuration: String,
+    manifest_dir: Option<String>,
+    type_: String,
+}
+
+fn main() -> process::ExitCode {
+    match parse_command(env::args().skip(1)) {
+        Err(error) => {
+            eprintln!("{error}\n\n{USAGE}");
+            process::ExitCode::FAILURE
+        }
+
+        Ok(Command::Help) => {
+            println!("{USAGE}");
+            process::ExitCode::SUCCESS
+        }
+
+        Ok(Command::Preview(arguments)) => match preview(arguments) {
+            Err(error) => {
+                eprintln!("Error: {error}");
+                process::ExitCode::FAILURE
+            }
+
+            Ok(report) => {
+                print!("{report}");
+                process::ExitCode::SUCCESS
+            }
+        },
+    }
+}
+
+fn parse_command(arguments: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut arguments = arguments.peekable();
+    // As a Cargo subcommand, the subcommand name is passed first.
+    arguments.next_if(|argument| argument == "iftree");
+
+    let mut configuration = None;
+    let mut manifest_dir = None;
+    let mut type_ = None;
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--help" | "-h" => return Ok(Command::Help),
+            "--manifest-dir" => manifest_dir = Some(get_value(&argument, arguments.next())?),
+            "--type" => type_ = Some(get_value(&argument, arguments.next())?),
+            _ if argument.starts_with("--") => return Err(format!("Unknown option: {argument}")),
+            _ if configuration.is_none() => configuration = Some(argument),
+            _ => return Err(format!("Unexpected argument: {argument}")),
+        }
+    }
+
+    match configuration {
+        None => Err("Missing configuration".into()),
+        Some(configuration) => Ok(Command::Preview(Arguments {
+            configuration,
+            manifest_dir,
+            type_: type_.unwrap_or_else(|| DEFAULT_TYPE.into()),
+        })),
+    }
+}
+
+fn get_value(option: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("Missing value of option {option}"))
+}
+
+fn preview(arguments: Arguments) -> Result<String, iftree_codegen::Error> {
+    // The default root folder variable is not set outside of Cargo builds.
+    match arguments.manifest_dir {
+        None => {
+            if env::var_os("CARGO_MANIFEST_DIR").is_none() {
+                if let Ok(folder) = env::current_dir() {
+                    env::set_var("CARGO_MANIFEST_DIR", folder);
+                }
+            }
+        }
+        Some(folder) => env::set_var("CARGO_MANIFEST_DIR", folder),
+    }
+
+    iftree_codegen::Builder::from_toml(&arguments.configuration, &arguments.type_).preview()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(arguments: &[&str]) -> Result<Command, String> {
+        parse_command(arguments.iter().map(|argument| String::from(*argument)))
+    }
+
+    #[test]
+    fn handles_cargo_subcommand() {
+        let actual = parse(&[
+            "iftree",
+            "--manifest-dir",
+            "my_crate",
+            "paths = '/my_assets/**'",
+        ]);
+
+        let expected = Ok(Command::Preview(Arguments {
+            configuration: "paths = '/my_assets/**'".into(),
+            manifest_dir: Some("my_crate".into()),
+            type_: DEFAULT_TYPE.into(),
+        }));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_type() {
+        let actual = parse(&["--type", "pub struct A;", "paths = '/a'"]);
+
+        let expected = Ok(Command::Preview(Arguments {
+            configuration: "paths = '/a'".into(),
+            manifest_dir: None,
+            type_: "pub struct A;".into(),
+        }));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_help() {
+        let actual = parse(&["iftree", "--help"]);
+
+        let expected = Ok(Command::Help);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_configuration_it_errs() {
+        let actual = parse(&["iftree"]);
+
+        let expected = Err("Missing configuration".into());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_missing_option_value_it_errs() {
+        let actual = parse(&["--type"]);
+
+        let expected = Err("Missing value of option --type".into());
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,184 @@
+use crate::go;
+use crate::list_files;
+use crate::model;
+use crate::preview;
+use crate::print;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path;
+
+/// Generates the same code as the attribute macro `include_file_tree`, but ahead
+/// of time, typically in a build script (`build.rs`).
+///
+/// The generated code is meant to be included with `include!`.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    configuration: String,
+    asset_type: String,
+}
+
+/// An error of [`Builder`].
+#[derive(Debug)]
+pub struct Error(Source);
+
+#[derive(Debug)]
+enum Source {
+    Generation(model::Error),
+    Io(io::Error),
+    Syntax(syn::Error),
+}
+
+impl Builder {
+    /// Takes a configuration like the one of the attribute macro and the
+    /// definition of the asset type like `"pub struct Asset { … }"`.
+    pub fn from_toml(configuration: &str, asset_type: &str) -> Self {
+        Self {
+            configuration: configuration.into(),
+            asset_type: asset_type.into(),
+        }
+    }
+
+    /// Generates formatted code, including the asset type definition.
+    pub fn generate(&self) -> Result<String, Error> {
+        let (configuration, item, type_) = self.parse()?;
+        let code = go::main(configuration, item, type_).map_err(Source::Generation)?;
+        format(code)
+    }
+
+    /// Generates a human-readable report of the selected files, the forest with
+    /// sanitized identifiers, identifier collisions, and the formatted code.
+    pub fn preview(&self) -> Result<String, Error> {
+        let (configuration, item, type_) = self.parse()?;
+        let view = go::get_view(configuration, type_).map_err(Source::Generation)?;
+        let report = preview::main(&view);
+        let code = format(print::main(item, view))?;
+        Ok(format!("{report}\nGenerated code:\n{code}"))
+    }
+
+    /// Generates formatted code to the given file.
+    ///
+    /// For Cargo to rerun the build script on changes, this also prints
+    /// `cargo:rerun-if-changed` for each selected file and each folder walked in
+    /// full, as well as `cargo:rerun-if-env-changed` for the environment
+    /// variables that the configuration refers to.
+    pub fn generate_to(&self, path: impl AsRef<path::Path>) -> Result<(), Error> {
+        let (configuration, _, _) = self.parse()?;
+        let tracked_paths =
+            list_files::get_tracked_paths(&configuration).map_err(Source::Generation)?;
+
+        let code = self.generate()?;
+        fs::write(path, code).map_err(Source::Io)?;
+
+        for path in tracked_paths {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+        println!(
+            "cargo:rerun-if-env-changed={}",
+            configuration.root_folder_variable,
+        );
+        if let Some(obfuscation) = &configuration.obfuscation {
+            println!("cargo:rerun-if-env-changed={}", obfuscation.key_variable);
+        }
+        Ok(())
+    }
+
+    fn parse(
+        &self,
+    ) -> Result<
+        (
+            model::Configuration,
+            proc_macro2::TokenStream,
+            model::Type<()>,
+        ),
+        Error,
+    > {
+        let configuration = syn::LitStr::new(&self.configuration, proc_macro2::Span::call_site());
+        let configuration =
+            syn::parse2(quote::quote! { #configuration }).map_err(Source::Syntax)?;
+        let item =
+            syn::parse_str::<proc_macro2::TokenStream>(&self.asset_type).map_err(Source::Syntax)?;
+        let type_ = syn::parse2(item.clone()).map_err(Source::Syntax)?;
+        Ok((configuration, item, type_))
+    }
+}
+
+fn format(code: proc_macro2::TokenStream) -> Result<String, Error> {
+    let file = syn::parse2(code).map_err(Source::Syntax)?;
+    Ok(prettyplease::unparse(&file))
+}
+
+impl From<Source> for Error {
+    fn from(source: Source) -> Self {
+        Self(source)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Source::Generation(error) => write!(formatter, "{error}"),
+            Source::Io(error) => write!(formatter, "Unable to write generated code: {error}"),
+            Source::Syntax(error) => write!(formatter, "Unable to parse: {error}"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.0 {
+            Source::Generation(error) => Some(error),
+            Source::Io(error) => Some(error),
+            Source::Syntax(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = Builder::from_toml(
+            "paths = '/credits.md'\nbase_folder = '../examples/assets'",
+            "pub struct Asset { relative_path: &'static str }",
+        )
+        .generate()
+        .unwrap();
+
+        let actual = actual.starts_with(
+            r#"pub struct Asset {
+    relative_path: &'static str,
+}
+pub static ASSETS: [Asset; 1usize] = [
+    Asset {
+        relative_path: "credits.md",
+    },
+];
+"#,
+        );
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_invalid_configuration_it_errs() {
+        let actual = Builder::from_toml("paths = #", "pub struct Asset;").generate();
+
+        let actual = actual.unwrap_err().to_string();
+        assert!(actual.starts_with("Unable to parse: "));
+    }
+
+    #[test]
+    fn given_no_files_it_errs() {
+        let actual = Builder::from_toml(
+            "paths = '/credits.md'\nbase_folder = 'nonexistent'",
+            "pub struct Asset;",
+        )
+        .generate();
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+}
@@ -0,0 +1,21 @@
+/// Compresses contents with DEFLATE at the highest level, as they are only
+/// compressed once at compile time.
+pub fn main(contents: &[u8]) -> Vec<u8> {
+    miniz_oxide::deflate::compress_to_vec(contents, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let contents = b"abcabcabcabcabcabcabcabcabcabcabcabc";
+
+        let actual = main(contents);
+
+        assert!(actual.len() < contents.len());
+        let actual = miniz_oxide::inflate::decompress_to_vec(&actual).unwrap();
+        assert_eq!(actual, contents);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::model;
+
+/// Rejects folders whose files are scattered by the order of files if their
+/// index ranges are needed, which is for the function `all` of folder modules or
+/// for visitors with extended inputs.
+pub fn main(
+    folder_functions: bool,
+    visitors: &[model::Visitor],
+    forest: &model::Forest,
+) -> model::Result<()> {
+    for visitor in visitors {
+        let usage = match visitor {
+            model::Visitor::Identifiers if folder_functions => {
+                format!("configuration {:?}", "folder_functions")
+            }
+            model::Visitor::Custom(model::CustomVisitor {
+                visit_folder: Some(_),
+                extended_inputs: true,
+                ..
+            }) => format!("{:?} with {:?}", "visit_folder", "extended_inputs"),
+            _ => continue,
+        };
+        check_forest(forest, "", &usage)?;
+    }
+    Ok(())
+}
+
+fn check_forest(forest: &model::Forest, parent_path: &str, usage: &str) -> model::Result<()> {
+    for (name, tree) in forest {
+        if let model::Tree::Folder(model::Folder { forest, .. }) = tree {
+            let relative_path = if parent_path.is_empty() {
+                name.into()
+            } else {
+                format!("{parent_path}/{name}")
+            };
+
+            let indices = model::files(forest).map(|file| file.index);
+            let (count, start, end) = indices
+                .fold((0, usize::MAX, 0), |(count, start, end), index| {
+                    (count + 1, start.min(index), end.max(index + 1))
+                });
+            if count > 0 && end - start != count {
+                return Err(model::Error::ScatteredFolder {
+                    folder: relative_path,
+                    usage: usage.into(),
+                });
+            }
+
+            check_forest(forest, &relative_path, usage)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_contiguous_folders_it_accepts() {
+        let actual = main(true, &[get_visitor()], &get_forest([0, 1, 2]));
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn given_scattered_folder_it_errs() {
+        let actual = main(false, &[get_visitor()], &get_forest([1, 0, 2]));
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::ScatteredFolder {
+            folder: "b".into(),
+            usage: "\"visit_folder\" with \"extended_inputs\"".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_folder_functions_it_errs_for_scattered_folder() {
+        let actual = main(true, &[model::Visitor::Identifiers], &get_forest([1, 0, 2]));
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::ScatteredFolder {
+            folder: "b".into(),
+            usage: "configuration \"folder_functions\"".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_index_ranges_it_accepts_scattered_folder() {
+        let actual = main(
+            false,
+            &[model::Visitor::Identifiers],
+            &get_forest([1, 0, 2]),
+        );
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    fn get_visitor() -> model::Visitor {
+        model::Visitor::Custom(model::CustomVisitor {
+            visit_base: None,
+            visit_folder: Some(syn::parse_str("visit_folder").unwrap()),
+            visit_empty_folder: None,
+            visit_file: syn::parse_str("visit_file").unwrap(),
+            extended_inputs: true,
+        })
+    }
+
+    /// Gets files `a`, `b/c`, and `b/d` with the given indices.
+    fn get_forest([a, c, d]: [usize; 3]) -> model::Forest {
+        let get_file = |index| {
+            model::Tree::File(model::File {
+                index,
+                ..model::stubs::file()
+            })
+        };
+        [
+            ("a".into(), get_file(a)),
+            (
+                "b".into(),
+                model::Tree::Folder(model::Folder {
+                    forest: [("c".into(), get_file(c)), ("d".into(), get_file(d))]
+                        .into_iter()
+                        .collect(),
+                    ..model::stubs::folder()
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
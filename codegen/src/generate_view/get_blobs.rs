@@ -0,0 +1,148 @@
+use super::is_contents_used;
+use crate::model;
+use std::collections;
+
+pub fn main(
+    deduplicate: bool,
+    visitors: &[model::Visitor],
+    mut forest: model::Forest,
+) -> (model::Forest, Vec<model::Blob>) {
+    if !(deduplicate && is_contents_used::main(visitors)) {
+        return (forest, vec![]);
+    }
+
+    let mut files = model::files(&forest).collect::<Vec<_>>();
+    files.sort_by_key(|file| file.index);
+
+    let mut indices_by_contents = collections::HashMap::<_, Vec<_>>::new();
+    for file in &files {
+        indices_by_contents
+            .entry(&file.contents[..])
+            .or_default()
+            .push(file.index);
+    }
+
+    let mut blobs = vec![];
+    let mut blob_by_index = collections::HashMap::new();
+    for file in &files {
+        let indices = &indices_by_contents[&file.contents[..]];
+        if indices.len() > 1 && indices[0] == file.index {
+            for &index in indices {
+                blob_by_index.insert(index, blobs.len());
+            }
+            blobs.push(model::Blob {
+                absolute_path: file.absolute_path.clone(),
+                contents: file.contents.clone(),
+                normalized: file.normalization.is_some(),
+                references: indices.len(),
+            });
+        }
+    }
+
+    assign_blobs(&blob_by_index, &mut forest);
+    (forest, blobs)
+}
+
+fn assign_blobs(blob_by_index: &collections::HashMap<usize, usize>, forest: &mut model::Forest) {
+    for tree in forest.values_mut() {
+        match tree {
+            model::Tree::File(file) => file.blob = blob_by_index.get(&file.index).copied(),
+            model::Tree::Folder(model::Folder { forest, .. }) => {
+                assign_blobs(blob_by_index, forest)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_deduplication() {
+        let forest = get_forest(&[b"a", b"a"]);
+
+        let actual = main(false, &get_visitors(), forest.clone());
+
+        let expected = (forest, vec![]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn shares_blob_between_files_with_same_contents() {
+        let actual = main(
+            true,
+            &get_visitors(),
+            get_forest(&[b"a", b"b", b"a", b"c", b"b", b"a"]),
+        );
+
+        let expected_forest = [
+            ("0".into(), get_file(0, b"a", Some(0))),
+            ("1".into(), get_file(1, b"b", Some(1))),
+            ("2".into(), get_file(2, b"a", Some(0))),
+            ("3".into(), get_file(3, b"c", None)),
+            ("4".into(), get_file(4, b"b", Some(1))),
+            ("5".into(), get_file(5, b"a", Some(0))),
+        ]
+        .into_iter()
+        .collect();
+        let expected = (
+            expected_forest,
+            vec![
+                model::Blob {
+                    absolute_path: "/0".into(),
+                    contents: b"a".to_vec(),
+                    normalized: false,
+                    references: 3,
+                },
+                model::Blob {
+                    absolute_path: "/1".into(),
+                    contents: b"b".to_vec(),
+                    normalized: false,
+                    references: 2,
+                },
+            ],
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_contents_are_unused_it_does_not_deduplicate() {
+        let forest = get_forest(&[b"a", b"a"]);
+
+        let actual = main(
+            true,
+            &[model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TypeAlias(model::Populator::RelativePath),
+            ))],
+            forest.clone(),
+        );
+
+        let expected = (forest, vec![]);
+        assert_eq!(actual, expected);
+    }
+
+    fn get_visitors() -> Vec<model::Visitor> {
+        vec![model::Visitor::Array(model::Initializer::Default(
+            model::TypeStructure::TypeAlias(model::Populator::ContentsBytes),
+        ))]
+    }
+
+    fn get_forest(contents: &[&[u8]]) -> model::Forest {
+        contents
+            .iter()
+            .enumerate()
+            .map(|(index, contents)| (index.to_string(), get_file(index, contents, None)))
+            .collect()
+    }
+
+    fn get_file(index: usize, contents: &[u8], blob: Option<usize>) -> model::Tree {
+        model::Tree::File(model::File {
+            index,
+            absolute_path: format!("/{index}"),
+            contents: contents.to_vec(),
+            blob,
+            ..model::stubs::file()
+        })
+    }
+}
@@ -0,0 +1,136 @@
+use crate::match_paths;
+use crate::model;
+
+pub fn main(
+    paths: &str,
+    bundles: &[model::Bundle],
+    forest: model::Forest,
+) -> model::Result<model::Forest> {
+    if bundles.is_empty() {
+        Ok(forest)
+    } else {
+        let filter = match_paths::main(paths)?;
+        let bundles = bundles
+            .iter()
+            .map(|bundle| Ok((match_paths::main(&bundle.paths)?, &bundle.predicate)))
+            .collect::<model::Result<Vec<_>>>()?;
+        Ok(annotate_forest(&filter, &bundles, forest))
+    }
+}
+
+fn annotate_forest(
+    filter: &match_paths::Filter,
+    bundles: &[(match_paths::Filter, &syn::Meta)],
+    forest: model::Forest,
+) -> model::Forest {
+    forest
+        .into_iter()
+        .map(|(name, tree)| {
+            let tree = match tree {
+                model::Tree::File(file) if filter.is_match(&file.relative_path) => {
+                    model::Tree::File(file)
+                }
+
+                model::Tree::File(file) => {
+                    let bundle = get_predicate(bundles, &file.relative_path);
+                    let cfg = match file.cfg {
+                        None => bundle,
+                        Some(cfg) => syn::parse_quote! { all(#bundle, #cfg) },
+                    };
+                    model::Tree::File(model::File {
+                        cfg: Some(Box::new(cfg)),
+                        ..file
+                    })
+                }
+
+                model::Tree::Folder(model::Folder { identifier, forest }) => {
+                    model::Tree::Folder(model::Folder {
+                        identifier,
+                        forest: annotate_forest(filter, bundles, forest),
+                    })
+                }
+            };
+            (name, tree)
+        })
+        .collect()
+}
+
+/// Files outside the main selection are only included by bundles, so one of
+/// the matching bundles must be enabled.
+fn get_predicate(bundles: &[(match_paths::Filter, &syn::Meta)], relative_path: &str) -> syn::Meta {
+    let predicates = bundles
+        .iter()
+        .filter(|(filter, _)| filter.is_match(relative_path))
+        .map(|(_, predicate)| *predicate)
+        .collect::<Vec<_>>();
+
+    match predicates.as_slice() {
+        [predicate] => (*predicate).clone(),
+        _ => syn::parse_quote! { any(#(#predicates),*) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_bundles() {
+        let forest = [("a".into(), model::Tree::File(model::stubs::file()))]
+            .into_iter()
+            .collect::<model::Forest>();
+
+        let actual = main("", &[], forest.clone());
+
+        let actual = actual.unwrap();
+        let expected = forest;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_bundles() {
+        let file = |relative_path: &str, cfg: Option<&str>| {
+            (
+                relative_path.into(),
+                model::Tree::File(model::File {
+                    relative_path: relative_path.into(),
+                    cfg: cfg.map(|cfg| syn::parse_str(cfg).unwrap()),
+                    ..model::stubs::file()
+                }),
+            )
+        };
+
+        let actual = main(
+            "/a",
+            &[
+                model::Bundle {
+                    paths: "/a\n/b\n/c".into(),
+                    predicate: syn::parse_str("feature = \"x\"").unwrap(),
+                },
+                model::Bundle {
+                    paths: "/c\n/d".into(),
+                    predicate: syn::parse_str("feature = \"y\"").unwrap(),
+                },
+            ],
+            [
+                file("a", None),
+                file("b", None),
+                file("c", None),
+                file("d", Some("unix")),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let actual = actual.unwrap();
+        let expected = [
+            file("a", None),
+            file("b", Some("feature = \"x\"")),
+            file("c", Some("any(feature = \"x\", feature = \"y\")")),
+            file("d", Some("all(feature = \"y\", unix)")),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(actual, expected);
+    }
+}
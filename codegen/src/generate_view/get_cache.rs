@@ -0,0 +1,279 @@
+use crate::get_etag;
+use crate::match_paths;
+use crate::model;
+
+const FINGERPRINTED_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+const DEFAULT_CACHE_CONTROL: &str = "no-cache";
+
+pub fn main(
+    cache: Option<&model::Cache>,
+    visitors: &[model::Visitor],
+    forest: model::Forest,
+) -> model::Result<model::Forest> {
+    let cache = match cache {
+        None if !is_cache_used(visitors) => return Ok(forest),
+        None => &model::Cache {
+            rules: vec![],
+            fingerprint: String::new(),
+        },
+        Some(cache) => cache,
+    };
+
+    let rules = cache
+        .rules
+        .iter()
+        .map(|rule| Ok((match_paths::main(&rule.paths)?, rule.cache_control.as_str())))
+        .collect::<model::Result<Vec<_>>>()?;
+    let fingerprint = match_paths::main(&cache.fingerprint)?;
+    Ok(annotate_forest(&Context { rules, fingerprint }, forest))
+}
+
+struct Context<'a> {
+    rules: Vec<(match_paths::Filter, &'a str)>,
+    fingerprint: match_paths::Filter,
+}
+
+fn is_cache_used(visitors: &[model::Visitor]) -> bool {
+    visitors.iter().any(|visitor| match visitor {
+        model::Visitor::Array(model::Initializer::Default(structure)) => match structure {
+            model::TypeStructure::Unit => false,
+            model::TypeStructure::TypeAlias(populator) => is_cache(populator),
+            model::TypeStructure::NamedFields(fields) => {
+                fields.iter().any(|(_, populator)| is_cache(populator))
+            }
+            model::TypeStructure::TupleFields(populators) => populators.iter().any(is_cache),
+        },
+        _ => false,
+    })
+}
+
+fn is_cache(populator: &model::Populator) -> bool {
+    matches!(
+        populator,
+        model::Populator::CacheControl | model::Populator::FingerprintedPath
+    )
+}
+
+fn annotate_forest(context: &Context, forest: model::Forest) -> model::Forest {
+    forest
+        .into_iter()
+        .map(|(name, tree)| {
+            let tree = match tree {
+                model::Tree::File(file) => model::Tree::File(model::File {
+                    cache: Some(Box::new(get_file_cache(context, &file))),
+                    ..file
+                }),
+
+                model::Tree::Folder(model::Folder { identifier, forest }) => {
+                    model::Tree::Folder(model::Folder {
+                        identifier,
+                        forest: annotate_forest(context, forest),
+                    })
+                }
+            };
+            (name, tree)
+        })
+        .collect()
+}
+
+fn get_file_cache(context: &Context, file: &model::File) -> model::FileCache {
+    let fingerprinted_path = context.fingerprint.is_match(&file.relative_path).then(|| {
+        let hash = get_etag::get_fingerprint(&file.contents);
+        get_fingerprinted_path(&file.relative_path, &hash)
+    });
+    let cache_control = context
+        .rules
+        .iter()
+        .find(|(filter, _)| filter.is_match(&file.relative_path))
+        .map_or(
+            if fingerprinted_path.is_some() {
+                FINGERPRINTED_CACHE_CONTROL
+            } else {
+                DEFAULT_CACHE_CONTROL
+            },
+            |(_, cache_control)| cache_control,
+        )
+        .into();
+
+    model::FileCache {
+        cache_control,
+        fingerprinted_path,
+    }
+}
+
+/// Inserts a hash before the filename extension, like `app.3f9a1c2b.js`.
+fn get_fingerprinted_path(relative_path: &str, hash: &str) -> String {
+    let (folder, filename) = match relative_path.rsplit_once('/') {
+        None => ("", relative_path),
+        Some((folder, filename)) => (folder, filename),
+    };
+    let filename = match filename.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => format!("{stem}.{hash}.{extension}"),
+        _ => format!("{filename}.{hash}"),
+    };
+
+    if folder.is_empty() {
+        filename
+    } else {
+        format!("{folder}/{filename}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let forest = get_forest(&["a.css", "b.html", "c/d.tar.gz", "e"]);
+
+        let actual = main(
+            Some(&model::Cache {
+                rules: vec![
+                    model::CacheRule {
+                        paths: "*.html".into(),
+                        cache_control: "no-store".into(),
+                    },
+                    model::CacheRule {
+                        paths: "*".into(),
+                        cache_control: "max-age=60".into(),
+                    },
+                ],
+                fingerprint: "*.css\n*.gz\ne".into(),
+            }),
+            &[],
+            forest,
+        );
+
+        let actual = get_caches(&actual.unwrap());
+        let expected = vec![
+            (
+                "a.css".into(),
+                model::FileCache {
+                    cache_control: "max-age=60".into(),
+                    fingerprinted_path: Some("a.e3b0c442.css".into()),
+                },
+            ),
+            (
+                "b.html".into(),
+                model::FileCache {
+                    cache_control: "no-store".into(),
+                    fingerprinted_path: None,
+                },
+            ),
+            (
+                "c/d.tar.gz".into(),
+                model::FileCache {
+                    cache_control: "max-age=60".into(),
+                    fingerprinted_path: Some("c/d.tar.e3b0c442.gz".into()),
+                },
+            ),
+            (
+                "e".into(),
+                model::FileCache {
+                    cache_control: "max-age=60".into(),
+                    fingerprinted_path: Some("e.e3b0c442".into()),
+                },
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_rule_matches_it_uses_default_policies() {
+        let forest = get_forest(&["a.js", "b.html"]);
+
+        let actual = main(
+            Some(&model::Cache {
+                rules: vec![],
+                fingerprint: "*.js".into(),
+            }),
+            &[],
+            forest,
+        );
+
+        let actual = get_caches(&actual.unwrap())
+            .into_iter()
+            .map(|(_, cache)| cache.cache_control)
+            .collect::<Vec<_>>();
+        let expected = vec![
+            "public, max-age=31536000, immutable".to_string(),
+            "no-cache".into(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_standard_field_it_annotates_without_configuration() {
+        let forest = get_forest(&["a.js"]);
+
+        let actual = main(
+            None,
+            &[model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TypeAlias(model::Populator::CacheControl),
+            ))],
+            forest,
+        );
+
+        let actual = get_caches(&actual.unwrap());
+        let expected = vec![(
+            "a.js".into(),
+            model::FileCache {
+                cache_control: "no-cache".into(),
+                fingerprinted_path: None,
+            },
+        )];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_neither_configuration_nor_standard_field_it_does_nothing() {
+        let forest = get_forest(&["a.js"]);
+
+        let actual = main(None, &[model::Visitor::Identifiers], forest.clone());
+
+        let actual = actual.unwrap();
+        let expected = forest;
+        assert_eq!(actual, expected);
+    }
+
+    fn get_forest(relative_paths: &[&str]) -> model::Forest {
+        let mut forest = model::Forest::new();
+        for relative_path in relative_paths {
+            let file = model::Tree::File(model::File {
+                relative_path: (*relative_path).into(),
+                ..model::stubs::file()
+            });
+            match relative_path.split_once('/') {
+                None => {
+                    forest.insert((*relative_path).into(), file);
+                }
+                Some((folder, filename)) => {
+                    forest.insert(
+                        folder.into(),
+                        model::Tree::Folder(model::Folder {
+                            forest: [(filename.into(), file)].into_iter().collect(),
+                            ..model::stubs::folder()
+                        }),
+                    );
+                }
+            }
+        }
+        forest
+    }
+
+    fn get_caches(forest: &model::Forest) -> Vec<(String, model::FileCache)> {
+        let mut caches = vec![];
+        for tree in forest.values() {
+            match tree {
+                model::Tree::File(file) => {
+                    caches.push((file.relative_path.clone(), *file.cache.clone().unwrap()))
+                }
+                model::Tree::Folder(model::Folder { forest, .. }) => {
+                    caches.extend(get_caches(forest))
+                }
+            }
+        }
+        caches
+    }
+}
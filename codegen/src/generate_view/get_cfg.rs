@@ -0,0 +1,169 @@
+use crate::match_paths;
+use crate::model;
+
+pub fn main(cfgs: &[model::Cfg], forest: model::Forest) -> model::Result<model::Forest> {
+    if cfgs.is_empty() {
+        Ok(forest)
+    } else {
+        let rules = cfgs
+            .iter()
+            .map(|cfg| Ok((match_paths::main(&cfg.paths)?, &cfg.predicate)))
+            .collect::<model::Result<Vec<_>>>()?;
+        Ok(annotate_forest(&rules, forest))
+    }
+}
+
+fn annotate_forest(
+    rules: &[(match_paths::Filter, &syn::Meta)],
+    forest: model::Forest,
+) -> model::Forest {
+    forest
+        .into_iter()
+        .map(|(name, tree)| {
+            let tree = match tree {
+                model::Tree::File(file) => model::Tree::File(model::File {
+                    cfg: get_predicate(rules, &file.relative_path).map(Box::new),
+                    ..file
+                }),
+
+                model::Tree::Folder(model::Folder { identifier, forest }) => {
+                    model::Tree::Folder(model::Folder {
+                        identifier,
+                        forest: annotate_forest(rules, forest),
+                    })
+                }
+            };
+            (name, tree)
+        })
+        .collect()
+}
+
+/// Combines the predicates of all matching rules, so each of them must hold.
+fn get_predicate(
+    rules: &[(match_paths::Filter, &syn::Meta)],
+    relative_path: &str,
+) -> Option<syn::Meta> {
+    let predicates = rules
+        .iter()
+        .filter(|(filter, _)| filter.is_match(relative_path))
+        .map(|(_, predicate)| *predicate)
+        .collect::<Vec<_>>();
+
+    match predicates.as_slice() {
+        [] => None,
+        [predicate] => Some((*predicate).clone()),
+        _ => Some(syn::parse_quote! { all(#(#predicates),*) }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_cfgs() {
+        let forest = [("a".into(), model::Tree::File(model::stubs::file()))]
+            .into_iter()
+            .collect::<model::Forest>();
+
+        let actual = main(&[], forest.clone());
+
+        let actual = actual.unwrap();
+        let expected = forest;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_cfgs() {
+        let actual = main(
+            &[
+                model::Cfg {
+                    paths: "/a/**".into(),
+                    predicate: syn::parse_str("debug_assertions").unwrap(),
+                },
+                model::Cfg {
+                    paths: "*.txt".into(),
+                    predicate: syn::parse_str("feature = \"text\"").unwrap(),
+                },
+            ],
+            [
+                (
+                    "a".into(),
+                    model::Tree::Folder(model::Folder {
+                        forest: [
+                            (
+                                "b".into(),
+                                model::Tree::File(model::File {
+                                    relative_path: "a/b".into(),
+                                    ..model::stubs::file()
+                                }),
+                            ),
+                            (
+                                "c.txt".into(),
+                                model::Tree::File(model::File {
+                                    relative_path: "a/c.txt".into(),
+                                    ..model::stubs::file()
+                                }),
+                            ),
+                        ]
+                        .into_iter()
+                        .collect(),
+                        ..model::stubs::folder()
+                    }),
+                ),
+                (
+                    "d".into(),
+                    model::Tree::File(model::File {
+                        relative_path: "d".into(),
+                        ..model::stubs::file()
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let actual = actual.unwrap();
+        let expected = [
+            (
+                "a".into(),
+                model::Tree::Folder(model::Folder {
+                    forest: [
+                        (
+                            "b".into(),
+                            model::Tree::File(model::File {
+                                relative_path: "a/b".into(),
+                                cfg: Some(syn::parse_str("debug_assertions").unwrap()),
+                                ..model::stubs::file()
+                            }),
+                        ),
+                        (
+                            "c.txt".into(),
+                            model::Tree::File(model::File {
+                                relative_path: "a/c.txt".into(),
+                                cfg: Some(
+                                    syn::parse_str("all(debug_assertions, feature = \"text\")")
+                                        .unwrap(),
+                                ),
+                                ..model::stubs::file()
+                            }),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::folder()
+                }),
+            ),
+            (
+                "d".into(),
+                model::Tree::File(model::File {
+                    relative_path: "d".into(),
+                    ..model::stubs::file()
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,465 @@
+use crate::model;
+use std::collections;
+#[cfg(any(feature = "format-json", feature = "format-yaml"))]
+use std::fmt;
+
+pub fn main(visitors: &[model::Visitor], forest: model::Forest) -> model::Result<model::Forest> {
+    let formats = get_formats(visitors);
+    add_data_to_forest(&formats, forest)
+}
+
+fn get_formats(visitors: &[model::Visitor]) -> collections::BTreeSet<model::Format> {
+    visitors
+        .iter()
+        .flat_map(|visitor| match visitor {
+            model::Visitor::Array(model::Initializer::Default(structure)) => match structure {
+                model::TypeStructure::Unit => vec![],
+                model::TypeStructure::TypeAlias(populator) => vec![populator],
+                model::TypeStructure::NamedFields(fields) => {
+                    fields.iter().map(|(_, populator)| populator).collect()
+                }
+                model::TypeStructure::TupleFields(populators) => populators.iter().collect(),
+            },
+            _ => vec![],
+        })
+        .filter_map(|populator| match populator {
+            model::Populator::Parsed(format) => Some(*format),
+            _ => None,
+        })
+        .collect()
+}
+
+fn add_data_to_forest(
+    formats: &collections::BTreeSet<model::Format>,
+    forest: model::Forest,
+) -> model::Result<model::Forest> {
+    forest
+        .into_iter()
+        .map(|(name, tree)| {
+            let tree = match tree {
+                model::Tree::File(file) => model::Tree::File(add_data_to_file(formats, file)?),
+
+                model::Tree::Folder(model::Folder { identifier, forest }) => {
+                    model::Tree::Folder(model::Folder {
+                        identifier,
+                        forest: add_data_to_forest(formats, forest)?,
+                    })
+                }
+            };
+            Ok((name, tree))
+        })
+        .collect()
+}
+
+fn add_data_to_file(
+    formats: &collections::BTreeSet<model::Format>,
+    file: model::File,
+) -> model::Result<model::File> {
+    let data = formats
+        .iter()
+        .map(|&format| match parse(format, &file.contents) {
+            Err(message) => Err(model::Error::Parse {
+                path: file.absolute_path.clone().into(),
+                format,
+                message,
+            }),
+            Ok(data) => Ok((format, data)),
+        })
+        .collect::<model::Result<_>>()?;
+
+    Ok(model::File { data, ..file })
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "format-csv",
+        feature = "format-json",
+        feature = "format-toml",
+        feature = "format-yaml",
+    )),
+    allow(unused_variables)
+)]
+fn parse(format: model::Format, contents: &[u8]) -> Result<model::Data, String> {
+    match format {
+        #[cfg(feature = "format-csv")]
+        model::Format::Csv => parse_csv(contents),
+
+        #[cfg(feature = "format-json")]
+        model::Format::Json => parse_json(contents),
+
+        #[cfg(feature = "format-toml")]
+        model::Format::Toml => parse_toml(contents),
+
+        #[cfg(feature = "format-yaml")]
+        model::Format::Yaml => parse_yaml(contents),
+
+        #[allow(unreachable_patterns)]
+        _ => unreachable!("Format {format} is not enabled"),
+    }
+}
+
+#[cfg(feature = "format-csv")]
+fn parse_csv(contents: &[u8]) -> Result<model::Data, String> {
+    csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(contents)
+        .records()
+        .map(|record| {
+            record.map(|record| {
+                model::Data::Array(
+                    record
+                        .iter()
+                        .map(|field| model::Data::String(field.into()))
+                        .collect(),
+                )
+            })
+        })
+        .collect::<Result<_, _>>()
+        .map(model::Data::Array)
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(feature = "format-json")]
+fn parse_json(contents: &[u8]) -> Result<model::Data, String> {
+    serde_json::from_slice(contents)
+        .map_err(|error| error.to_string())
+        .and_then(get_json_data)
+}
+
+#[cfg(feature = "format-json")]
+fn get_json_data(value: serde_json::Value) -> Result<model::Data, String> {
+    Ok(match value {
+        serde_json::Value::Null => model::Data::Null,
+        serde_json::Value::Bool(value) => model::Data::Boolean(value),
+        serde_json::Value::Number(value) => match value.as_i64() {
+            None if value.is_u64() => return Err(get_integer_range_error(value)),
+            None => model::Data::Float(value.as_f64().unwrap_or(f64::NAN)),
+            Some(value) => model::Data::Integer(value),
+        },
+        serde_json::Value::String(value) => model::Data::String(value),
+        serde_json::Value::Array(values) => model::Data::Array(
+            values
+                .into_iter()
+                .map(get_json_data)
+                .collect::<Result<_, _>>()?,
+        ),
+        serde_json::Value::Object(values) => model::Data::Table(
+            values
+                .into_iter()
+                .map(|(key, value)| Ok((key, get_json_data(value)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+    })
+}
+
+#[cfg(feature = "format-toml")]
+fn parse_toml(contents: &[u8]) -> Result<model::Data, String> {
+    let contents = std::str::from_utf8(contents).map_err(|error| error.to_string())?;
+    toml::from_str(contents)
+        .map(|table| get_toml_data(toml::Value::Table(table)))
+        .map_err(|error: toml::de::Error| error.to_string())
+}
+
+#[cfg(feature = "format-toml")]
+fn get_toml_data(value: toml::Value) -> model::Data {
+    match value {
+        toml::Value::String(value) => model::Data::String(value),
+        toml::Value::Integer(value) => model::Data::Integer(value),
+        toml::Value::Float(value) => model::Data::Float(value),
+        toml::Value::Boolean(value) => model::Data::Boolean(value),
+        toml::Value::Datetime(value) => model::Data::String(value.to_string()),
+        toml::Value::Array(values) => {
+            model::Data::Array(values.into_iter().map(get_toml_data).collect())
+        }
+        toml::Value::Table(values) => model::Data::Table(
+            values
+                .into_iter()
+                .map(|(key, value)| (key, get_toml_data(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(feature = "format-yaml")]
+fn parse_yaml(contents: &[u8]) -> Result<model::Data, String> {
+    serde_yaml::from_slice(contents)
+        .map_err(|error| error.to_string())
+        .and_then(get_yaml_data)
+}
+
+#[cfg(feature = "format-yaml")]
+fn get_yaml_data(value: serde_yaml::Value) -> Result<model::Data, String> {
+    Ok(match value {
+        serde_yaml::Value::Null => model::Data::Null,
+        serde_yaml::Value::Bool(value) => model::Data::Boolean(value),
+        serde_yaml::Value::Number(value) => match value.as_i64() {
+            None if value.is_u64() => return Err(get_integer_range_error(value)),
+            None => model::Data::Float(value.as_f64().unwrap_or(f64::NAN)),
+            Some(value) => model::Data::Integer(value),
+        },
+        serde_yaml::Value::String(value) => model::Data::String(value),
+        serde_yaml::Value::Sequence(values) => model::Data::Array(
+            values
+                .into_iter()
+                .map(get_yaml_data)
+                .collect::<Result<_, _>>()?,
+        ),
+        serde_yaml::Value::Mapping(values) => model::Data::Table(
+            values
+                .into_iter()
+                .map(|(key, value)| match key {
+                    serde_yaml::Value::String(key) => Ok((key, get_yaml_data(value)?)),
+                    _ => Err(format!("mapping key is not a string: {key:?}")),
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        serde_yaml::Value::Tagged(value) => get_yaml_data(value.value)?,
+    })
+}
+
+#[cfg(any(feature = "format-json", feature = "format-yaml"))]
+fn get_integer_range_error(value: impl fmt::Display) -> String {
+    format!("integer {value} is out of range of i64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_parsed_populators() {
+        let actual = main(
+            &[model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TypeAlias(model::Populator::ContentsBytes),
+            ))],
+            [(
+                "a".into(),
+                model::Tree::File(model::File {
+                    contents: b"{".to_vec(),
+                    ..model::stubs::file()
+                }),
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        let actual = actual.unwrap();
+        let expected = [(
+            "a".into(),
+            model::Tree::File(model::File {
+                contents: b"{".to_vec(),
+                ..model::stubs::file()
+            }),
+        )]
+        .into_iter()
+        .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-json")]
+    #[test]
+    fn handles_nested_files() {
+        let actual = main(
+            &[model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::NamedFields(vec![(
+                    quote::format_ident!("parsed_json"),
+                    model::Populator::Parsed(model::Format::Json),
+                )]),
+            ))],
+            [(
+                "a".into(),
+                model::Tree::Folder(model::Folder {
+                    forest: [(
+                        "b".into(),
+                        model::Tree::File(model::File {
+                            contents: b"[1, 2.5]".to_vec(),
+                            ..model::stubs::file()
+                        }),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::folder()
+                }),
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        let actual = actual.unwrap();
+        let expected = [(
+            "a".into(),
+            model::Tree::Folder(model::Folder {
+                forest: [(
+                    "b".into(),
+                    model::Tree::File(model::File {
+                        contents: b"[1, 2.5]".to_vec(),
+                        data: [(
+                            model::Format::Json,
+                            model::Data::Array(vec![
+                                model::Data::Integer(1),
+                                model::Data::Float(2.5),
+                            ]),
+                        )]
+                        .into_iter()
+                        .collect(),
+                        ..model::stubs::file()
+                    }),
+                )]
+                .into_iter()
+                .collect(),
+                ..model::stubs::folder()
+            }),
+        )]
+        .into_iter()
+        .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-json")]
+    #[test]
+    fn given_invalid_contents_it_errs() {
+        let actual = main(
+            &[model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TypeAlias(model::Populator::Parsed(model::Format::Json)),
+            ))],
+            [(
+                "a".into(),
+                model::Tree::File(model::File {
+                    absolute_path: "/a".into(),
+                    contents: b"{\n  \"b\": }".to_vec(),
+                    ..model::stubs::file()
+                }),
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::Parse {
+            path: "/a".into(),
+            format: model::Format::Json,
+            message: "expected value at line 2 column 8".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-csv")]
+    #[test]
+    fn handles_csv() {
+        let actual = parse(model::Format::Csv, b"a,b\nc,\"d,e\"\n");
+
+        let actual = actual.unwrap();
+        let expected = model::Data::Array(vec![
+            model::Data::Array(vec![
+                model::Data::String("a".into()),
+                model::Data::String("b".into()),
+            ]),
+            model::Data::Array(vec![
+                model::Data::String("c".into()),
+                model::Data::String("d,e".into()),
+            ]),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-json")]
+    #[test]
+    fn handles_json() {
+        let actual = parse(
+            model::Format::Json,
+            br#"{"b": [null, true, -3, 0.5, "c"], "a": {}}"#,
+        );
+
+        let actual = actual.unwrap();
+        let expected = model::Data::Table(vec![
+            ("a".into(), model::Data::Table(vec![])),
+            (
+                "b".into(),
+                model::Data::Array(vec![
+                    model::Data::Null,
+                    model::Data::Boolean(true),
+                    model::Data::Integer(-3),
+                    model::Data::Float(0.5),
+                    model::Data::String("c".into()),
+                ]),
+            ),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-json")]
+    #[test]
+    fn given_json_with_too_large_integer_it_errs() {
+        let actual = parse(model::Format::Json, b"[18446744073709551615]");
+
+        let actual = actual.unwrap_err();
+        let expected = "integer 18446744073709551615 is out of range of i64";
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-toml")]
+    #[test]
+    fn handles_toml() {
+        let actual = parse(
+            model::Format::Toml,
+            b"a = 1\nb = 1979-05-27\n[c]\nd = ['e', 0.5]\n",
+        );
+
+        let actual = actual.unwrap();
+        let expected = model::Data::Table(vec![
+            ("a".into(), model::Data::Integer(1)),
+            ("b".into(), model::Data::String("1979-05-27".into())),
+            (
+                "c".into(),
+                model::Data::Table(vec![(
+                    "d".into(),
+                    model::Data::Array(vec![
+                        model::Data::String("e".into()),
+                        model::Data::Float(0.5),
+                    ]),
+                )]),
+            ),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-yaml")]
+    #[test]
+    fn handles_yaml() {
+        let actual = parse(model::Format::Yaml, b"b: [~, yes, 2]\na: !x 'c'\n");
+
+        let actual = actual.unwrap();
+        let expected = model::Data::Table(vec![
+            (
+                "b".into(),
+                model::Data::Array(vec![
+                    model::Data::Null,
+                    model::Data::String("yes".into()),
+                    model::Data::Integer(2),
+                ]),
+            ),
+            ("a".into(), model::Data::String("c".into())),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-yaml")]
+    #[test]
+    fn given_yaml_with_non_string_key_it_errs() {
+        let actual = parse(model::Format::Yaml, b"1: a\n");
+
+        let actual = actual.unwrap_err();
+        let expected = "mapping key is not a string: Number(1)";
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-yaml")]
+    #[test]
+    fn given_yaml_with_too_large_integer_it_errs() {
+        let actual = parse(model::Format::Yaml, b"a: 9223372036854775808\n");
+
+        let actual = actual.unwrap_err();
+        let expected = "integer 9223372036854775808 is out of range of i64";
+        assert_eq!(actual, expected);
+    }
+}
@@ -19,7 +19,13 @@ pub fn main(
                             field: field.clone(),
                             standard_fields: standard_field_populators.keys().cloned().collect(),
                         }),
-                        Some(populator) => Ok((field, populator.clone())),
+                        Some(populator) => match get_disabled_feature(populator) {
+                            None => Ok((field, populator.clone())),
+                            Some(feature) => Err(model::Error::DisabledFeature {
+                                feature: feature.into(),
+                                usage: format!("field {:?}", field.to_string()),
+                            }),
+                        },
                     })
                     .collect::<model::Result<_>>()?,
             ))
@@ -37,6 +43,10 @@ pub fn main(
 
 fn get_standard_field_populators() -> collections::BTreeMap<syn::Ident, model::Populator> {
     [
+        (
+            quote::format_ident!("cache_control"),
+            model::Populator::CacheControl,
+        ),
         (
             quote::format_ident!("contents_bytes"),
             model::Populator::ContentsBytes,
@@ -45,11 +55,40 @@ fn get_standard_field_populators() -> collections::BTreeMap<syn::Ident, model::P
             quote::format_ident!("contents_str"),
             model::Populator::ContentsStr,
         ),
+        (quote::format_ident!("etag"), model::Populator::Etag),
+        (
+            quote::format_ident!("fingerprinted_path"),
+            model::Populator::FingerprintedPath,
+        ),
         (
             quote::format_ident!("get_bytes"),
             model::Populator::GetBytes,
         ),
+        (
+            quote::format_ident!("get_decompressed"),
+            model::Populator::GetDecompressed,
+        ),
+        (
+            quote::format_ident!("get_decompressed_str"),
+            model::Populator::GetDecompressedStr,
+        ),
         (quote::format_ident!("get_str"), model::Populator::GetStr),
+        (
+            quote::format_ident!("parsed_csv"),
+            model::Populator::Parsed(model::Format::Csv),
+        ),
+        (
+            quote::format_ident!("parsed_json"),
+            model::Populator::Parsed(model::Format::Json),
+        ),
+        (
+            quote::format_ident!("parsed_toml"),
+            model::Populator::Parsed(model::Format::Toml),
+        ),
+        (
+            quote::format_ident!("parsed_yaml"),
+            model::Populator::Parsed(model::Format::Yaml),
+        ),
         (
             quote::format_ident!("relative_path"),
             model::Populator::RelativePath,
@@ -59,6 +98,29 @@ fn get_standard_field_populators() -> collections::BTreeMap<syn::Ident, model::P
     .collect()
 }
 
+fn get_disabled_feature(populator: &model::Populator) -> Option<&'static str> {
+    match populator {
+        model::Populator::GetDecompressed | model::Populator::GetDecompressedStr
+            if !cfg!(feature = "compression") =>
+        {
+            Some("compression")
+        }
+        model::Populator::Parsed(model::Format::Csv) if !cfg!(feature = "format-csv") => {
+            Some("format-csv")
+        }
+        model::Populator::Parsed(model::Format::Json) if !cfg!(feature = "format-json") => {
+            Some("format-json")
+        }
+        model::Populator::Parsed(model::Format::Toml) if !cfg!(feature = "format-toml") => {
+            Some("format-toml")
+        }
+        model::Populator::Parsed(model::Format::Yaml) if !cfg!(feature = "format-yaml") => {
+            Some("format-yaml")
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,10 +179,19 @@ mod tests {
             let expected = model::Error::NonstandardField {
                 field: quote::format_ident!("abc"),
                 standard_fields: vec![
+                    quote::format_ident!("cache_control"),
                     quote::format_ident!("contents_bytes"),
                     quote::format_ident!("contents_str"),
+                    quote::format_ident!("etag"),
+                    quote::format_ident!("fingerprinted_path"),
                     quote::format_ident!("get_bytes"),
+                    quote::format_ident!("get_decompressed"),
+                    quote::format_ident!("get_decompressed_str"),
                     quote::format_ident!("get_str"),
+                    quote::format_ident!("parsed_csv"),
+                    quote::format_ident!("parsed_json"),
+                    quote::format_ident!("parsed_toml"),
+                    quote::format_ident!("parsed_yaml"),
                     quote::format_ident!("relative_path"),
                 ],
             };
@@ -159,6 +230,76 @@ mod tests {
             ]);
             assert_eq!(actual, expected);
         }
+
+        #[cfg(feature = "format-json")]
+        #[test]
+        fn given_enabled_feature_it_handles_parsed_field() {
+            let actual = main(model::TypeStructure::NamedFields(vec![(
+                quote::format_ident!("parsed_json"),
+                (),
+            )]));
+
+            let actual = actual.unwrap();
+            let expected = model::TypeStructure::NamedFields(vec![(
+                quote::format_ident!("parsed_json"),
+                model::Populator::Parsed(model::Format::Json),
+            )]);
+            assert_eq!(actual, expected);
+        }
+
+        #[cfg(not(feature = "format-json"))]
+        #[test]
+        fn given_disabled_feature_it_errs() {
+            let actual = main(model::TypeStructure::NamedFields(vec![(
+                quote::format_ident!("parsed_json"),
+                (),
+            )]));
+
+            let actual = actual.unwrap_err();
+            let expected = model::Error::DisabledFeature {
+                feature: "format-json".into(),
+                usage: "field \"parsed_json\"".into(),
+            };
+            assert_eq!(actual, expected);
+        }
+
+        #[cfg(feature = "compression")]
+        #[test]
+        fn given_enabled_feature_it_handles_decompressed_fields() {
+            let actual = main(model::TypeStructure::NamedFields(vec![
+                (quote::format_ident!("get_decompressed"), ()),
+                (quote::format_ident!("get_decompressed_str"), ()),
+            ]));
+
+            let actual = actual.unwrap();
+            let expected = model::TypeStructure::NamedFields(vec![
+                (
+                    quote::format_ident!("get_decompressed"),
+                    model::Populator::GetDecompressed,
+                ),
+                (
+                    quote::format_ident!("get_decompressed_str"),
+                    model::Populator::GetDecompressedStr,
+                ),
+            ]);
+            assert_eq!(actual, expected);
+        }
+
+        #[cfg(not(feature = "compression"))]
+        #[test]
+        fn given_disabled_compression_feature_it_errs() {
+            let actual = main(model::TypeStructure::NamedFields(vec![(
+                quote::format_ident!("get_decompressed"),
+                (),
+            )]));
+
+            let actual = actual.unwrap_err();
+            let expected = model::Error::DisabledFeature {
+                feature: "compression".into(),
+                usage: "field \"get_decompressed\"".into(),
+            };
+            assert_eq!(actual, expected);
+        }
     }
 
     #[cfg(test)]
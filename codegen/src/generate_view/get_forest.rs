@@ -1,14 +1,19 @@
 use super::sanitize_name;
 use crate::model;
+use std::collections;
 use std::iter;
 
-pub fn main(paths: Vec<model::Path>) -> model::Result<model::Forest> {
+pub fn main(paths: Vec<model::Path>, folders: Vec<Vec<String>>) -> model::Result<model::Forest> {
     let mut forest = model::Forest::new();
 
     for path in paths.into_iter() {
         add_path(&mut forest, path)?;
     }
 
+    for folder in folders.into_iter() {
+        add_folder(&mut forest, folder)?;
+    }
+
     let mut index = 0;
     overwrite_indices_in_order(&mut forest, &mut index);
 
@@ -30,6 +35,15 @@ fn add_path(forest: &mut model::Forest, path: model::Path) -> model::Result<()>
                 index: 0,
                 relative_path: path.relative.join(NORMALIZED_FOLDER_SEPARATOR),
                 absolute_path: path.absolute,
+                contents: path.contents,
+                normalization: None,
+                cfg: None,
+                obfuscated: None,
+                blob: None,
+                slice: None,
+                data: collections::BTreeMap::new(),
+                modified: path.modified,
+                cache: None,
             };
 
             let mut reverse_path = path.relative;
@@ -82,7 +96,7 @@ fn get_singleton_tree(reverse_path: Vec<String>, file: model::File, root: &str)
 
     let mut tree = model::Tree::File(file);
 
-    for (child, parent) in reverse_path.into_iter().zip(parents.into_iter()) {
+    for (child, parent) in reverse_path.into_iter().zip(parents) {
         let forest = [(child, tree)].into_iter().collect();
         tree = model::Tree::Folder(model::Folder {
             identifier: parent,
@@ -93,6 +107,30 @@ fn get_singleton_tree(reverse_path: Vec<String>, file: model::File, root: &str)
     tree
 }
 
+fn add_folder(forest: &mut model::Forest, relative_path: Vec<String>) -> model::Result<()> {
+    let mut parent = forest;
+
+    for name in &relative_path {
+        let tree = parent.entry(name.clone()).or_insert_with(|| {
+            model::Tree::Folder(model::Folder {
+                identifier: sanitize_name::main(name, sanitize_name::Convention::SnakeCase),
+                forest: model::Forest::new(),
+            })
+        });
+
+        parent = match tree {
+            model::Tree::File(_) => {
+                return Err(model::Error::UnexpectedPathCollision(
+                    relative_path.join(NORMALIZED_FOLDER_SEPARATOR).into(),
+                ))
+            }
+            model::Tree::Folder(model::Folder { forest, .. }) => forest,
+        };
+    }
+
+    Ok(())
+}
+
 fn get_folder_identifiers(names: &[&str]) -> Vec<syn::Ident> {
     names
         .iter()
@@ -121,7 +159,7 @@ mod tests {
 
     #[test]
     fn handles_empty_set() {
-        let actual = main(vec![]);
+        let actual = main(vec![], vec![]);
 
         let actual = actual.unwrap();
         let expected = model::Forest::new();
@@ -130,16 +168,23 @@ mod tests {
 
     #[test]
     fn handles_files() {
-        let actual = main(vec![
-            model::Path {
-                relative: vec!["B".into()],
-                absolute: "/a/B".into(),
-            },
-            model::Path {
-                relative: vec!["c".into()],
-                absolute: "/a/c".into(),
-            },
-        ]);
+        let actual = main(
+            vec![
+                model::Path {
+                    relative: vec!["B".into()],
+                    absolute: "/a/B".into(),
+                    contents: b"b".to_vec(),
+                    modified: None,
+                },
+                model::Path {
+                    relative: vec!["c".into()],
+                    absolute: "/a/c".into(),
+                    contents: vec![],
+                    modified: None,
+                },
+            ],
+            vec![],
+        );
 
         let actual = actual.unwrap();
         let expected = [
@@ -150,6 +195,15 @@ mod tests {
                     index: 0,
                     relative_path: "B".into(),
                     absolute_path: "/a/B".into(),
+                    contents: b"b".to_vec(),
+                    normalization: None,
+                    cfg: None,
+                    obfuscated: None,
+                    blob: None,
+                    slice: None,
+                    data: collections::BTreeMap::new(),
+                    modified: None,
+                    cache: None,
                 }),
             ),
             (
@@ -159,6 +213,7 @@ mod tests {
                     index: 1,
                     relative_path: "c".into(),
                     absolute_path: "/a/c".into(),
+                    ..model::stubs::file()
                 }),
             ),
         ]
@@ -169,20 +224,29 @@ mod tests {
 
     #[test]
     fn handles_folders() {
-        let actual = main(vec![
-            model::Path {
-                relative: vec!["a".into()],
-                absolute: "/a".into(),
-            },
-            model::Path {
-                relative: vec!["b".into(), "a".into(), "b".into()],
-                absolute: "/b/a/b".into(),
-            },
-            model::Path {
-                relative: vec!["b".into(), "c".into()],
-                absolute: "/b/c".into(),
-            },
-        ]);
+        let actual = main(
+            vec![
+                model::Path {
+                    relative: vec!["a".into()],
+                    absolute: "/a".into(),
+                    contents: vec![],
+                    modified: None,
+                },
+                model::Path {
+                    relative: vec!["b".into(), "a".into(), "b".into()],
+                    absolute: "/b/a/b".into(),
+                    contents: vec![],
+                    modified: None,
+                },
+                model::Path {
+                    relative: vec!["b".into(), "c".into()],
+                    absolute: "/b/c".into(),
+                    contents: vec![],
+                    modified: None,
+                },
+            ],
+            vec![],
+        );
 
         let actual = actual.unwrap();
         let expected = [
@@ -193,6 +257,7 @@ mod tests {
                     index: 0,
                     relative_path: "a".into(),
                     absolute_path: "/a".into(),
+                    ..model::stubs::file()
                 }),
             ),
             (
@@ -211,6 +276,7 @@ mod tests {
                                         index: 1,
                                         relative_path: "b/a/b".into(),
                                         absolute_path: "/b/a/b".into(),
+                                        ..model::stubs::file()
                                     }),
                                 )]
                                 .into_iter()
@@ -224,6 +290,7 @@ mod tests {
                                 index: 2,
                                 relative_path: "b/c".into(),
                                 absolute_path: "/b/c".into(),
+                                ..model::stubs::file()
                             }),
                         ),
                     ]
@@ -237,12 +304,83 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn handles_empty_folders() {
+        let actual = main(
+            vec![model::Path {
+                relative: vec!["a".into(), "b".into()],
+                absolute: "/a/b".into(),
+                contents: vec![],
+                modified: None,
+            }],
+            vec![
+                vec!["a".into()],
+                vec!["a".into(), "c".into(), "d".into()],
+                vec!["e".into()],
+            ],
+        );
+
+        let actual = actual.unwrap();
+        let expected = [
+            (
+                "a".into(),
+                model::Tree::Folder(model::Folder {
+                    identifier: quote::format_ident!("r#a"),
+                    forest: [
+                        (
+                            "b".into(),
+                            model::Tree::File(model::File {
+                                identifier: quote::format_ident!("r#B"),
+                                index: 0,
+                                relative_path: "a/b".into(),
+                                absolute_path: "/a/b".into(),
+                                ..model::stubs::file()
+                            }),
+                        ),
+                        (
+                            "c".into(),
+                            model::Tree::Folder(model::Folder {
+                                identifier: quote::format_ident!("r#c"),
+                                forest: [(
+                                    "d".into(),
+                                    model::Tree::Folder(model::Folder {
+                                        identifier: quote::format_ident!("r#d"),
+                                        forest: model::Forest::new(),
+                                    }),
+                                )]
+                                .into_iter()
+                                .collect(),
+                            }),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                }),
+            ),
+            (
+                "e".into(),
+                model::Tree::Folder(model::Folder {
+                    identifier: quote::format_ident!("r#e"),
+                    forest: model::Forest::new(),
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn given_empty_relative_path_it_errs() {
-        let actual = main(vec![model::Path {
-            relative: vec![],
-            absolute: "/a/b".into(),
-        }]);
+        let actual = main(
+            vec![model::Path {
+                relative: vec![],
+                absolute: "/a/b".into(),
+                contents: vec![],
+                modified: None,
+            }],
+            vec![],
+        );
 
         let actual = actual.unwrap_err();
         let expected = model::Error::UnexpectedEmptyRelativePath {
@@ -253,16 +391,34 @@ mod tests {
 
     #[test]
     fn given_path_collision_it_errs() {
-        let actual = main(vec![
-            model::Path {
-                relative: vec!["a".into(), "b".into()],
-                ..model::stubs::path()
-            },
-            model::Path {
-                relative: vec!["a".into(), "b".into()],
+        let actual = main(
+            vec![
+                model::Path {
+                    relative: vec!["a".into(), "b".into()],
+                    ..model::stubs::path()
+                },
+                model::Path {
+                    relative: vec!["a".into(), "b".into()],
+                    ..model::stubs::path()
+                },
+            ],
+            vec![],
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::UnexpectedPathCollision("a/b".into());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_folder_collides_with_file_it_errs() {
+        let actual = main(
+            vec![model::Path {
+                relative: vec!["a".into()],
                 ..model::stubs::path()
-            },
-        ]);
+            }],
+            vec![vec!["a".into(), "b".into()]],
+        );
 
         let actual = actual.unwrap_err();
         let expected = model::Error::UnexpectedPathCollision("a/b".into());
@@ -0,0 +1,47 @@
+use crate::model;
+
+pub fn main(glob: bool) -> model::Result<bool> {
+    if glob && !cfg!(feature = "glob") {
+        Err(model::Error::DisabledFeature {
+            feature: "glob".into(),
+            usage: format!("configuration {:?}", "glob"),
+        })
+    } else {
+        Ok(glob)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_glob() {
+        let actual = main(false);
+
+        let actual = actual.unwrap();
+        assert!(!actual);
+    }
+
+    #[cfg(feature = "glob")]
+    #[test]
+    fn handles_glob() {
+        let actual = main(true);
+
+        let actual = actual.unwrap();
+        assert!(actual);
+    }
+
+    #[cfg(not(feature = "glob"))]
+    #[test]
+    fn given_disabled_feature_it_errs() {
+        let actual = main(true);
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::DisabledFeature {
+            feature: "glob".into(),
+            usage: "configuration \"glob\"".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::model;
+use std::path;
+
+pub fn main(
+    configuration: &model::Configuration,
+    base_folder: path::PathBuf,
+) -> model::Result<Option<model::Lookup>> {
+    let filesystem = get_filesystem(configuration, base_folder)?;
+    let overlay_variable = configuration.overlay_env.clone();
+
+    Ok(if filesystem.is_none() && overlay_variable.is_none() {
+        None
+    } else {
+        Some(model::Lookup {
+            filesystem,
+            overlay_variable,
+        })
+    })
+}
+
+fn get_filesystem(
+    configuration: &model::Configuration,
+    base_folder: path::PathBuf,
+) -> model::Result<Option<model::Filesystem>> {
+    match configuration.dev_mode {
+        None => Ok(None),
+
+        Some(model::DevMode::Filesystem) => {
+            if !cfg!(feature = "dev-mode") {
+                return Err(model::Error::DisabledFeature {
+                    feature: "dev-mode".into(),
+                    usage: format!("configuration {:?}", "dev_mode"),
+                });
+            }
+
+            let base_folder = base_folder
+                .into_os_string()
+                .into_string()
+                .map_err(|base_folder| model::Error::PathInvalidUnicode(base_folder.into()))?;
+
+            Ok(Some(model::Filesystem {
+                base_folder,
+                paths: configuration.paths.clone(),
+                bundles: configuration.bundles.clone(),
+                order: configuration.order,
+                text: configuration.text.clone(),
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_lookup() {
+        let actual = main(
+            &model::Configuration {
+                dev_mode: None,
+                overlay_env: None,
+                ..model::stubs::configuration()
+            },
+            "/a".into(),
+        );
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, None);
+    }
+
+    #[cfg(feature = "dev-mode")]
+    #[test]
+    fn handles_dev_mode() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/b/**".into(),
+                bundles: vec![model::Bundle {
+                    paths: "/c/**".into(),
+                    predicate: syn::parse_quote! { feature = "c" },
+                }],
+                order: model::Order::Path,
+                text: Some(model::Text {
+                    paths: "*.md".into(),
+                    normalization: model::Normalization {
+                        line_endings: Some(model::LineEnding::Lf),
+                        strip_bom: true,
+                    },
+                }),
+                dev_mode: Some(model::DevMode::Filesystem),
+                ..model::stubs::configuration()
+            },
+            "/a".into(),
+        );
+
+        let actual = actual.unwrap();
+        let expected = Some(model::Lookup {
+            filesystem: Some(model::Filesystem {
+                base_folder: "/a".into(),
+                paths: "/b/**".into(),
+                bundles: vec![model::Bundle {
+                    paths: "/c/**".into(),
+                    predicate: syn::parse_quote! { feature = "c" },
+                }],
+                order: model::Order::Path,
+                text: Some(model::Text {
+                    paths: "*.md".into(),
+                    normalization: model::Normalization {
+                        line_endings: Some(model::LineEnding::Lf),
+                        strip_bom: true,
+                    },
+                }),
+            }),
+            overlay_variable: None,
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(not(feature = "dev-mode"))]
+    #[test]
+    fn given_disabled_feature_it_errs() {
+        let actual = main(
+            &model::Configuration {
+                dev_mode: Some(model::DevMode::Filesystem),
+                ..model::stubs::configuration()
+            },
+            "/a".into(),
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::DisabledFeature {
+            feature: "dev-mode".into(),
+            usage: "configuration \"dev_mode\"".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_overlay() {
+        let actual = main(
+            &model::Configuration {
+                overlay_env: Some("MY_OVERLAY".into()),
+                ..model::stubs::configuration()
+            },
+            "/a".into(),
+        );
+
+        let actual = actual.unwrap();
+        let expected = Some(model::Lookup {
+            filesystem: None,
+            overlay_variable: Some("MY_OVERLAY".into()),
+        });
+        assert_eq!(actual, expected);
+    }
+}
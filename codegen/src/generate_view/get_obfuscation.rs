@@ -0,0 +1,268 @@
+use crate::match_paths;
+use crate::model;
+
+pub fn main(
+    obfuscation: Option<&model::Obfuscation>,
+    key: Option<&str>,
+    visitors: &[model::Visitor],
+    forest: model::Forest,
+) -> model::Result<model::Forest> {
+    match (obfuscation, key) {
+        (
+            Some(model::Obfuscation {
+                paths,
+                key_variable,
+            }),
+            Some(key),
+        ) => {
+            if !cfg!(feature = "obfuscate") {
+                return Err(model::Error::DisabledFeature {
+                    feature: "obfuscate".into(),
+                    usage: format!("configuration {:?}", "obfuscate"),
+                });
+            }
+
+            let filter = match_paths::main(paths)?;
+            if model::files(&forest).any(|file| filter.is_match(&file.relative_path)) {
+                check_fields(visitors)?;
+            }
+
+            Ok(obfuscate_forest(&filter, key_variable, key, forest))
+        }
+
+        _ => Ok(forest),
+    }
+}
+
+/// Rejects standard fields that would embed the contents of obfuscated files as
+/// they are.
+fn check_fields(visitors: &[model::Visitor]) -> model::Result<()> {
+    for visitor in visitors {
+        if let model::Visitor::Array(model::Initializer::Default(
+            model::TypeStructure::NamedFields(fields),
+        )) = visitor
+        {
+            for (field, populator) in fields {
+                if matches!(
+                    populator,
+                    model::Populator::ContentsBytes
+                        | model::Populator::ContentsStr
+                        | model::Populator::GetDecompressed
+                        | model::Populator::GetDecompressedStr
+                        | model::Populator::Parsed(_)
+                ) {
+                    return Err(model::Error::PlaintextField(field.clone()));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn obfuscate_forest(
+    filter: &match_paths::Filter,
+    key_variable: &str,
+    key: &str,
+    forest: model::Forest,
+) -> model::Forest {
+    forest
+        .into_iter()
+        .map(|(name, tree)| {
+            let tree = match tree {
+                model::Tree::File(file) if filter.is_match(&file.relative_path) => {
+                    let (nonce, contents) = obfuscate(key, &file.relative_path, &file.contents);
+                    model::Tree::File(model::File {
+                        obfuscated: Some(Box::new(model::Obfuscated {
+                            key_variable: key_variable.into(),
+                            nonce,
+                            contents,
+                        })),
+                        ..file
+                    })
+                }
+
+                model::Tree::File(file) => model::Tree::File(file),
+
+                model::Tree::Folder(model::Folder { identifier, forest }) => {
+                    model::Tree::Folder(model::Folder {
+                        identifier,
+                        forest: obfuscate_forest(filter, key_variable, key, forest),
+                    })
+                }
+            };
+            (name, tree)
+        })
+        .collect()
+}
+
+/// Encrypts contents with ChaCha20 like `iftree::runtime::deobfuscate` decrypts
+/// them. To keep builds reproducible without revealing anything about the
+/// contents, the nonce is a hash of the key and the relative path.
+#[cfg(feature = "obfuscate")]
+fn obfuscate(key: &str, relative_path: &str, contents: &[u8]) -> ([u8; 12], Vec<u8>) {
+    use chacha20::cipher::KeyIvInit;
+    use chacha20::cipher::StreamCipher;
+    use sha2::Digest;
+
+    let key: [u8; 32] = sha2::Sha256::digest(key).into();
+    let mut nonce = [0; 12];
+    nonce.copy_from_slice(
+        &sha2::Sha256::new()
+            .chain_update(key)
+            .chain_update(relative_path)
+            .finalize()[..12],
+    );
+    let mut contents = contents.to_vec();
+    chacha20::ChaCha20::new(&key.into(), &nonce.into()).apply_keystream(&mut contents);
+    (nonce, contents)
+}
+
+#[cfg(not(feature = "obfuscate"))]
+fn obfuscate(_: &str, _: &str, _: &[u8]) -> ([u8; 12], Vec<u8>) {
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_obfuscation() {
+        let forest = [("a".into(), model::Tree::File(model::stubs::file()))]
+            .into_iter()
+            .collect::<model::Forest>();
+
+        let actual = main(None, None, &[], forest.clone());
+
+        let actual = actual.unwrap();
+        let expected = forest;
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "obfuscate")]
+    #[test]
+    fn handles_obfuscation() {
+        let file = |relative_path: &str| {
+            (
+                relative_path.into(),
+                model::Tree::File(model::File {
+                    relative_path: relative_path.into(),
+                    contents: b"abc".to_vec(),
+                    ..model::stubs::file()
+                }),
+            )
+        };
+
+        let actual = main(
+            Some(&model::Obfuscation {
+                paths: "/a".into(),
+                key_variable: "KEY".into(),
+            }),
+            Some("secret"),
+            &[],
+            [file("a"), file("b")].into_iter().collect(),
+        );
+
+        let actual = actual.unwrap();
+        let (nonce, contents) = obfuscate("secret", "a", b"abc");
+        let (name, mut expected) = file("a");
+        if let model::Tree::File(file) = &mut expected {
+            file.obfuscated = Some(Box::new(model::Obfuscated {
+                key_variable: "KEY".into(),
+                nonce,
+                contents,
+            }));
+        }
+        let expected = [(name, expected), file("b")].into_iter().collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "obfuscate")]
+    #[test]
+    fn handles_encryption_reproducibly() {
+        let actual = obfuscate("secret", "a", b"abc");
+
+        assert_ne!(actual.1, b"abc");
+        let expected = obfuscate("secret", "a", b"abc");
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "obfuscate")]
+    #[test]
+    fn handles_nonce_independent_of_contents() {
+        let actual = [
+            obfuscate("secret", "a", b"abc").0,
+            obfuscate("secret", "a", b"def").0,
+            obfuscate("secret", "b", b"abc").0,
+            obfuscate("other", "a", b"abc").0,
+        ];
+
+        assert_eq!(actual[0], actual[1]);
+        assert_ne!(actual[0], actual[2]);
+        assert_ne!(actual[0], actual[3]);
+    }
+
+    #[test]
+    fn given_plaintext_field_it_errs() {
+        let actual = main(
+            Some(&model::Obfuscation {
+                paths: "*".into(),
+                key_variable: "KEY".into(),
+            }),
+            Some("secret"),
+            &get_plaintext_visitors(),
+            [("a".into(), model::Tree::File(model::stubs::file()))]
+                .into_iter()
+                .collect(),
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = if cfg!(feature = "obfuscate") {
+            model::Error::PlaintextField(quote::format_ident!("contents_str"))
+        } else {
+            model::Error::DisabledFeature {
+                feature: "obfuscate".into(),
+                usage: "configuration \"obfuscate\"".into(),
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "obfuscate")]
+    #[test]
+    fn given_plaintext_field_without_obfuscated_files_it_passes() {
+        let forest = [(
+            "a".into(),
+            model::Tree::File(model::File {
+                relative_path: "a".into(),
+                ..model::stubs::file()
+            }),
+        )]
+        .into_iter()
+        .collect::<model::Forest>();
+
+        let actual = main(
+            Some(&model::Obfuscation {
+                paths: "/b".into(),
+                key_variable: "KEY".into(),
+            }),
+            Some("secret"),
+            &get_plaintext_visitors(),
+            forest.clone(),
+        );
+
+        let actual = actual.unwrap();
+        let expected = forest;
+        assert_eq!(actual, expected);
+    }
+
+    fn get_plaintext_visitors() -> Vec<model::Visitor> {
+        vec![model::Visitor::Array(model::Initializer::Default(
+            model::TypeStructure::NamedFields(vec![(
+                quote::format_ident!("contents_str"),
+                model::Populator::ContentsStr,
+            )]),
+        ))]
+    }
+}
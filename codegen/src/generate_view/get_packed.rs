@@ -0,0 +1,221 @@
+use super::is_contents_used;
+use crate::model;
+use std::collections;
+
+pub fn main(
+    storage: model::Storage,
+    visitors: &[model::Visitor],
+    mut forest: model::Forest,
+) -> (model::Forest, Option<model::Packed>) {
+    match storage {
+        model::Storage::Packed { alignment } if is_contents_used::main(visitors) => {
+            let mut contents = vec![];
+            assign_slices(
+                alignment.unwrap_or(1),
+                &mut contents,
+                &mut collections::HashMap::new(),
+                &mut forest,
+            );
+            (
+                forest,
+                Some(model::Packed {
+                    contents,
+                    alignment,
+                }),
+            )
+        }
+
+        _ => (forest, None),
+    }
+}
+
+fn assign_slices(
+    alignment: usize,
+    contents: &mut Vec<u8>,
+    slice_by_blob: &mut collections::HashMap<usize, model::Slice>,
+    forest: &mut model::Forest,
+) {
+    for tree in forest.values_mut() {
+        match tree {
+            model::Tree::File(file) => {
+                let slice = match file.blob {
+                    None => append(alignment, contents, &file.contents),
+                    Some(blob) => *slice_by_blob
+                        .entry(blob)
+                        .or_insert_with(|| append(alignment, contents, &file.contents)),
+                };
+                file.slice = Some(slice);
+            }
+
+            model::Tree::Folder(model::Folder { forest, .. }) => {
+                assign_slices(alignment, contents, slice_by_blob, forest)
+            }
+        }
+    }
+}
+
+fn append(alignment: usize, contents: &mut Vec<u8>, file_contents: &[u8]) -> model::Slice {
+    let offset = contents.len().next_multiple_of(alignment);
+    contents.resize(offset, 0);
+    contents.extend_from_slice(file_contents);
+    model::Slice {
+        offset,
+        length: file_contents.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_files_storage() {
+        let forest = get_forest(&[(b"a", None)]);
+
+        let actual = main(model::Storage::Files, &get_visitors(), forest.clone());
+
+        let expected = (forest, None);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_packed_storage() {
+        let actual = main(
+            model::Storage::Packed { alignment: None },
+            &get_visitors(),
+            get_forest(&[(b"ab", None), (b"", None), (b"cde", None)]),
+        );
+
+        let actual = (actual.0.values().map(get_slice).collect(), actual.1);
+        let expected = (
+            vec![
+                model::Slice {
+                    offset: 0,
+                    length: 2,
+                },
+                model::Slice {
+                    offset: 2,
+                    length: 0,
+                },
+                model::Slice {
+                    offset: 2,
+                    length: 3,
+                },
+            ],
+            Some(model::Packed {
+                contents: b"abcde".to_vec(),
+                alignment: None,
+            }),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_alignment() {
+        let actual = main(
+            model::Storage::Packed { alignment: Some(4) },
+            &get_visitors(),
+            get_forest(&[(b"ab", None), (b"cde", None), (b"f", None)]),
+        );
+
+        let actual = (actual.0.values().map(get_slice).collect(), actual.1);
+        let expected = (
+            vec![
+                model::Slice {
+                    offset: 0,
+                    length: 2,
+                },
+                model::Slice {
+                    offset: 4,
+                    length: 3,
+                },
+                model::Slice {
+                    offset: 8,
+                    length: 1,
+                },
+            ],
+            Some(model::Packed {
+                contents: b"ab\0\0cde\0f".to_vec(),
+                alignment: Some(4),
+            }),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn shares_slices_of_blobs() {
+        let actual = main(
+            model::Storage::Packed { alignment: None },
+            &get_visitors(),
+            get_forest(&[(b"ab", Some(0)), (b"c", None), (b"ab", Some(0))]),
+        );
+
+        let actual = (actual.0.values().map(get_slice).collect(), actual.1);
+        let expected = (
+            vec![
+                model::Slice {
+                    offset: 0,
+                    length: 2,
+                },
+                model::Slice {
+                    offset: 2,
+                    length: 1,
+                },
+                model::Slice {
+                    offset: 0,
+                    length: 2,
+                },
+            ],
+            Some(model::Packed {
+                contents: b"abc".to_vec(),
+                alignment: None,
+            }),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_contents_are_unused_it_does_not_pack() {
+        let forest = get_forest(&[(b"a", None)]);
+
+        let actual = main(
+            model::Storage::Packed { alignment: None },
+            &[model::Visitor::Identifiers],
+            forest.clone(),
+        );
+
+        let expected = (forest, None);
+        assert_eq!(actual, expected);
+    }
+
+    fn get_visitors() -> Vec<model::Visitor> {
+        vec![model::Visitor::Array(model::Initializer::Default(
+            model::TypeStructure::TypeAlias(model::Populator::ContentsBytes),
+        ))]
+    }
+
+    fn get_forest(files: &[(&[u8], Option<usize>)]) -> model::Forest {
+        files
+            .iter()
+            .enumerate()
+            .map(|(index, (contents, blob))| {
+                (
+                    index.to_string(),
+                    model::Tree::File(model::File {
+                        index,
+                        contents: contents.to_vec(),
+                        blob: *blob,
+                        ..model::stubs::file()
+                    }),
+                )
+            })
+            .collect()
+    }
+
+    fn get_slice(tree: &model::Tree) -> model::Slice {
+        match tree {
+            model::Tree::File(file) => file.slice.unwrap(),
+            model::Tree::Folder(_) => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::model;
+
+pub fn main(
+    lookup: model::PathLookup,
+    forest: &model::Forest,
+) -> model::Result<Option<model::PathKeys>> {
+    if !(lookup.case_insensitive || lookup.normalize) {
+        return Ok(None);
+    }
+
+    // Relative paths are normalized already, so only case folding applies.
+    let mut keys = model::files(forest)
+        .map(|file| model::PathKey {
+            key: if lookup.case_insensitive {
+                file.relative_path.to_lowercase()
+            } else {
+                file.relative_path.clone()
+            },
+            relative_path: file.relative_path.clone(),
+        })
+        .collect::<Vec<_>>();
+    keys.sort_by(|left, right| left.key.cmp(&right.key));
+
+    match keys.windows(2).find(|pair| pair[0].key == pair[1].key) {
+        None => Ok(Some(model::PathKeys { lookup, keys })),
+        Some(pair) => Err(model::Error::CaseInsensitivePathCollision {
+            relative_path: pair[0].relative_path.clone(),
+            other_relative_path: pair[1].relative_path.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_default_lookup_it_gets_none() {
+        let actual = main(model::PathLookup::default(), &get_forest(&["a"]));
+
+        let actual = actual.unwrap();
+        let expected = None;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_case_insensitive_lookup() {
+        let lookup = model::PathLookup {
+            case_insensitive: true,
+            normalize: false,
+        };
+
+        let actual = main(lookup, &get_forest(&["B", "a/C.txt"]));
+
+        let actual = actual.unwrap();
+        let expected = Some(model::PathKeys {
+            lookup,
+            keys: vec![
+                model::PathKey {
+                    key: "a/c.txt".into(),
+                    relative_path: "a/C.txt".into(),
+                },
+                model::PathKey {
+                    key: "b".into(),
+                    relative_path: "B".into(),
+                },
+            ],
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_normalized_lookup() {
+        let lookup = model::PathLookup {
+            case_insensitive: false,
+            normalize: true,
+        };
+
+        let actual = main(lookup, &get_forest(&["B", "a"]));
+
+        let actual = actual.unwrap();
+        let expected = Some(model::PathKeys {
+            lookup,
+            keys: vec![
+                model::PathKey {
+                    key: "B".into(),
+                    relative_path: "B".into(),
+                },
+                model::PathKey {
+                    key: "a".into(),
+                    relative_path: "a".into(),
+                },
+            ],
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_case_collision_it_errs() {
+        let lookup = model::PathLookup {
+            case_insensitive: true,
+            normalize: true,
+        };
+
+        let actual = main(lookup, &get_forest(&["Logo.PNG", "b", "logo.png"]));
+
+        let expected = Err(model::Error::CaseInsensitivePathCollision {
+            relative_path: "Logo.PNG".into(),
+            other_relative_path: "logo.png".into(),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    fn get_forest(relative_paths: &[&str]) -> model::Forest {
+        relative_paths
+            .iter()
+            .map(|relative_path| {
+                (
+                    relative_path.replace('/', "_"),
+                    model::Tree::File(model::File {
+                        relative_path: relative_path.to_string(),
+                        ..model::stubs::file()
+                    }),
+                )
+            })
+            .collect()
+    }
+}
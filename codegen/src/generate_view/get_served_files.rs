@@ -0,0 +1,210 @@
+use crate::get_etag;
+use crate::model;
+use std::collections;
+use std::time;
+
+/// Content codings of precompressed variants by filename extension, in order of
+/// preference.
+const ENCODINGS: [(&str, &str); 3] = [("br", ".br"), ("zstd", ".zst"), ("gzip", ".gz")];
+
+pub fn main(serve: bool, forest: &model::Forest) -> model::Result<Option<Vec<model::ServedFile>>> {
+    if !serve {
+        return Ok(None);
+    } else if !cfg!(feature = "tower") {
+        return Err(model::Error::DisabledFeature {
+            feature: "tower".into(),
+            usage: format!("configuration {:?}", "serve"),
+        });
+    }
+
+    // Serving obfuscated files would embed their plaintext.
+    let file_by_relative_path = model::files(forest)
+        .filter(|file| file.obfuscated.is_none())
+        .map(|file| (file.relative_path.as_str(), file))
+        .collect::<collections::BTreeMap<_, _>>();
+
+    Ok(Some(
+        file_by_relative_path
+            .values()
+            .map(|file| model::ServedFile {
+                relative_path: file.relative_path.clone(),
+                media_type: get_media_type(&file.relative_path),
+                etag: get_etag::main(&file.contents),
+                last_modified: file.modified.map(format_http_date),
+                cache_control: file.cache.as_ref().map(|cache| cache.cache_control.clone()),
+                fingerprinted_path: file
+                    .cache
+                    .as_ref()
+                    .and_then(|cache| cache.fingerprinted_path.clone()),
+                variants: ENCODINGS
+                    .into_iter()
+                    .filter_map(|(encoding, extension)| {
+                        let relative_path = format!("{}{extension}", file.relative_path);
+                        let variant = file_by_relative_path.get(relative_path.as_str())?;
+                        Some(model::Variant {
+                            encoding: encoding.into(),
+                            relative_path,
+                            etag: get_etag::main(&variant.contents),
+                        })
+                    })
+                    .collect(),
+            })
+            .collect(),
+    ))
+}
+
+#[cfg(feature = "tower")]
+fn get_media_type(relative_path: &str) -> String {
+    mime_guess::from_path(relative_path)
+        .first_or_octet_stream()
+        .essence_str()
+        .into()
+}
+
+#[cfg(not(feature = "tower"))]
+fn get_media_type(_: &str) -> String {
+    unreachable!()
+}
+
+#[cfg(feature = "tower")]
+fn format_http_date(time: time::SystemTime) -> String {
+    httpdate::fmt_http_date(time)
+}
+
+#[cfg(not(feature = "tower"))]
+fn format_http_date(_: time::SystemTime) -> String {
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "tower")]
+    #[test]
+    fn handles() {
+        let forest = [
+            ("a.css", b"a".as_slice()),
+            ("a.css.br", b"b"),
+            ("a.css.gz", b"c"),
+            ("d", b""),
+        ]
+        .map(|(relative_path, contents)| {
+            (
+                relative_path.into(),
+                model::Tree::File(model::File {
+                    relative_path: relative_path.into(),
+                    contents: contents.to_vec(),
+                    modified: (relative_path == "a.css").then_some(time::UNIX_EPOCH),
+                    ..model::stubs::file()
+                }),
+            )
+        })
+        .into_iter()
+        .collect();
+
+        let actual = main(true, &forest);
+
+        let etag_a = "\"ca978112ca1bbdcafac231b39a23dc4d\"";
+        let etag_b = "\"3e23e8160039594a33894f6564e1b134\"";
+        let etag_c = "\"2e7d2c03a9507ae265ecf5b5356885a5\"";
+        let etag_empty = "\"e3b0c44298fc1c149afbf4c8996fb924\"";
+        let actual = actual.unwrap().unwrap();
+        let expected = vec![
+            model::ServedFile {
+                relative_path: "a.css".into(),
+                media_type: "text/css".into(),
+                etag: etag_a.into(),
+                last_modified: Some("Thu, 01 Jan 1970 00:00:00 GMT".into()),
+                cache_control: None,
+                fingerprinted_path: None,
+                variants: vec![
+                    model::Variant {
+                        encoding: "br".into(),
+                        relative_path: "a.css.br".into(),
+                        etag: etag_b.into(),
+                    },
+                    model::Variant {
+                        encoding: "gzip".into(),
+                        relative_path: "a.css.gz".into(),
+                        etag: etag_c.into(),
+                    },
+                ],
+            },
+            model::ServedFile {
+                relative_path: "a.css.br".into(),
+                media_type: "application/octet-stream".into(),
+                etag: etag_b.into(),
+                last_modified: None,
+                cache_control: None,
+                fingerprinted_path: None,
+                variants: vec![],
+            },
+            model::ServedFile {
+                relative_path: "a.css.gz".into(),
+                media_type: "application/gzip".into(),
+                etag: etag_c.into(),
+                last_modified: None,
+                cache_control: None,
+                fingerprinted_path: None,
+                variants: vec![],
+            },
+            model::ServedFile {
+                relative_path: "d".into(),
+                media_type: "application/octet-stream".into(),
+                etag: etag_empty.into(),
+                last_modified: None,
+                cache_control: None,
+                fingerprinted_path: None,
+                variants: vec![],
+            },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "tower")]
+    #[test]
+    fn given_obfuscated_file_it_omits_file() {
+        let forest = [(
+            "a".into(),
+            model::Tree::File(model::File {
+                obfuscated: Some(Box::new(model::Obfuscated {
+                    key_variable: "KEY".into(),
+                    nonce: [0; 12],
+                    contents: vec![],
+                })),
+                ..model::stubs::file()
+            }),
+        )]
+        .into_iter()
+        .collect();
+
+        let actual = main(true, &forest);
+
+        let actual = actual.unwrap();
+        let expected = Some(vec![]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_serving_it_gets_none() {
+        let actual = main(false, &model::Forest::new());
+
+        let actual = actual.unwrap();
+        let expected = None;
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(not(feature = "tower"))]
+    #[test]
+    fn given_disabled_feature_it_errs() {
+        let actual = main(true, &model::Forest::new());
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::DisabledFeature {
+            feature: "tower".into(),
+            usage: "configuration \"serve\"".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+}
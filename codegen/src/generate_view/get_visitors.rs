@@ -9,11 +9,15 @@ pub fn main(
     Ok(match template {
         model::Template::Default {
             initializer,
+            initializer_arguments,
             identifiers,
         } => {
             let initializer = match initializer {
                 None => model::Initializer::Default(get_default_initializer::main(structure)?),
-                Some(macro_) => model::Initializer::Macro(macro_),
+                Some(macro_) => model::Initializer::Macro {
+                    macro_,
+                    arguments: initializer_arguments,
+                },
             };
 
             iter::once(model::Visitor::Array(initializer))
@@ -40,6 +44,10 @@ mod tests {
             let actual = main(
                 model::Template::Default {
                     initializer: None,
+                    initializer_arguments: vec![
+                        model::InitializerArgument::RelativePath,
+                        model::InitializerArgument::AbsolutePath,
+                    ],
                     identifiers: false,
                 },
                 model::TypeStructure::Unit,
@@ -57,15 +65,23 @@ mod tests {
             let actual = main(
                 model::Template::Default {
                     initializer: Some(syn::parse_str("abc").unwrap()),
+                    initializer_arguments: vec![
+                        model::InitializerArgument::RelativePath,
+                        model::InitializerArgument::AbsolutePath,
+                    ],
                     identifiers: false,
                 },
                 model::stubs::type_structure(),
             );
 
             let actual = actual.unwrap();
-            let expected = vec![model::Visitor::Array(model::Initializer::Macro(
-                syn::parse_str("abc").unwrap(),
-            ))];
+            let expected = vec![model::Visitor::Array(model::Initializer::Macro {
+                macro_: syn::parse_str("abc").unwrap(),
+                arguments: vec![
+                    model::InitializerArgument::RelativePath,
+                    model::InitializerArgument::AbsolutePath,
+                ],
+            })];
             assert_eq!(actual, expected);
         }
 
@@ -74,15 +90,23 @@ mod tests {
             let actual = main(
                 model::Template::Default {
                     initializer: Some(syn::parse_str("abc").unwrap()),
+                    initializer_arguments: vec![
+                        model::InitializerArgument::RelativePath,
+                        model::InitializerArgument::AbsolutePath,
+                    ],
                     identifiers: false,
                 },
                 model::stubs::type_structure(),
             );
 
             let actual = actual.unwrap();
-            let expected = vec![model::Visitor::Array(model::Initializer::Macro(
-                syn::parse_str("abc").unwrap(),
-            ))];
+            let expected = vec![model::Visitor::Array(model::Initializer::Macro {
+                macro_: syn::parse_str("abc").unwrap(),
+                arguments: vec![
+                    model::InitializerArgument::RelativePath,
+                    model::InitializerArgument::AbsolutePath,
+                ],
+            })];
             assert_eq!(actual, expected);
         }
 
@@ -91,6 +115,10 @@ mod tests {
             let actual = main(
                 model::Template::Default {
                     initializer: Some(syn::parse_str("abc").unwrap()),
+                    initializer_arguments: vec![
+                        model::InitializerArgument::RelativePath,
+                        model::InitializerArgument::AbsolutePath,
+                    ],
                     identifiers: true,
                 },
                 model::stubs::type_structure(),
@@ -98,7 +126,13 @@ mod tests {
 
             let actual = actual.unwrap();
             let expected = vec![
-                model::Visitor::Array(model::Initializer::Macro(syn::parse_str("abc").unwrap())),
+                model::Visitor::Array(model::Initializer::Macro {
+                    macro_: syn::parse_str("abc").unwrap(),
+                    arguments: vec![
+                        model::InitializerArgument::RelativePath,
+                        model::InitializerArgument::AbsolutePath,
+                    ],
+                }),
                 model::Visitor::Identifiers,
             ];
             assert_eq!(actual, expected);
@@ -111,7 +145,9 @@ mod tests {
             model::Template::Visitors(vec![model::CustomVisitor {
                 visit_base: Some(syn::parse_str("visit_base").unwrap()),
                 visit_folder: Some(syn::parse_str("visit_folder").unwrap()),
+                visit_empty_folder: None,
                 visit_file: syn::parse_str("visit_file").unwrap(),
+                extended_inputs: false,
             }]),
             model::stubs::type_structure(),
         );
@@ -120,7 +156,9 @@ mod tests {
         let expected = vec![model::Visitor::Custom(model::CustomVisitor {
             visit_base: Some(syn::parse_str("visit_base").unwrap()),
             visit_folder: Some(syn::parse_str("visit_folder").unwrap()),
+            visit_empty_folder: None,
             visit_file: syn::parse_str("visit_file").unwrap(),
+            extended_inputs: false,
         })];
         assert_eq!(actual, expected);
     }
@@ -0,0 +1,199 @@
+use crate::model;
+
+pub fn main(web: Option<model::Web>, forest: &model::Forest) -> model::Result<Option<model::Web>> {
+    match web {
+        None => Ok(None),
+
+        Some(web) => {
+            let files = model::files(forest).collect::<Vec<_>>();
+
+            for index_file in &web.index_files {
+                let is_selected = files
+                    .iter()
+                    .any(|file| file.relative_path.rsplit('/').next() == Some(index_file.as_str()));
+                if !is_selected {
+                    return Err(model::Error::UnknownWebFile {
+                        option: "index_files".into(),
+                        file: index_file.clone(),
+                    });
+                }
+            }
+
+            if let Some(fallback) = &web.fallback {
+                match files.iter().find(|file| &file.relative_path == fallback) {
+                    None => {
+                        return Err(model::Error::UnknownWebFile {
+                            option: "fallback".into(),
+                            file: fallback.clone(),
+                        });
+                    }
+
+                    // The service itself is not gated, so it would refer to a
+                    // file that may be missing.
+                    Some(file) if file.cfg.is_some() || file.obfuscated.is_some() => {
+                        return Err(model::Error::UnservableWebFile {
+                            option: "fallback".into(),
+                            file: fallback.clone(),
+                        });
+                    }
+
+                    Some(_) => {}
+                }
+            }
+
+            Ok(Some(web))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let web = model::Web {
+            index_files: vec!["index.html".into()],
+            fallback: Some("app.html".into()),
+            trailing_slash: model::TrailingSlash::Redirect,
+            cache: None,
+        };
+
+        let actual = main(Some(web.clone()), &get_forest());
+
+        let expected = Ok(Some(web));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_web_it_gets_none() {
+        let actual = main(None, &get_forest());
+
+        let expected = Ok(None);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_unknown_index_file_it_errs() {
+        let actual = main(
+            Some(model::Web {
+                index_files: vec!["index.html".into(), "index.htm".into()],
+                fallback: None,
+                trailing_slash: model::TrailingSlash::Ignore,
+                cache: None,
+            }),
+            &get_forest(),
+        );
+
+        let expected = Err(model::Error::UnknownWebFile {
+            option: "index_files".into(),
+            file: "index.htm".into(),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_unknown_fallback_it_errs() {
+        let actual = main(
+            Some(model::Web {
+                index_files: vec![],
+                fallback: Some("index.html".into()),
+                trailing_slash: model::TrailingSlash::Ignore,
+                cache: None,
+            }),
+            &get_forest(),
+        );
+
+        let expected = Err(model::Error::UnknownWebFile {
+            option: "fallback".into(),
+            file: "index.html".into(),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_conditional_fallback_it_errs() {
+        let forest = [(
+            "app.html".into(),
+            model::Tree::File(model::File {
+                relative_path: "app.html".into(),
+                cfg: Some(Box::new(syn::parse_quote! { feature = "a" })),
+                ..model::stubs::file()
+            }),
+        )]
+        .into_iter()
+        .collect();
+
+        let actual = main(Some(get_fallback_web()), &forest);
+
+        let expected = Err(model::Error::UnservableWebFile {
+            option: "fallback".into(),
+            file: "app.html".into(),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_obfuscated_fallback_it_errs() {
+        let forest = [(
+            "app.html".into(),
+            model::Tree::File(model::File {
+                relative_path: "app.html".into(),
+                obfuscated: Some(Box::new(model::Obfuscated {
+                    key_variable: "KEY".into(),
+                    nonce: [0; 12],
+                    contents: vec![],
+                })),
+                ..model::stubs::file()
+            }),
+        )]
+        .into_iter()
+        .collect();
+
+        let actual = main(Some(get_fallback_web()), &forest);
+
+        let expected = Err(model::Error::UnservableWebFile {
+            option: "fallback".into(),
+            file: "app.html".into(),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    fn get_fallback_web() -> model::Web {
+        model::Web {
+            index_files: vec![],
+            fallback: Some("app.html".into()),
+            trailing_slash: model::TrailingSlash::Ignore,
+            cache: None,
+        }
+    }
+
+    fn get_forest() -> model::Forest {
+        [
+            (
+                "app.html".into(),
+                model::Tree::File(model::File {
+                    relative_path: "app.html".into(),
+                    ..model::stubs::file()
+                }),
+            ),
+            (
+                "docs".into(),
+                model::Tree::Folder(model::Folder {
+                    forest: [(
+                        "index.html".into(),
+                        model::Tree::File(model::File {
+                            relative_path: "docs/index.html".into(),
+                            ..model::stubs::file()
+                        }),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::folder()
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
@@ -0,0 +1,188 @@
+use super::get_visitors;
+use super::is_contents_used;
+use crate::model;
+
+/// Whether the contents of files are needed during expansion, which is the case
+/// if they are validated, transformed, hashed, parsed, or inlined.
+pub fn main(configuration: &model::Configuration, type_: &model::Type<()>) -> model::Result<bool> {
+    let visitors = get_visitors::main(configuration.template.clone(), type_.structure.clone())?;
+
+    let is_embedded = is_contents_used::main(&visitors)
+        || configuration.dev_mode.is_some()
+        || configuration.overlay_env.is_some();
+    let is_transformed = configuration.text.is_some()
+        || configuration.obfuscation.is_some()
+        || configuration.deduplicate
+        || matches!(configuration.storage, model::Storage::Packed { .. });
+
+    Ok(!configuration.validations.is_empty()
+        || configuration.serve
+        || configuration
+            .web
+            .as_ref()
+            .is_some_and(|web| web.cache.is_some())
+        || (is_embedded && is_transformed)
+        || visitors.iter().any(is_contents_needed))
+}
+
+fn is_contents_needed(visitor: &model::Visitor) -> bool {
+    match visitor {
+        model::Visitor::Array(model::Initializer::Default(structure)) => match structure {
+            model::TypeStructure::Unit => false,
+            model::TypeStructure::TypeAlias(populator) => is_populator_hashed(populator),
+            model::TypeStructure::NamedFields(fields) => fields
+                .iter()
+                .any(|(_, populator)| is_populator_hashed(populator)),
+            model::TypeStructure::TupleFields(populators) => {
+                populators.iter().any(is_populator_hashed)
+            }
+        },
+
+        model::Visitor::Array(model::Initializer::Macro { arguments, .. }) => {
+            arguments.contains(&model::InitializerArgument::Size)
+        }
+
+        model::Visitor::Identifiers | model::Visitor::Custom(_) => false,
+    }
+}
+
+fn is_populator_hashed(populator: &model::Populator) -> bool {
+    matches!(
+        populator,
+        model::Populator::CacheControl
+            | model::Populator::Etag
+            | model::Populator::FingerprintedPath
+            | model::Populator::GetDecompressed
+            | model::Populator::GetDecompressedStr
+            | model::Populator::Parsed(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_only_paths_it_skips_contents() {
+        let actual = main(
+            &model::Configuration {
+                template: model::Template::Default {
+                    initializer: None,
+                    initializer_arguments: vec![],
+                    identifiers: true,
+                },
+                ..model::stubs::configuration()
+            },
+            &model::Type {
+                structure: model::TypeStructure::NamedFields(vec![
+                    (quote::format_ident!("relative_path"), ()),
+                    (quote::format_ident!("contents_str"), ()),
+                ]),
+                ..model::stubs::type_()
+            },
+        );
+
+        let actual = actual.unwrap();
+        assert!(!actual);
+    }
+
+    #[test]
+    fn given_hashed_field_it_reads_contents() {
+        let actual = main(
+            &model::Configuration {
+                template: model::Template::Default {
+                    initializer: None,
+                    initializer_arguments: vec![],
+                    identifiers: false,
+                },
+                ..model::stubs::configuration()
+            },
+            &model::Type {
+                structure: model::TypeStructure::NamedFields(vec![(
+                    quote::format_ident!("etag"),
+                    (),
+                )]),
+                ..model::stubs::type_()
+            },
+        );
+
+        let actual = actual.unwrap();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_embedded_contents_with_transformation_it_reads_contents() {
+        let actual = main(
+            &model::Configuration {
+                template: model::Template::Default {
+                    initializer: None,
+                    initializer_arguments: vec![],
+                    identifiers: false,
+                },
+                deduplicate: true,
+                ..model::stubs::configuration()
+            },
+            &model::Type {
+                structure: model::TypeStructure::NamedFields(vec![(
+                    quote::format_ident!("contents_bytes"),
+                    (),
+                )]),
+                ..model::stubs::type_()
+            },
+        );
+
+        let actual = actual.unwrap();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_size_argument_it_reads_contents() {
+        let actual = main(
+            &model::Configuration {
+                template: model::Template::Default {
+                    initializer: Some(syn::parse_str("abc").unwrap()),
+                    initializer_arguments: vec![model::InitializerArgument::Size],
+                    identifiers: false,
+                },
+                ..model::stubs::configuration()
+            },
+            &model::stubs::type_(),
+        );
+
+        let actual = actual.unwrap();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_serving_it_reads_contents() {
+        let actual = main(
+            &model::Configuration {
+                template: model::Template::Visitors(vec![]),
+                serve: true,
+                ..model::stubs::configuration()
+            },
+            &model::stubs::type_(),
+        );
+
+        let actual = actual.unwrap();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_validations_it_reads_contents() {
+        let actual = main(
+            &model::Configuration {
+                template: model::Template::Visitors(vec![]),
+                validations: vec![model::Validation {
+                    paths: "*.json".into(),
+                    validator: model::Validator::Utf8,
+                }],
+                ..model::stubs::configuration()
+            },
+            &model::stubs::type_(),
+        );
+
+        let actual = actual.unwrap();
+        assert!(actual);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::model;
+
+pub fn main(visitors: &[model::Visitor]) -> bool {
+    visitors.iter().any(|visitor| match visitor {
+        model::Visitor::Array(model::Initializer::Default(structure)) => match structure {
+            model::TypeStructure::Unit => false,
+            model::TypeStructure::TypeAlias(populator) => is_contents(populator),
+            model::TypeStructure::NamedFields(fields) => {
+                fields.iter().any(|(_, populator)| is_contents(populator))
+            }
+            model::TypeStructure::TupleFields(populators) => populators.iter().any(is_contents),
+        },
+        _ => false,
+    })
+}
+
+fn is_contents(populator: &model::Populator) -> bool {
+    matches!(
+        populator,
+        model::Populator::ContentsBytes
+            | model::Populator::ContentsStr
+            | model::Populator::GetBytes
+            | model::Populator::GetStr
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = [
+            vec![],
+            vec![model::Visitor::Identifiers],
+            vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::NamedFields(vec![(
+                    quote::format_ident!("relative_path"),
+                    model::Populator::RelativePath,
+                )]),
+            ))],
+            vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TupleFields(vec![
+                    model::Populator::RelativePath,
+                    model::Populator::GetStr,
+                ]),
+            ))],
+        ]
+        .map(|visitors| main(&visitors));
+
+        let expected = [false, false, false, true];
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,159 @@
+use super::check_index_ranges;
+use super::get_blobs;
+use super::get_bundles;
+use super::get_cache;
+use super::get_cfg;
+use super::get_data;
+use super::get_forest;
+use super::get_glob;
+use super::get_lookup;
+use super::get_obfuscation;
+use super::get_packed;
+use super::get_path_keys;
+use super::get_served_files;
+use super::get_visitors;
+use super::get_web;
+use super::normalize_text;
+use super::order_files;
+use crate::model;
+use std::path;
+
+pub fn main(
+    configuration: model::Configuration,
+    type_: model::Type<()>,
+    base_folder: path::PathBuf,
+    paths: Vec<model::Path>,
+    folders: Vec<Vec<String>>,
+    obfuscation_key: Option<String>,
+) -> model::Result<model::View> {
+    let lookup = get_lookup::main(&configuration, base_folder)?;
+    let glob = get_glob::main(configuration.glob)?;
+    let embedded_tree = configuration.embedded_tree || glob;
+    let visitors = get_visitors::main(configuration.template, type_.structure)?;
+    let forest = get_forest::main(paths, folders)?;
+    let forest = order_files::main(configuration.order, &configuration.paths, forest)?;
+    check_index_ranges::main(configuration.folder_functions, &visitors, &forest)?;
+    let forest = get_cfg::main(&configuration.cfgs, forest)?;
+    let forest = get_bundles::main(&configuration.paths, &configuration.bundles, forest)?;
+    let path_keys = get_path_keys::main(configuration.path_lookup, &forest)?;
+    let forest = normalize_text::main(configuration.text.as_ref(), forest)?;
+    let forest = get_obfuscation::main(
+        configuration.obfuscation.as_ref(),
+        obfuscation_key.as_deref(),
+        &visitors,
+        forest,
+    )?;
+    let web = get_web::main(configuration.web, &forest)?;
+    let cache = web.as_ref().and_then(|web| web.cache.as_ref());
+    let forest = get_cache::main(cache, &visitors, forest)?;
+    let forest = get_data::main(&visitors, forest)?;
+    let (forest, blobs) = get_blobs::main(configuration.deduplicate, &visitors, forest);
+    let (forest, packed) = get_packed::main(configuration.storage, &visitors, forest);
+    let served_files = get_served_files::main(configuration.serve, &forest)?;
+    Ok(model::View {
+        type_: type_.name,
+        type_lifetimes: type_.lifetimes,
+        visitors,
+        forest,
+        include_folders: configuration.include_folders,
+        folder_functions: configuration.folder_functions,
+        assets_by_path: configuration.assets_by_path,
+        embedded_tree,
+        tree: configuration.tree,
+        glob,
+        blobs,
+        packed,
+        lookup,
+        path_keys,
+        served_files,
+        web,
+        debug: configuration.debug,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections;
+
+    #[test]
+    fn handles() {
+        let actual = main(
+            model::Configuration {
+                template: model::Template::Default {
+                    initializer: Some(syn::parse_str("abc").unwrap()),
+                    initializer_arguments: vec![
+                        model::InitializerArgument::RelativePath,
+                        model::InitializerArgument::AbsolutePath,
+                    ],
+                    identifiers: true,
+                },
+                debug: true,
+                ..model::stubs::configuration()
+            },
+            model::Type {
+                name: quote::format_ident!("Asset"),
+                ..model::stubs::type_()
+            },
+            "/a".into(),
+            vec![model::Path {
+                relative: vec!["b".into()],
+                absolute: "/a/b".into(),
+                contents: b"c".to_vec(),
+                modified: None,
+            }],
+            vec![],
+            None,
+        );
+
+        let actual = actual.unwrap();
+        let expected = model::View {
+            type_: quote::format_ident!("Asset"),
+            type_lifetimes: 0,
+            visitors: vec![
+                model::Visitor::Array(model::Initializer::Macro {
+                    macro_: syn::parse_str("abc").unwrap(),
+                    arguments: vec![
+                        model::InitializerArgument::RelativePath,
+                        model::InitializerArgument::AbsolutePath,
+                    ],
+                }),
+                model::Visitor::Identifiers,
+            ],
+            forest: [(
+                "b".into(),
+                model::Tree::File(model::File {
+                    identifier: quote::format_ident!("r#B"),
+                    index: 0,
+                    relative_path: "b".into(),
+                    absolute_path: "/a/b".into(),
+                    contents: b"c".to_vec(),
+                    normalization: None,
+                    cfg: None,
+                    obfuscated: None,
+                    blob: None,
+                    slice: None,
+                    data: collections::BTreeMap::new(),
+                    modified: None,
+                    cache: None,
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            include_folders: false,
+            folder_functions: false,
+            assets_by_path: false,
+            embedded_tree: false,
+            tree: false,
+            glob: false,
+            blobs: vec![],
+            packed: None,
+            lookup: None,
+            path_keys: None,
+            served_files: None,
+            web: None,
+            debug: true,
+        };
+        assert_eq!(actual, expected);
+    }
+}
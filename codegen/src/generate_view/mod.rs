@@ -0,0 +1,25 @@
+mod check_index_ranges;
+mod get_blobs;
+mod get_bundles;
+mod get_cache;
+mod get_cfg;
+mod get_data;
+mod get_default_initializer;
+mod get_forest;
+mod get_glob;
+mod get_lookup;
+mod get_obfuscation;
+mod get_packed;
+mod get_path_keys;
+mod get_served_files;
+mod get_visitors;
+mod get_web;
+mod is_contents_read;
+mod is_contents_used;
+mod main;
+mod normalize_text;
+mod order_files;
+mod sanitize_name;
+
+pub use is_contents_read::main as is_contents_read;
+pub use main::main;
@@ -0,0 +1,157 @@
+use crate::match_paths;
+use crate::model;
+
+pub fn main(text: Option<&model::Text>, forest: model::Forest) -> model::Result<model::Forest> {
+    match text {
+        None => Ok(forest),
+        Some(text) => {
+            let filter = match_paths::main(&text.paths)?;
+            Ok(normalize_forest(&filter, text.normalization, forest))
+        }
+    }
+}
+
+fn normalize_forest(
+    filter: &match_paths::Filter,
+    normalization: model::Normalization,
+    forest: model::Forest,
+) -> model::Forest {
+    forest
+        .into_iter()
+        .map(|(name, tree)| {
+            let tree = match tree {
+                model::Tree::File(file) if filter.is_match(&file.relative_path) => {
+                    model::Tree::File(model::File {
+                        contents: normalize(&file.contents, normalization),
+                        normalization: Some(normalization),
+                        ..file
+                    })
+                }
+
+                model::Tree::File(file) => model::Tree::File(file),
+
+                model::Tree::Folder(model::Folder { identifier, forest }) => {
+                    model::Tree::Folder(model::Folder {
+                        identifier,
+                        forest: normalize_forest(filter, normalization, forest),
+                    })
+                }
+            };
+            (name, tree)
+        })
+        .collect()
+}
+
+fn normalize(contents: &[u8], normalization: model::Normalization) -> Vec<u8> {
+    iftree_runtime::normalize(
+        contents,
+        iftree_runtime::Normalization {
+            line_ending: normalization
+                .line_endings
+                .map(|line_ending| match line_ending {
+                    model::LineEnding::Crlf => iftree_runtime::LineEnding::Crlf,
+                    model::LineEnding::Lf => iftree_runtime::LineEnding::Lf,
+                }),
+            strip_bom: normalization.strip_bom,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_text_configuration() {
+        let forest = [(
+            "a".into(),
+            model::Tree::File(model::File {
+                contents: b"\xEF\xBB\xBFb\r\n".to_vec(),
+                ..model::stubs::file()
+            }),
+        )]
+        .into_iter()
+        .collect::<model::Forest>();
+
+        let actual = main(None, forest.clone());
+
+        let actual = actual.unwrap();
+        let expected = forest;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn normalizes_matching_files_only() {
+        let normalization = model::Normalization {
+            line_endings: Some(model::LineEnding::Lf),
+            strip_bom: true,
+        };
+
+        let actual = main(
+            Some(&model::Text {
+                paths: "*.txt".into(),
+                normalization,
+            }),
+            [
+                (
+                    "a".into(),
+                    model::Tree::Folder(model::Folder {
+                        forest: [(
+                            "b.txt".into(),
+                            model::Tree::File(model::File {
+                                relative_path: "a/b.txt".into(),
+                                contents: b"\xEF\xBB\xBFc\r\nd".to_vec(),
+                                ..model::stubs::file()
+                            }),
+                        )]
+                        .into_iter()
+                        .collect(),
+                        ..model::stubs::folder()
+                    }),
+                ),
+                (
+                    "e.bin".into(),
+                    model::Tree::File(model::File {
+                        relative_path: "e.bin".into(),
+                        contents: b"f\r\n".to_vec(),
+                        ..model::stubs::file()
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let actual = actual.unwrap();
+        let expected = [
+            (
+                "a".into(),
+                model::Tree::Folder(model::Folder {
+                    forest: [(
+                        "b.txt".into(),
+                        model::Tree::File(model::File {
+                            relative_path: "a/b.txt".into(),
+                            contents: b"c\nd".to_vec(),
+                            normalization: Some(normalization),
+                            ..model::stubs::file()
+                        }),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::folder()
+                }),
+            ),
+            (
+                "e.bin".into(),
+                model::Tree::File(model::File {
+                    relative_path: "e.bin".into(),
+                    contents: b"f\r\n".to_vec(),
+                    ..model::stubs::file()
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::match_paths;
+use crate::model;
+use std::collections;
+
+pub fn main(
+    order: model::Order,
+    paths: &str,
+    mut forest: model::Forest,
+) -> model::Result<model::Forest> {
+    let mut relative_paths = vec![];
+    collect_relative_paths(&forest, &mut relative_paths);
+
+    match order {
+        model::Order::Tree => return Ok(forest),
+        model::Order::Path => relative_paths.sort(),
+        model::Order::Pattern => {
+            let filters = paths
+                .lines()
+                .filter(|pattern| !(pattern.is_empty() || pattern.starts_with(['!', '#'])))
+                .map(match_paths::main)
+                .collect::<model::Result<Vec<_>>>()?;
+
+            // Files not matched by any single pattern on their own come last.
+            relative_paths.sort_by_cached_key(|relative_path| {
+                filters
+                    .iter()
+                    .position(|filter| filter.is_match(relative_path))
+                    .unwrap_or(filters.len())
+            });
+        }
+    }
+
+    let index_by_relative_path = relative_paths
+        .into_iter()
+        .enumerate()
+        .map(|(index, relative_path)| (relative_path, index))
+        .collect();
+    assign_indices(&index_by_relative_path, &mut forest);
+    Ok(forest)
+}
+
+/// Collects relative paths in the order of the indices.
+fn collect_relative_paths(forest: &model::Forest, relative_paths: &mut Vec<String>) {
+    let mut files = model::files(forest).collect::<Vec<_>>();
+    files.sort_by_key(|file| file.index);
+    relative_paths.extend(files.into_iter().map(|file| file.relative_path.clone()));
+}
+
+fn assign_indices(
+    index_by_relative_path: &collections::HashMap<String, usize>,
+    forest: &mut model::Forest,
+) {
+    for tree in forest.values_mut() {
+        match tree {
+            model::Tree::File(file) => file.index = index_by_relative_path[&file.relative_path],
+            model::Tree::Folder(model::Folder { forest, .. }) => {
+                assign_indices(index_by_relative_path, forest)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_tree_order_it_keeps_indices() {
+        let forest = get_forest();
+
+        let actual = main(model::Order::Tree, "", forest.clone());
+
+        let actual = actual.unwrap();
+        let expected = forest;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_path_order_it_sorts_by_relative_path() {
+        let actual = main(model::Order::Path, "", get_forest());
+
+        let actual = get_relative_paths(&actual.unwrap());
+        let expected = vec!["a.css", "a/b.css", "c.js"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_pattern_order_it_sorts_by_first_matching_pattern() {
+        let actual = main(
+            model::Order::Pattern,
+            "# Base styles last.
+/*.js
+!/x
+
+/a/**
+*.css",
+            get_forest(),
+        );
+
+        let actual = get_relative_paths(&actual.unwrap());
+        let expected = vec!["c.js", "a/b.css", "a.css"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_invalid_pattern_it_errs() {
+        let actual = main(model::Order::Pattern, "[", get_forest());
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    fn get_forest() -> model::Forest {
+        let file = |index, relative_path: &str| {
+            model::Tree::File(model::File {
+                index,
+                relative_path: relative_path.into(),
+                ..model::stubs::file()
+            })
+        };
+
+        [
+            (
+                "a".into(),
+                model::Tree::Folder(model::Folder {
+                    forest: [("b.css".into(), file(0, "a/b.css"))].into_iter().collect(),
+                    ..model::stubs::folder()
+                }),
+            ),
+            ("a.css".into(), file(1, "a.css")),
+            ("c.js".into(), file(2, "c.js")),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn get_relative_paths(forest: &model::Forest) -> Vec<String> {
+        let mut relative_paths = vec![];
+        collect_relative_paths(forest, &mut relative_paths);
+        relative_paths
+    }
+}
@@ -0,0 +1,44 @@
+use sha2::Digest;
+
+/// Gets a strong entity tag including quotes from a hash of the contents.
+pub fn main(contents: &[u8]) -> String {
+    let hash = get_hash(contents, 32);
+    format!("\"{hash}\"")
+}
+
+/// Gets a short hash of the contents for fingerprinted paths, which is a prefix
+/// of the hash in the entity tag.
+pub fn get_fingerprint(contents: &[u8]) -> String {
+    get_hash(contents, iftree_runtime::FINGERPRINT_LENGTH)
+}
+
+/// Gets the first `length` hexadecimal digits of a hash of the contents.
+fn get_hash(contents: &[u8], length: usize) -> String {
+    let mut hash = sha2::Sha256::digest(contents)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    hash.truncate(length);
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = main(b"a");
+
+        let expected = "\"ca978112ca1bbdcafac231b39a23dc4d\"";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_fingerprint() {
+        let actual = get_fingerprint(b"a");
+
+        let expected = "ca978112";
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,201 @@
+use super::generate_view;
+use super::list_files;
+use super::model;
+use super::print;
+use std::env;
+
+pub fn main(
+    configuration: model::Configuration,
+    item: proc_macro2::TokenStream,
+    type_: model::Type<()>,
+) -> model::Result<proc_macro2::TokenStream> {
+    // Pipeline overview:
+    // 1. and 2. are in `get_view`.
+    let view = get_view(configuration, type_)?;
+    // 3. Generate code ("view").
+    Ok(print::main(item, view))
+}
+
+/// Runs the first two steps of the pipeline, up to the view model.
+pub fn get_view(
+    configuration: model::Configuration,
+    type_: model::Type<()>,
+) -> model::Result<model::View> {
+    let is_contents_read = generate_view::is_contents_read(&configuration, &type_)?;
+    // 1. I/O only happens here.
+    let (base_folder, paths, folders) = list_files::main(&configuration, is_contents_read)?;
+    let obfuscation_key = list_files::get_obfuscation_key(&configuration, &|name| env::var(name))?;
+    // 2. Construct a view model.
+    generate_view::main(
+        configuration,
+        type_,
+        base_folder,
+        paths,
+        folders,
+        obfuscation_key,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = main(
+            model::Configuration {
+                paths: "/assets/*.md".into(),
+                bundles: vec![],
+                base_folder: "../examples".into(),
+                root_folder_variable: "CARGO_MANIFEST_DIR".into(),
+                include_folders: false,
+                folder_functions: false,
+                assets_by_path: false,
+                embedded_tree: false,
+                tree: false,
+                glob: false,
+                order: model::Order::Tree,
+                path_lookup: model::PathLookup::default(),
+                web: None,
+                serve: false,
+                template: model::Template::Default {
+                    initializer: None,
+                    initializer_arguments: vec![
+                        model::InitializerArgument::RelativePath,
+                        model::InitializerArgument::AbsolutePath,
+                    ],
+                    identifiers: true,
+                },
+                validations: vec![],
+                cfgs: vec![],
+                text: None,
+                obfuscation: None,
+                deduplicate: false,
+                storage: model::Storage::Files,
+                dev_mode: None,
+                overlay_env: None,
+                debug: false,
+            },
+            quote::quote! {
+                pub struct Asset {
+                    relative_path: &'static str,
+                }
+            },
+            model::Type {
+                name: quote::format_ident!("Asset"),
+                lifetimes: 0,
+                structure: model::TypeStructure::NamedFields(vec![(
+                    quote::format_ident!("relative_path"),
+                    (),
+                )]),
+            },
+        );
+
+        let actual = actual.unwrap().to_string();
+        let expected = quote::quote! {
+            pub struct Asset {
+                relative_path: &'static str,
+            }
+
+            pub static ASSETS: [Asset; 1usize] = [
+                Asset {
+                    relative_path: "assets/credits.md",
+                },
+            ];
+
+            pub mod base {
+                #[doc = "assets"]
+                pub mod r#assets {
+                    #[doc = "credits.md"]
+                    pub static r#CREDITS_MD: &super::super::Asset = &super::super::ASSETS[0usize];
+                }
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_conditional_fallback_it_errs() {
+        let actual = get_view(
+            syn::parse_str(
+                r#""
+paths = '/assets/*.md'
+base_folder = '../examples'
+
+[web]
+fallback = 'assets/credits.md'
+
+[[cfg]]
+paths = '/assets/credits.md'
+predicate = 'feature = \"nope\"'
+""#,
+            )
+            .unwrap(),
+            model::stubs::type_(),
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::UnservableWebFile {
+            option: "fallback".into(),
+            file: "assets/credits.md".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "obfuscate")]
+    #[test]
+    fn given_obfuscated_fallback_it_errs() {
+        let actual = get_view(
+            syn::parse_str(
+                r#""
+paths = '/assets/*.md'
+base_folder = '../examples'
+
+[web]
+fallback = 'assets/credits.md'
+
+[obfuscate]
+paths = '/assets/credits.md'
+key_env = 'CARGO_PKG_NAME'
+""#,
+            )
+            .unwrap(),
+            model::stubs::type_(),
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::UnservableWebFile {
+            option: "fallback".into(),
+            file: "assets/credits.md".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_folder_functions_with_scattered_folder_it_errs() {
+        let actual = get_view(
+            syn::parse_str(
+                r#""
+paths = [
+    '/assets/world/levels/*',
+    '/assets/credits.md',
+    '/assets/world/*',
+]
+base_folder = '../examples'
+order = 'pattern'
+folder_functions = true
+""#,
+            )
+            .unwrap(),
+            model::stubs::type_(),
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::ScatteredFolder {
+            folder: "assets/world".into(),
+            usage: "configuration \"folder_functions\"".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,41 @@
+//! Code generation of [Iftree](https://docs.rs/iftree).
+//!
+//! Depend on the crate `iftree` instead of using this crate directly. It is used
+//! by the attribute macro `iftree::include_file_tree` as well as by
+//! `iftree::Builder` for build scripts.
+
+mod builder;
+#[cfg(feature = "compression")]
+mod compress;
+mod generate_view;
+mod get_etag;
+mod go;
+mod list_files;
+mod match_paths;
+mod model;
+mod parse;
+mod preview;
+mod print;
+
+pub use builder::Builder;
+pub use builder::Error;
+
+#[doc(hidden)]
+pub fn include_file_tree(
+    parameters: proc_macro2::TokenStream,
+    item: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let configuration = match syn::parse2(parameters) {
+        Err(error) => return error.to_compile_error(),
+        Ok(configuration) => configuration,
+    };
+    let type_ = match syn::parse2(item.clone()) {
+        Err(error) => return error.to_compile_error(),
+        Ok(type_) => type_,
+    };
+
+    match go::main(configuration, item, type_) {
+        Err(error) => panic!("{error}"),
+        Ok(code) => code,
+    }
+}
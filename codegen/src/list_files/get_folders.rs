@@ -0,0 +1,37 @@
+use super::get_paths;
+use crate::model;
+use std::path;
+
+pub fn main(
+    base_folder: &path::Path,
+    folders: Vec<path::PathBuf>,
+) -> model::Result<Vec<Vec<String>>> {
+    folders
+        .into_iter()
+        .map(|folder| get_paths::get_path_components(folder.strip_prefix(base_folder)?))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = main(
+            path::Path::new("/a/b"),
+            vec!["/a/b/c".into(), "/a/b/d/e".into()],
+        );
+
+        let actual = actual.unwrap();
+        let expected = Vec::<Vec<String>>::from([vec!["c".into()], vec!["d".into(), "e".into()]]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_folder_outside_base_folder_it_errs() {
+        let actual = main(path::Path::new("/a/b"), vec!["/a/c".into()]);
+
+        assert!(matches!(actual, Err(model::Error::PathStripPrefix(_))));
+    }
+}
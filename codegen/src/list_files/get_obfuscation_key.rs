@@ -0,0 +1,74 @@
+use crate::model;
+use std::env;
+
+pub fn main(
+    configuration: &model::Configuration,
+    get_environment_variable: &dyn Fn(&str) -> Result<String, env::VarError>,
+) -> model::Result<Option<String>> {
+    configuration
+        .obfuscation
+        .as_ref()
+        .map(|obfuscation| {
+            let name = &obfuscation.key_variable;
+            get_environment_variable(name).map_err(|source| model::Error::EnvironmentVariable {
+                name: name.clone(),
+                source,
+            })
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_obfuscation() {
+        let actual = main(&model::stubs::configuration(), &|_| unreachable!());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn handles_obfuscation() {
+        let actual = main(
+            &model::Configuration {
+                obfuscation: Some(model::Obfuscation {
+                    paths: "*".into(),
+                    key_variable: "KEY".into(),
+                }),
+                ..model::stubs::configuration()
+            },
+            &|name| {
+                assert_eq!(name, "KEY");
+                Ok("secret".into())
+            },
+        );
+
+        let actual = actual.unwrap();
+        let expected = Some("secret".into());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_such_environment_variable_it_errs() {
+        let actual = main(
+            &model::Configuration {
+                obfuscation: Some(model::Obfuscation {
+                    paths: "*".into(),
+                    key_variable: "KEY".into(),
+                }),
+                ..model::stubs::configuration()
+            },
+            &|_| Err(env::VarError::NotPresent),
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::EnvironmentVariable {
+            name: "KEY".into(),
+            source: env::VarError::NotPresent,
+        };
+        assert_eq!(actual, expected);
+    }
+}
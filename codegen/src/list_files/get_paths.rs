@@ -0,0 +1,100 @@
+use crate::model;
+use std::io;
+use std::path;
+use std::time;
+
+pub fn main(
+    base_folder: path::PathBuf,
+    paths: Vec<path::PathBuf>,
+    read_file: &dyn Fn(&path::Path) -> io::Result<Vec<u8>>,
+    get_modified: &dyn Fn(&path::Path) -> Option<time::SystemTime>,
+) -> model::Result<Vec<model::Path>> {
+    paths
+        .into_iter()
+        .map(|path| get_path(&base_folder, path, read_file, get_modified))
+        .collect()
+}
+
+fn get_path(
+    base_folder: &path::Path,
+    path: path::PathBuf,
+    read_file: &dyn Fn(&path::Path) -> io::Result<Vec<u8>>,
+    get_modified: &dyn Fn(&path::Path) -> Option<time::SystemTime>,
+) -> model::Result<model::Path> {
+    let relative = get_path_components(path.strip_prefix(base_folder)?)?;
+    let absolute = get_path_string(&path)?;
+    let contents = read_file(&path).map_err(|error| ignore::Error::WithPath {
+        path: path.clone(),
+        err: Box::new(error.into()),
+    })?;
+    let modified = get_modified(&path);
+
+    Ok(model::Path {
+        relative,
+        absolute,
+        contents,
+        modified,
+    })
+}
+
+pub fn get_path_components(path: &path::Path) -> model::Result<Vec<String>> {
+    path.iter()
+        .map(|component| match component.to_str() {
+            None => Err(model::Error::PathInvalidUnicode(path.to_path_buf())),
+            Some(string) => Ok(string.into()),
+        })
+        .collect()
+}
+
+fn get_path_string(path: &path::Path) -> model::Result<String> {
+    match path.to_str() {
+        None => Err(model::Error::PathInvalidUnicode(path.to_path_buf())),
+        Some(string) => Ok(string.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = main(
+            "/a/b".into(),
+            vec!["/a/b/c".into(), "/a/b/a/b".into()],
+            &|path| Ok(path.to_str().unwrap().into()),
+            &|_| Some(time::UNIX_EPOCH),
+        );
+
+        let actual = actual.unwrap();
+        let expected = vec![
+            model::Path {
+                relative: vec!["c".into()],
+                absolute: "/a/b/c".into(),
+                contents: b"/a/b/c".to_vec(),
+                modified: Some(time::UNIX_EPOCH),
+            },
+            model::Path {
+                relative: vec!["a".into(), "b".into()],
+                absolute: "/a/b/a/b".into(),
+                contents: b"/a/b/a/b".to_vec(),
+                modified: Some(time::UNIX_EPOCH),
+            },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_unreadable_file_it_errs() {
+        let actual = main(
+            "/a".into(),
+            vec!["/a/b".into()],
+            &|_| Err(io::ErrorKind::NotFound.into()),
+            &|_| None,
+        );
+
+        let actual = actual.unwrap_err().to_string();
+        let expected = "/a/b: entity not found";
+        assert_eq!(actual, expected);
+    }
+}
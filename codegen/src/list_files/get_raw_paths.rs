@@ -0,0 +1,383 @@
+use crate::model;
+use ignore::overrides;
+use std::borrow;
+use std::iter;
+use std::path;
+use std::sync;
+
+/// Lists the selected files and, if configured, the selected folders, including
+/// those of bundles. Also lists the folders that were walked in full, that is,
+/// without skipping any subfolder.
+pub fn main(
+    configuration: &model::Configuration,
+    base_folder: &path::Path,
+) -> model::Result<(Vec<path::PathBuf>, Vec<path::PathBuf>, Vec<path::PathBuf>)> {
+    let mut files = vec![];
+    let mut folders = vec![];
+    let mut walked_folders = vec![];
+
+    let patterns = iter::once(&configuration.paths)
+        .chain(configuration.bundles.iter().map(|bundle| &bundle.paths));
+    for patterns in patterns {
+        let filter = get_filter(patterns, base_folder)?;
+        let scope = get_scope(patterns)?;
+        let skipped_folders = sync::Arc::new(sync::Mutex::new(vec![]));
+        let mut entered_folders = vec![];
+
+        for entry in iterate_entries(base_folder, filter.clone(), scope, &skipped_folders) {
+            let entry = entry?;
+            if entry.metadata()?.is_dir() {
+                // The walk enters unmatched folders, too, so only keep matched ones.
+                if configuration.include_folders
+                    && entry.depth() > 0
+                    && filter.matched(entry.path(), true).is_whitelist()
+                {
+                    folders.push(entry.path().into());
+                }
+                entered_folders.push(entry.into_path());
+            } else if !filter.matched(entry.path(), false).is_ignore() {
+                files.push(entry.into_path());
+            }
+        }
+
+        let skipped_folders = skipped_folders.lock().unwrap();
+        walked_folders.extend(entered_folders.into_iter().filter(|folder| {
+            !skipped_folders
+                .iter()
+                .any(|skipped_folder| skipped_folder.starts_with(folder))
+        }));
+    }
+
+    if !configuration.bundles.is_empty() {
+        // Bundles may overlap with each other or the main selection.
+        files.sort();
+        files.dedup();
+        folders.sort();
+        folders.dedup();
+        walked_folders.sort();
+        walked_folders.dedup();
+    }
+
+    Ok((files, folders, walked_folders))
+}
+
+fn iterate_entries(
+    base_folder: &path::Path,
+    filter: overrides::Override,
+    scope: Vec<Option<Vec<Component>>>,
+    skipped_folders: &sync::Arc<sync::Mutex<Vec<path::PathBuf>>>,
+) -> ignore::Walk {
+    let base_folder_ = base_folder.to_path_buf();
+    let skipped_folders = skipped_folders.clone();
+
+    ignore::WalkBuilder::new(base_folder)
+        .standard_filters(false)
+        .filter_entry(move |entry| {
+            if !entry.file_type().is_some_and(|type_| type_.is_dir()) {
+                return true;
+            }
+            let is_entered = !filter.matched(entry.path(), true).is_ignore()
+                && is_in_scope(&scope, &base_folder_, entry.path());
+            if !is_entered {
+                skipped_folders.lock().unwrap().push(entry.path().into());
+            }
+            is_entered
+        })
+        .build()
+}
+
+fn get_filter(patterns: &str, base_folder: &path::Path) -> model::Result<overrides::Override> {
+    let mut builder = overrides::OverrideBuilder::new(base_folder);
+    for pattern in patterns.lines() {
+        builder.add(pattern)?;
+    }
+    let filter = builder.build()?;
+
+    if filter.is_empty() {
+        ignore_everything(base_folder)
+    } else {
+        Ok(filter)
+    }
+}
+
+fn ignore_everything(base_folder: &path::Path) -> model::Result<overrides::Override> {
+    Ok(overrides::OverrideBuilder::new(base_folder)
+        .add("!*")?
+        .build()?)
+}
+
+enum Component {
+    Any,
+    Glob(overrides::Override),
+}
+
+/// Splits each selecting pattern into its path components so that the walk can
+/// skip folders that no pattern reaches, like `target`. A pattern that is not
+/// anchored by a slash reaches any folder, which is represented by `None`.
+fn get_scope(patterns: &str) -> model::Result<Vec<Option<Vec<Component>>>> {
+    patterns
+        .lines()
+        .filter(|pattern| !pattern.is_empty() && !pattern.starts_with(['!', '#']))
+        .map(|pattern| {
+            let pattern = pattern.strip_suffix('/').unwrap_or(pattern);
+            if !pattern.contains('/') {
+                return Ok(None);
+            }
+            pattern
+                .strip_prefix('/')
+                .unwrap_or(pattern)
+                .split('/')
+                .map(|component| {
+                    Ok(if component == "**" {
+                        Component::Any
+                    } else {
+                        Component::Glob(
+                            overrides::OverrideBuilder::new("")
+                                .add(component)?
+                                .build()?,
+                        )
+                    })
+                })
+                .collect::<model::Result<_>>()
+                .map(Some)
+        })
+        .collect()
+}
+
+fn is_in_scope(
+    scope: &[Option<Vec<Component>>],
+    base_folder: &path::Path,
+    folder: &path::Path,
+) -> bool {
+    let folder = folder
+        .strip_prefix(base_folder)
+        .unwrap_or(folder)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>();
+
+    scope.iter().any(|components| match components {
+        None => true,
+        Some(components) => is_in_components(components, &folder),
+    })
+}
+
+fn is_in_components(components: &[Component], folder: &[borrow::Cow<str>]) -> bool {
+    for (index, name) in folder.iter().enumerate() {
+        match components.get(index) {
+            None => return false,
+            Some(Component::Any) => return true,
+            Some(Component::Glob(glob)) => {
+                if !glob.matched(name.as_ref(), true).is_whitelist() {
+                    return false;
+                }
+            }
+        }
+    }
+    components.len() > folder.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_empty_paths() {
+        let actual = main(
+            &model::Configuration {
+                paths: String::new(),
+                ..model::stubs::configuration()
+            },
+            path::Path::new(".."),
+        );
+
+        let actual = actual.unwrap().0;
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn handles_single_path() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/examples/assets/**".into(),
+                ..model::stubs::configuration()
+            },
+            path::Path::new(".."),
+        );
+
+        let mut actual = actual.unwrap().0;
+        actual.sort();
+        let expected = Vec::<path::PathBuf>::from([
+            "../examples/assets/.env".into(),
+            "../examples/assets/configuration/menu.json".into(),
+            "../examples/assets/configuration/translations.csv".into(),
+            "../examples/assets/credits.md".into(),
+            "../examples/assets/world/levels/tutorial.json".into(),
+            "../examples/assets/world/physical_constants.json".into(),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_multiple_paths() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/examples/assets/configuration/**
+/examples/assets/world/**"
+                    .into(),
+                ..model::stubs::configuration()
+            },
+            path::Path::new(".."),
+        );
+
+        let mut actual = actual.unwrap().0;
+        actual.sort();
+        let expected = Vec::<path::PathBuf>::from([
+            "../examples/assets/configuration/menu.json".into(),
+            "../examples/assets/configuration/translations.csv".into(),
+            "../examples/assets/world/levels/tutorial.json".into(),
+            "../examples/assets/world/physical_constants.json".into(),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_negated_patterns() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/examples/assets/**/*.json
+!/examples/assets/world/levels/"
+                    .into(),
+                ..model::stubs::configuration()
+            },
+            path::Path::new(".."),
+        );
+
+        let mut actual = actual.unwrap().0;
+        actual.sort();
+        let expected = Vec::<path::PathBuf>::from([
+            "../examples/assets/configuration/menu.json".into(),
+            "../examples/assets/world/physical_constants.json".into(),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_without_hidden_files() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/examples/assets/*
+!.*"
+                .into(),
+                ..model::stubs::configuration()
+            },
+            path::Path::new(".."),
+        );
+
+        let mut actual = actual.unwrap().0;
+        actual.sort();
+        let expected = Vec::<path::PathBuf>::from(["../examples/assets/credits.md".into()]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_folders() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/examples/assets/**
+!*.json"
+                    .into(),
+                include_folders: true,
+                ..model::stubs::configuration()
+            },
+            path::Path::new(".."),
+        );
+
+        let (mut files, mut folders, _) = actual.unwrap();
+        files.sort();
+        folders.sort();
+        let expected_files = Vec::<path::PathBuf>::from([
+            "../examples/assets/.env".into(),
+            "../examples/assets/configuration/translations.csv".into(),
+            "../examples/assets/credits.md".into(),
+        ]);
+        let expected_folders = Vec::<path::PathBuf>::from([
+            "../examples/assets/configuration".into(),
+            "../examples/assets/world".into(),
+            "../examples/assets/world/levels".into(),
+        ]);
+        assert_eq!((files, folders), (expected_files, expected_folders));
+    }
+
+    #[test]
+    fn handles_bundles() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/examples/assets/*.md".into(),
+                bundles: vec![
+                    model::Bundle {
+                        paths: "/examples/assets/world/*".into(),
+                        predicate: syn::parse_str("a").unwrap(),
+                    },
+                    model::Bundle {
+                        paths: "/examples/assets/**/*.json
+!/examples/assets/world/"
+                            .into(),
+                        predicate: syn::parse_str("b").unwrap(),
+                    },
+                ],
+                ..model::stubs::configuration()
+            },
+            path::Path::new(".."),
+        );
+
+        let actual = actual.unwrap().0;
+        let expected = Vec::<path::PathBuf>::from([
+            "../examples/assets/configuration/menu.json".into(),
+            "../examples/assets/credits.md".into(),
+            "../examples/assets/world/physical_constants.json".into(),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_folders_are_not_included_it_omits_them() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/examples/assets/**".into(),
+                ..model::stubs::configuration()
+            },
+            path::Path::new(".."),
+        );
+
+        let actual = actual.unwrap().1;
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn handles_walked_folders() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/examples/*/world/**/*.json".into(),
+                ..model::stubs::configuration()
+            },
+            path::Path::new(".."),
+        );
+
+        let (mut files, _, mut walked_folders) = actual.unwrap();
+        files.sort();
+        walked_folders.sort();
+        let expected_files = Vec::<path::PathBuf>::from([
+            "../examples/assets/world/levels/tutorial.json".into(),
+            "../examples/assets/world/physical_constants.json".into(),
+        ]);
+        let expected_walked_folders = Vec::<path::PathBuf>::from([
+            "../examples/assets/world".into(),
+            "../examples/assets/world/levels".into(),
+        ]);
+        assert_eq!(
+            (files, walked_folders),
+            (expected_files, expected_walked_folders),
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use super::get_base_folder;
+use super::get_raw_paths;
+use crate::model;
+use std::env;
+use std::path;
+
+/// Lists the paths to watch for changes: the selected files and the folders
+/// walked in full, where new files would be selected, too.
+pub fn main(configuration: &model::Configuration) -> model::Result<Vec<path::PathBuf>> {
+    let base_folder = get_base_folder::main(configuration, &|name| env::var(name))?;
+    let (files, _, folders) = get_raw_paths::main(configuration, &base_folder)?;
+    Ok(files.into_iter().chain(folders).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = main(&model::Configuration {
+            paths: "/assets/world/**".into(),
+            base_folder: "../examples".into(),
+            root_folder_variable: "CARGO_MANIFEST_DIR".into(),
+            ..model::stubs::configuration()
+        });
+
+        let mut actual = actual.unwrap();
+        actual.sort();
+        let base_folder = path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("..")
+            .join("examples");
+        let expected = vec![
+            base_folder.join("assets").join("world"),
+            base_folder.join("assets").join("world").join("levels"),
+            base_folder
+                .join("assets")
+                .join("world")
+                .join("levels")
+                .join("tutorial.json"),
+            base_folder
+                .join("assets")
+                .join("world")
+                .join("physical_constants.json"),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,102 @@
+use super::get_base_folder;
+use super::get_folders;
+use super::get_paths;
+use super::get_raw_paths;
+use super::validate_files;
+use crate::model;
+use std::env;
+use std::fs;
+use std::path;
+
+pub fn main(
+    configuration: &model::Configuration,
+    is_contents_read: bool,
+) -> model::Result<(path::PathBuf, Vec<model::Path>, Vec<Vec<String>>)> {
+    let base_folder = get_base_folder::main(configuration, &|name| env::var(name))?;
+    let (paths, folders, _) = get_raw_paths::main(configuration, &base_folder)?;
+    let folders = get_folders::main(&base_folder, folders)?;
+    let paths = get_paths::main(
+        base_folder.clone(),
+        paths,
+        &|path| {
+            if is_contents_read {
+                fs::read(path)
+            } else {
+                Ok(vec![])
+            }
+        },
+        &|path| {
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        },
+    )?;
+    validate_files::main(configuration, &paths, &validate_files::run_command)?;
+    Ok((base_folder, paths, folders))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn handles() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/assets/*.md".into(),
+                base_folder: "../examples".into(),
+                root_folder_variable: "CARGO_MANIFEST_DIR".into(),
+                ..model::stubs::configuration()
+            },
+            true,
+        );
+
+        let actual = actual.unwrap();
+        let base_folder = path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("..")
+            .join("examples");
+        let expected = (
+            base_folder.clone(),
+            vec![model::Path {
+                relative: vec!["assets".into(), "credits.md".into()],
+                absolute: base_folder
+                    .join("assets")
+                    .join("credits.md")
+                    .into_os_string()
+                    .into_string()
+                    .unwrap(),
+                contents: b"Boo Far\n".to_vec(),
+                modified: Some(
+                    fs::metadata(base_folder.join("assets").join("credits.md"))
+                        .unwrap()
+                        .modified()
+                        .unwrap(),
+                ),
+            }],
+            vec![],
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_contents_are_not_needed_it_skips_reading_them() {
+        let actual = main(
+            &model::Configuration {
+                paths: "/assets/*.md".into(),
+                base_folder: "../examples".into(),
+                root_folder_variable: "CARGO_MANIFEST_DIR".into(),
+                ..model::stubs::configuration()
+            },
+            false,
+        );
+
+        let (_, paths, _) = actual.unwrap();
+        let actual = paths
+            .into_iter()
+            .map(|path| path.contents)
+            .collect::<Vec<_>>();
+        let expected = vec![Vec::<u8>::new()];
+        assert_eq!(actual, expected);
+    }
+}
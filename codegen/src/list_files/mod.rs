@@ -0,0 +1,13 @@
+mod get_base_folder;
+mod get_folders;
+mod get_obfuscation_key;
+mod get_paths;
+mod get_raw_paths;
+mod get_tracked_paths;
+mod main;
+mod validate_files;
+
+pub use get_base_folder::main as get_base_folder;
+pub use get_obfuscation_key::main as get_obfuscation_key;
+pub use get_tracked_paths::main as get_tracked_paths;
+pub use main::main;
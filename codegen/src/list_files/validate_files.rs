@@ -0,0 +1,325 @@
+use crate::match_paths;
+use crate::model;
+use std::process;
+use std::str;
+
+type RunCommand = dyn Fn(&[String], &str) -> Result<(), String>;
+
+pub fn main(
+    configuration: &model::Configuration,
+    paths: &[model::Path],
+    run_command: &RunCommand,
+) -> model::Result<()> {
+    let mut invalid_files = vec![];
+
+    for validation in &configuration.validations {
+        check_feature(&validation.validator)?;
+        let filter = match_paths::main(&validation.paths)?;
+
+        for path in paths {
+            if filter.is_match(&path.relative.join("/")) {
+                if let Err(problem) = validate(&validation.validator, path, run_command) {
+                    invalid_files.push(model::InvalidFile {
+                        path: path.absolute.clone().into(),
+                        problem,
+                    });
+                }
+            }
+        }
+    }
+
+    if invalid_files.is_empty() {
+        Ok(())
+    } else {
+        Err(model::Error::InvalidFiles(invalid_files))
+    }
+}
+
+fn check_feature(validator: &model::Validator) -> model::Result<()> {
+    let (validator, feature) = match validator {
+        model::Validator::Json if !cfg!(feature = "format-json") => ("json", "format-json"),
+        model::Validator::Toml if !cfg!(feature = "format-toml") => ("toml", "format-toml"),
+        model::Validator::Xml if !cfg!(feature = "format-xml") => ("xml", "format-xml"),
+        _ => return Ok(()),
+    };
+
+    Err(model::Error::DisabledFeature {
+        feature: feature.into(),
+        usage: format!("validator {validator:?}"),
+    })
+}
+
+fn validate(
+    validator: &model::Validator,
+    path: &model::Path,
+    run_command: &RunCommand,
+) -> Result<(), String> {
+    match validator {
+        model::Validator::Command(command) => run_command(command, &path.absolute),
+
+        model::Validator::Json => validate_json(&path.contents),
+
+        model::Validator::Toml => validate_toml(&path.contents),
+
+        model::Validator::Utf8 => {
+            str::from_utf8(&path.contents).map_err(|error| format!("invalid UTF-8: {error}"))?;
+            Ok(())
+        }
+
+        model::Validator::Xml => validate_xml(&path.contents),
+    }
+}
+
+#[cfg(feature = "format-json")]
+fn validate_json(contents: &[u8]) -> Result<(), String> {
+    serde_json::from_slice::<serde::de::IgnoredAny>(contents)
+        .map_err(|error| format!("invalid JSON: {error}"))?;
+    Ok(())
+}
+
+#[cfg(not(feature = "format-json"))]
+fn validate_json(_: &[u8]) -> Result<(), String> {
+    unreachable!()
+}
+
+#[cfg(feature = "format-toml")]
+fn validate_toml(contents: &[u8]) -> Result<(), String> {
+    str::from_utf8(contents)
+        .map_err(|error| error.to_string())
+        .and_then(|contents| {
+            toml::from_str::<toml::Table>(contents).map_err(|error| error.to_string())
+        })
+        .map_err(|error| format!("invalid TOML: {error}"))?;
+    Ok(())
+}
+
+#[cfg(not(feature = "format-toml"))]
+fn validate_toml(_: &[u8]) -> Result<(), String> {
+    unreachable!()
+}
+
+#[cfg(feature = "format-xml")]
+fn validate_xml(contents: &[u8]) -> Result<(), String> {
+    str::from_utf8(contents)
+        .map_err(|error| error.to_string())
+        .and_then(|contents| {
+            roxmltree::Document::parse(contents).map_err(|error| error.to_string())
+        })
+        .map_err(|error| format!("invalid XML: {error}"))?;
+    Ok(())
+}
+
+#[cfg(not(feature = "format-xml"))]
+fn validate_xml(_: &[u8]) -> Result<(), String> {
+    unreachable!()
+}
+
+pub fn run_command(command: &[String], path: &str) -> Result<(), String> {
+    let Some((program, arguments)) = command.split_first() else {
+        return Err("command is empty".into());
+    };
+
+    let output = process::Command::new(program)
+        .args(arguments)
+        .arg(path)
+        .output()
+        .map_err(|error| format!("unable to run command {command:?}: {error}"))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let status = output.status;
+        let error = String::from_utf8_lossy(&output.stderr);
+        let error = error.trim();
+        Err(format!("command {command:?} failed ({status}): {error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_validations() {
+        let actual = main(
+            &model::Configuration {
+                validations: vec![],
+                ..model::stubs::configuration()
+            },
+            &[model::stubs::path()],
+            &|_, _| unreachable!(),
+        );
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn validates_matching_files_only() {
+        let actual = main(
+            &model::Configuration {
+                validations: vec![model::Validation {
+                    paths: "*.txt".into(),
+                    validator: model::Validator::Utf8,
+                }],
+                ..model::stubs::configuration()
+            },
+            &[
+                model::Path {
+                    relative: vec!["a".into(), "b.txt".into()],
+                    absolute: "/a/b.txt".into(),
+                    contents: b"\xFF".to_vec(),
+                    modified: None,
+                },
+                model::Path {
+                    relative: vec!["c.bin".into()],
+                    absolute: "/c.bin".into(),
+                    contents: b"\xFF".to_vec(),
+                    modified: None,
+                },
+                model::Path {
+                    relative: vec!["d.txt".into()],
+                    absolute: "/d.txt".into(),
+                    contents: b"d".to_vec(),
+                    modified: None,
+                },
+            ],
+            &|_, _| unreachable!(),
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::InvalidFiles(vec![model::InvalidFile {
+            path: "/a/b.txt".into(),
+            problem: "invalid UTF-8: invalid utf-8 sequence of 1 bytes from index 0".into(),
+        }]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_command() {
+        let actual = main(
+            &model::Configuration {
+                validations: vec![model::Validation {
+                    paths: "*".into(),
+                    validator: model::Validator::Command(vec!["check".into()]),
+                }],
+                ..model::stubs::configuration()
+            },
+            &[
+                model::Path {
+                    absolute: "/a".into(),
+                    ..model::stubs::path()
+                },
+                model::Path {
+                    absolute: "/b".into(),
+                    ..model::stubs::path()
+                },
+            ],
+            &|command, path| {
+                assert_eq!(command, ["check"]);
+                if path == "/a" {
+                    Ok(())
+                } else {
+                    Err(format!("{path} is bad"))
+                }
+            },
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::InvalidFiles(vec![model::InvalidFile {
+            path: "/b".into(),
+            problem: "/b is bad".into(),
+        }]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_invalid_pattern_it_errs() {
+        let actual = main(
+            &model::Configuration {
+                validations: vec![model::Validation {
+                    paths: "[".into(),
+                    validator: model::Validator::Utf8,
+                }],
+                ..model::stubs::configuration()
+            },
+            &[],
+            &|_, _| unreachable!(),
+        );
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[cfg(not(feature = "format-xml"))]
+    #[test]
+    fn given_disabled_feature_it_errs() {
+        let actual = main(
+            &model::Configuration {
+                validations: vec![model::Validation {
+                    paths: "*".into(),
+                    validator: model::Validator::Xml,
+                }],
+                ..model::stubs::configuration()
+            },
+            &[],
+            &|_, _| unreachable!(),
+        );
+
+        let actual = actual.unwrap_err();
+        let expected = model::Error::DisabledFeature {
+            feature: "format-xml".into(),
+            usage: "validator \"xml\"".into(),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-json")]
+    #[test]
+    fn handles_json() {
+        let actual = [&b"{\"a\": [1]}"[..], b"{\"a\": }"].map(validate_json);
+
+        let expected = [
+            Ok(()),
+            Err("invalid JSON: expected value at line 1 column 7".into()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-toml")]
+    #[test]
+    fn handles_toml() {
+        let actual = [&b"a = 1"[..], b"a ="].map(|contents| validate_toml(contents).is_ok());
+
+        let expected = [true, false];
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "format-xml")]
+    #[test]
+    fn handles_xml() {
+        let actual = [&b"<a><b/></a>"[..], b"<a><b></a>"].map(validate_xml);
+
+        let expected = [
+            Ok(()),
+            Err("invalid XML: expected 'b' tag, not 'a' at 1:7".into()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn runs_command() {
+        let actual = [
+            run_command(&["test".into(), "-e".into()], "/"),
+            run_command(&["test".into(), "-d".into()], "/dev/null"),
+            run_command(&[], "/"),
+        ];
+
+        let expected = [
+            Ok(()),
+            Err("command [\"test\", \"-d\"] failed (exit status: 1): ".into()),
+            Err("command is empty".into()),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::model;
+use ignore::overrides;
+use std::path;
+
+pub fn main(patterns: &str) -> model::Result<Filter> {
+    let mut builder = overrides::OverrideBuilder::new("");
+    for pattern in patterns.lines() {
+        builder.add(pattern)?;
+    }
+    Ok(Filter(builder.build()?))
+}
+
+pub struct Filter(overrides::Override);
+
+impl Filter {
+    pub fn is_match(&self, relative_path: &str) -> bool {
+        // Like a walk over the file tree, skip files in excluded folders.
+        let is_in_excluded_folder = path::Path::new(relative_path)
+            .ancestors()
+            .skip(1)
+            .filter(|folder| !folder.as_os_str().is_empty())
+            .any(|folder| self.0.matched(folder, true).is_ignore());
+
+        !is_in_excluded_folder && self.0.matched(relative_path, false).is_whitelist()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_empty_patterns() {
+        let filter = main("").unwrap();
+
+        let actual = filter.is_match("a");
+
+        assert!(!actual);
+    }
+
+    #[test]
+    fn handles_patterns_like_paths_configuration() {
+        let filter = main(
+            "/a/**/*.json
+b.json
+!/a/c/",
+        )
+        .unwrap();
+
+        let actual = ["a/b.json", "a/c/d.json", "a/e.md", "f/b.json", "g.json"]
+            .into_iter()
+            .filter(|path| filter.is_match(path))
+            .collect::<Vec<_>>();
+
+        let expected = vec!["a/b.json", "f/b.json"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_invalid_pattern_it_errs() {
+        let actual = main("[");
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+}
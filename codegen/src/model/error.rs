@@ -0,0 +1,380 @@
+use super::main;
+use std::error;
+use std::fmt;
+use std::path;
+
+impl PartialEq for main::IgnoreError {
+    fn eq(&self, other: &Self) -> bool {
+        format!("{self:?}") == format!("{other:?}")
+    }
+}
+
+impl fmt::Display for main::Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            main::Error::CaseInsensitivePathCollision {
+                relative_path,
+                other_relative_path,
+            } => write!(
+                formatter,
+                "Paths collide when ignoring case, consider renaming one: \
+                {relative_path:?} and {other_relative_path:?}",
+            ),
+
+            main::Error::DisabledFeature { feature, usage } => write!(
+                formatter,
+                "Feature {feature:?} of this crate is required for {usage}. \
+                Enable it in your manifest (\"Cargo.toml\").",
+            ),
+
+            main::Error::EnvironmentVariable { name, source } => write!(
+                formatter,
+                "Unable to get environment variable {name:?}: {source}",
+            ),
+
+            main::Error::Ignore(main::IgnoreError(error)) => write!(formatter, "{error}"),
+
+            main::Error::InvalidFiles(files) => {
+                formatter.write_str("Files failed validation:")?;
+                for main::InvalidFile { path, problem } in files {
+                    write!(formatter, "\n- {path:?}: {problem}")?;
+                }
+                Ok(())
+            }
+
+            main::Error::NoInitializer => formatter.write_str(
+                "No initializer. \
+                Configure one with \"template.initializer = 'a_macro'\" or \
+                use standard fields to generate a default initializer.",
+            ),
+
+            main::Error::NonstandardField {
+                field,
+                standard_fields,
+            } => {
+                let field = field.to_string();
+                let standard_fields = standard_fields
+                    .iter()
+                    .map(|field| {
+                        let field = field.to_string();
+                        format!("{field:?}")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    formatter,
+                    "Default initializer cannot be generated \
+                    as field {field:?} is not standard. \
+                    Configure an initializer with \"template.initializer = 'a_macro'\" or \
+                    use standard fields only ({standard_fields}).",
+                )
+            }
+
+            main::Error::Parse {
+                path,
+                format,
+                message,
+            } => write!(
+                formatter,
+                "Unable to parse file {path:?} as {format}: {message}",
+            ),
+
+            main::Error::PathInvalidUnicode(path) => write!(
+                formatter,
+                "Path is not valid Unicode, consider renaming it: {path:?}",
+            ),
+
+            main::Error::PathStripPrefix(error) => write!(formatter, "{error}"),
+
+            main::Error::PlaintextField(field) => {
+                let field = field.to_string();
+                write!(
+                    formatter,
+                    "Field {field:?} would embed the contents of obfuscated files in plaintext. \
+                    Use \"get_bytes\" or \"get_str\" instead.",
+                )
+            }
+
+            main::Error::ScatteredFolder { folder, usage } => write!(
+                formatter,
+                "Files of folder {folder:?} are not contiguous in the configured order \
+                as needed for {usage}. Consider \"order = 'tree'\".",
+            ),
+
+            main::Error::UnexpectedEmptyRelativePath { absolute_path } => write!(
+                formatter,
+                "Unexpected empty relative path for absolute path \
+                (consider reporting this): {absolute_path:?}",
+            ),
+
+            main::Error::UnexpectedPathCollision(path) => write!(
+                formatter,
+                "Unexpected path collision (consider reporting this): {path:?}",
+            ),
+
+            main::Error::UnknownWebFile { option, file } => write!(
+                formatter,
+                "Configuration \"web.{option}\" refers to no selected file: {file:?}",
+            ),
+
+            main::Error::UnservableWebFile { option, file } => write!(
+                formatter,
+                "Configuration \"web.{option}\" refers to a file that is not always served, \
+                as it is conditionally compiled or obfuscated: {file:?}",
+            ),
+        }
+    }
+}
+
+impl error::Error for main::Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            main::Error::CaseInsensitivePathCollision { .. } => None,
+            main::Error::DisabledFeature { .. } => None,
+            main::Error::EnvironmentVariable { source, .. } => Some(source),
+            main::Error::Ignore(main::IgnoreError(error)) => Some(error),
+            main::Error::InvalidFiles(_) => None,
+            main::Error::NoInitializer => None,
+            main::Error::NonstandardField { .. } => None,
+            main::Error::Parse { .. } => None,
+            main::Error::PathInvalidUnicode(_) => None,
+            main::Error::PathStripPrefix(error) => Some(error),
+            main::Error::PlaintextField(_) => None,
+            main::Error::ScatteredFolder { .. } => None,
+            main::Error::UnexpectedEmptyRelativePath { .. } => None,
+            main::Error::UnexpectedPathCollision(_) => None,
+            main::Error::UnknownWebFile { .. } => None,
+            main::Error::UnservableWebFile { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for main::Format {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            main::Format::Csv => "CSV",
+            main::Format::Json => "JSON",
+            main::Format::Toml => "TOML",
+            main::Format::Yaml => "YAML",
+        })
+    }
+}
+
+impl From<ignore::Error> for main::Error {
+    fn from(error: ignore::Error) -> Self {
+        main::Error::Ignore(main::IgnoreError(error))
+    }
+}
+
+impl From<path::StripPrefixError> for main::Error {
+    fn from(error: path::StripPrefixError) -> Self {
+        main::Error::PathStripPrefix(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[cfg(test)]
+    mod display {
+        use super::*;
+
+        #[test]
+        fn handles_case_insensitive_path_collision() {
+            let actual = main::Error::CaseInsensitivePathCollision {
+                relative_path: "A/b".into(),
+                other_relative_path: "a/B".into(),
+            }
+            .to_string();
+
+            let expected = "Paths collide when ignoring case, consider renaming one: \
+\"A/b\" and \"a/B\"";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_disabled_feature() {
+            let actual = main::Error::DisabledFeature {
+                feature: "abc".into(),
+                usage: "xy".into(),
+            }
+            .to_string();
+
+            let expected = "Feature \"abc\" of this crate is required for xy. \
+Enable it in your manifest (\"Cargo.toml\").";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_environment_variable() {
+            let actual = main::Error::EnvironmentVariable {
+                name: "ABC".into(),
+                source: env::VarError::NotPresent,
+            }
+            .to_string();
+
+            let expected = "Unable to get environment variable \"ABC\": \
+            environment variable not found";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_ignore() {
+            let actual = main::Error::Ignore(main::IgnoreError(ignore::Error::Glob {
+                glob: Some("[".into()),
+                err: "abc".into(),
+            }))
+            .to_string();
+
+            let expected = "error parsing glob '[': abc";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_invalid_files() {
+            let actual = main::Error::InvalidFiles(vec![
+                main::InvalidFile {
+                    path: "/a".into(),
+                    problem: "bc".into(),
+                },
+                main::InvalidFile {
+                    path: "/d".into(),
+                    problem: "ef".into(),
+                },
+            ])
+            .to_string();
+
+            let expected = "Files failed validation:
+- \"/a\": bc
+- \"/d\": ef";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_no_initializer() {
+            let actual = main::Error::NoInitializer.to_string();
+
+            let expected = "No initializer. \
+Configure one with \"template.initializer = 'a_macro'\" or \
+use standard fields to generate a default initializer.";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_nonstandard_field() {
+            let actual = main::Error::NonstandardField {
+                field: quote::format_ident!("abc"),
+                standard_fields: vec![quote::format_ident!("xy"), quote::format_ident!("z")],
+            }
+            .to_string();
+
+            let expected = "Default initializer cannot be generated \
+as field \"abc\" is not standard. \
+Configure an initializer with \"template.initializer = 'a_macro'\" or \
+use standard fields only (\"xy\", \"z\").";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_parse() {
+            let actual = main::Error::Parse {
+                path: "/a/b.json".into(),
+                format: main::Format::Json,
+                message: "EOF while parsing a value at line 1 column 0".into(),
+            }
+            .to_string();
+
+            let expected = "Unable to parse file \"/a/b.json\" as JSON: \
+EOF while parsing a value at line 1 column 0";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_path_invalid_unicode() {
+            let actual = main::Error::PathInvalidUnicode("a/b".into()).to_string();
+
+            let expected = "Path is not valid Unicode, consider renaming it: \"a/b\"";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_path_strip_prefix() {
+            let actual =
+                main::Error::PathStripPrefix(path::Path::new("ab").strip_prefix("bc").unwrap_err())
+                    .to_string();
+
+            let expected = "prefix not found";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_plaintext_field() {
+            let actual =
+                main::Error::PlaintextField(quote::format_ident!("contents_str")).to_string();
+
+            let expected = "Field \"contents_str\" would embed the contents of obfuscated files \
+in plaintext. Use \"get_bytes\" or \"get_str\" instead.";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_unexpected_empty_relative_path() {
+            let actual = main::Error::UnexpectedEmptyRelativePath {
+                absolute_path: "/a".into(),
+            }
+            .to_string();
+
+            let expected = "Unexpected empty relative path for absolute path \
+(consider reporting this): \"/a\"";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_scattered_folder() {
+            let actual = main::Error::ScatteredFolder {
+                folder: "a/b".into(),
+                usage: "configuration \"folder_functions\"".into(),
+            }
+            .to_string();
+
+            let expected = "Files of folder \"a/b\" are not contiguous in the configured order \
+as needed for configuration \"folder_functions\". Consider \"order = 'tree'\".";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_unexpected_path_collision() {
+            let actual = main::Error::UnexpectedPathCollision("a/b".into()).to_string();
+
+            let expected = "Unexpected path collision (consider reporting this): \"a/b\"";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_unknown_web_file() {
+            let actual = main::Error::UnknownWebFile {
+                option: "fallback".into(),
+                file: "a/b".into(),
+            }
+            .to_string();
+
+            let expected = "Configuration \"web.fallback\" refers to no selected file: \"a/b\"";
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_unservable_web_file() {
+            let actual = main::Error::UnservableWebFile {
+                option: "fallback".into(),
+                file: "a/b".into(),
+            }
+            .to_string();
+
+            let expected = "Configuration \"web.fallback\" refers to a file that is not always \
+served, as it is conditionally compiled or obfuscated: \"a/b\"";
+            assert_eq!(actual, expected);
+        }
+    }
+}
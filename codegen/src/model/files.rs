@@ -0,0 +1,89 @@
+use super::main::File;
+use super::main::Folder;
+use super::main::Forest;
+use super::main::Tree;
+use std::collections;
+
+/// Iterates over the files of a forest depth-first, with the entries of each
+/// folder ordered by name.
+pub fn main(forest: &Forest) -> impl Iterator<Item = &File> {
+    Files {
+        stack: vec![forest.values()],
+    }
+}
+
+struct Files<'a> {
+    stack: Vec<collections::btree_map::Values<'a, String, Tree>>,
+}
+
+impl<'a> Iterator for Files<'a> {
+    type Item = &'a File;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.stack.last_mut()?.next() {
+                None => {
+                    self.stack.pop();
+                }
+                Some(Tree::File(file)) => return Some(file),
+                Some(Tree::Folder(Folder { forest, .. })) => self.stack.push(forest.values()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::main::stubs;
+    use super::*;
+
+    #[test]
+    fn handles_empty_forest() {
+        let actual = main(&Forest::new()).count();
+
+        assert_eq!(actual, 0);
+    }
+
+    #[test]
+    fn handles_nested_forest() {
+        let forest = [
+            ("a".into(), get_file("a")),
+            (
+                "b".into(),
+                Tree::Folder(Folder {
+                    forest: [
+                        ("a".into(), get_file("b/a")),
+                        (
+                            "b".into(),
+                            Tree::Folder(Folder {
+                                forest: Forest::new(),
+                                ..stubs::folder()
+                            }),
+                        ),
+                        ("c".into(), get_file("b/c")),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    ..stubs::folder()
+                }),
+            ),
+            ("c".into(), get_file("c")),
+        ]
+        .into_iter()
+        .collect();
+
+        let actual = main(&forest)
+            .map(|file| file.relative_path.as_str())
+            .collect::<Vec<_>>();
+
+        let expected = vec!["a", "b/a", "b/c", "c"];
+        assert_eq!(actual, expected);
+    }
+
+    fn get_file(relative_path: &str) -> Tree {
+        Tree::File(File {
+            relative_path: relative_path.into(),
+            ..stubs::file()
+        })
+    }
+}
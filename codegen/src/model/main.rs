@@ -0,0 +1,545 @@
+use std::cmp;
+use std::collections;
+use std::env;
+use std::path;
+use std::result;
+use std::time;
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Configuration {
+    pub paths: String,
+    pub bundles: Vec<Bundle>,
+    pub base_folder: path::PathBuf,
+    pub root_folder_variable: String,
+    pub include_folders: bool,
+    pub folder_functions: bool,
+    pub assets_by_path: bool,
+    pub embedded_tree: bool,
+    pub tree: bool,
+    pub glob: bool,
+    pub order: Order,
+    pub path_lookup: PathLookup,
+    pub web: Option<Web>,
+    pub serve: bool,
+    pub template: Template,
+    pub validations: Vec<Validation>,
+    pub cfgs: Vec<Cfg>,
+    pub text: Option<Text>,
+    pub obfuscation: Option<Obfuscation>,
+    pub deduplicate: bool,
+    pub storage: Storage,
+    pub dev_mode: Option<DevMode>,
+    pub overlay_env: Option<String>,
+    pub debug: bool,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub enum Template {
+    Default {
+        initializer: Option<syn::Path>,
+        initializer_arguments: Vec<InitializerArgument>,
+        identifiers: bool,
+    },
+    Visitors(Vec<CustomVisitor>),
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct CustomVisitor {
+    pub visit_base: Option<syn::Path>,
+    pub visit_folder: Option<syn::Path>,
+    pub visit_empty_folder: Option<syn::Path>,
+    pub visit_file: syn::Path,
+    pub extended_inputs: bool,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Validation {
+    pub paths: String,
+    pub validator: Validator,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub enum Validator {
+    Command(Vec<String>),
+    Json,
+    Toml,
+    Utf8,
+    Xml,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Bundle {
+    pub paths: String,
+    pub predicate: syn::Meta,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Cfg {
+    pub paths: String,
+    pub predicate: syn::Meta,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Text {
+    pub paths: String,
+    pub normalization: Normalization,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Obfuscation {
+    pub paths: String,
+    pub key_variable: String,
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Eq)]
+pub struct Normalization {
+    pub line_endings: Option<LineEnding>,
+    pub strip_bom: bool,
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Eq)]
+pub enum LineEnding {
+    Crlf,
+    Lf,
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Eq)]
+pub enum Storage {
+    Files,
+    Packed { alignment: Option<usize> },
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Default, Eq)]
+pub struct PathLookup {
+    pub case_insensitive: bool,
+    pub normalize: bool,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Web {
+    pub index_files: Vec<String>,
+    pub fallback: Option<String>,
+    pub trailing_slash: TrailingSlash,
+    pub cache: Option<Cache>,
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Eq)]
+pub enum TrailingSlash {
+    Ignore,
+    Redirect,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Cache {
+    pub rules: Vec<CacheRule>,
+    pub fingerprint: String,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct CacheRule {
+    pub paths: String,
+    pub cache_control: String,
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Eq)]
+pub enum Order {
+    Path,
+    Pattern,
+    Tree,
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Eq)]
+pub enum DevMode {
+    Filesystem,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Type<T> {
+    pub name: syn::Ident,
+    pub lifetimes: usize,
+    pub structure: TypeStructure<T>,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub enum TypeStructure<T> {
+    Unit,
+    TypeAlias(T),
+    NamedFields(Vec<(syn::Ident, T)>),
+    TupleFields(Vec<T>),
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Path {
+    pub relative: Vec<String>,
+    pub absolute: String,
+    pub contents: Vec<u8>,
+    pub modified: Option<time::SystemTime>,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug)]
+pub struct View {
+    pub type_: syn::Ident,
+    pub type_lifetimes: usize,
+    pub visitors: Vec<Visitor>,
+    pub forest: Forest,
+    pub include_folders: bool,
+    pub folder_functions: bool,
+    pub assets_by_path: bool,
+    pub embedded_tree: bool,
+    pub tree: bool,
+    pub glob: bool,
+    pub blobs: Vec<Blob>,
+    pub packed: Option<Packed>,
+    pub lookup: Option<Lookup>,
+    pub path_keys: Option<PathKeys>,
+    pub served_files: Option<Vec<ServedFile>>,
+    pub web: Option<Web>,
+    pub debug: bool,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub enum Visitor {
+    Array(Initializer),
+    Identifiers,
+    Custom(CustomVisitor),
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub enum Initializer {
+    Default(TypeStructure<Populator>),
+    Macro {
+        macro_: syn::Path,
+        arguments: Vec<InitializerArgument>,
+    },
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Eq)]
+pub enum InitializerArgument {
+    AbsolutePath,
+    Id,
+    Index,
+    Name,
+    RelativePath,
+    Size,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub enum Populator {
+    CacheControl,
+    ContentsBytes,
+    ContentsStr,
+    Etag,
+    FingerprintedPath,
+    GetBytes,
+    GetDecompressed,
+    GetDecompressedStr,
+    GetStr,
+    Parsed(Format),
+    RelativePath,
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Eq, cmp::PartialOrd, cmp::Ord)]
+pub enum Format {
+    Csv,
+    Json,
+    Toml,
+    Yaml,
+}
+
+pub type Forest = collections::BTreeMap<String, Tree>;
+
+#[derive(Clone, cmp::PartialEq, Debug)]
+pub enum Tree {
+    File(File),
+    Folder(Folder),
+}
+
+#[derive(Clone, cmp::PartialEq, Debug)]
+pub struct File {
+    pub identifier: syn::Ident,
+    pub index: usize,
+    pub relative_path: String,
+    pub absolute_path: String,
+    pub contents: Vec<u8>,
+    pub normalization: Option<Normalization>,
+    pub cfg: Option<Box<syn::Meta>>,
+    pub obfuscated: Option<Box<Obfuscated>>,
+    pub blob: Option<usize>,
+    pub slice: Option<Slice>,
+    pub data: collections::BTreeMap<Format, Data>,
+    pub modified: Option<time::SystemTime>,
+    pub cache: Option<Box<FileCache>>,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct FileCache {
+    pub cache_control: String,
+    pub fingerprinted_path: Option<String>,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Obfuscated {
+    pub key_variable: String,
+    pub nonce: [u8; 12],
+    pub contents: Vec<u8>,
+}
+
+#[derive(Clone, Copy, cmp::PartialEq, Debug, Eq)]
+pub struct Slice {
+    pub offset: usize,
+    pub length: usize,
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "format-csv",
+        feature = "format-json",
+        feature = "format-toml",
+        feature = "format-yaml",
+    )),
+    allow(dead_code)
+)]
+#[derive(Clone, cmp::PartialEq, Debug)]
+pub enum Data {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<Data>),
+    Table(Vec<(String, Data)>),
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Blob {
+    pub absolute_path: String,
+    pub contents: Vec<u8>,
+    pub normalized: bool,
+    pub references: usize,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Packed {
+    pub contents: Vec<u8>,
+    pub alignment: Option<usize>,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct PathKeys {
+    pub lookup: PathLookup,
+    pub keys: Vec<PathKey>,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct PathKey {
+    pub key: String,
+    pub relative_path: String,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct ServedFile {
+    pub relative_path: String,
+    pub media_type: String,
+    pub etag: String,
+    pub last_modified: Option<String>,
+    pub cache_control: Option<String>,
+    pub fingerprinted_path: Option<String>,
+    pub variants: Vec<Variant>,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Variant {
+    pub encoding: String,
+    pub relative_path: String,
+    pub etag: String,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Lookup {
+    pub filesystem: Option<Filesystem>,
+    pub overlay_variable: Option<String>,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct Filesystem {
+    pub base_folder: String,
+    pub paths: String,
+    pub bundles: Vec<Bundle>,
+    pub order: Order,
+    pub text: Option<Text>,
+}
+
+#[derive(Clone, cmp::PartialEq, Debug)]
+pub struct Folder {
+    pub identifier: syn::Ident,
+    pub forest: Forest,
+}
+
+pub type Result<T> = result::Result<T, Error>;
+
+#[derive(Clone, cmp::PartialEq, Debug)]
+pub enum Error {
+    CaseInsensitivePathCollision {
+        relative_path: String,
+        other_relative_path: String,
+    },
+    DisabledFeature {
+        feature: String,
+        usage: String,
+    },
+    EnvironmentVariable {
+        name: String,
+        source: env::VarError,
+    },
+    Ignore(IgnoreError),
+    InvalidFiles(Vec<InvalidFile>),
+    NoInitializer,
+    NonstandardField {
+        field: syn::Ident,
+        standard_fields: Vec<syn::Ident>,
+    },
+    Parse {
+        path: path::PathBuf,
+        format: Format,
+        message: String,
+    },
+    PathInvalidUnicode(path::PathBuf),
+    PathStripPrefix(path::StripPrefixError),
+    PlaintextField(syn::Ident),
+    ScatteredFolder {
+        folder: String,
+        usage: String,
+    },
+    UnexpectedEmptyRelativePath {
+        absolute_path: path::PathBuf,
+    },
+    UnexpectedPathCollision(path::PathBuf),
+    UnknownWebFile {
+        option: String,
+        file: String,
+    },
+    UnservableWebFile {
+        option: String,
+        file: String,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct IgnoreError(pub ignore::Error);
+
+#[derive(Clone, cmp::PartialEq, Debug, Eq)]
+pub struct InvalidFile {
+    pub path: path::PathBuf,
+    pub problem: String,
+}
+
+#[cfg(test)]
+pub mod stubs {
+    use super::*;
+
+    pub fn configuration() -> Configuration {
+        Configuration {
+            paths: "!*".into(),
+            bundles: vec![],
+            base_folder: "foo".into(),
+            root_folder_variable: "BAR".into(),
+            include_folders: false,
+            folder_functions: false,
+            assets_by_path: false,
+            embedded_tree: false,
+            tree: false,
+            glob: false,
+            order: Order::Tree,
+            path_lookup: PathLookup::default(),
+            web: None,
+            serve: false,
+            template: Template::Visitors(vec![]),
+            validations: vec![],
+            cfgs: vec![],
+            text: None,
+            obfuscation: None,
+            deduplicate: false,
+            storage: Storage::Files,
+            dev_mode: None,
+            overlay_env: None,
+            debug: false,
+        }
+    }
+
+    pub fn type_<T>() -> Type<T> {
+        Type {
+            name: quote::format_ident!("Foo"),
+            lifetimes: 0,
+            structure: type_structure(),
+        }
+    }
+
+    pub fn type_structure<T>() -> TypeStructure<T> {
+        TypeStructure::Unit
+    }
+
+    pub fn path() -> Path {
+        Path {
+            relative: vec!["bar".into()],
+            absolute: "/foo/bar".into(),
+            contents: vec![],
+            modified: None,
+        }
+    }
+
+    pub fn view() -> View {
+        View {
+            type_: quote::format_ident!("Foo"),
+            type_lifetimes: 0,
+            visitors: vec![],
+            forest: Forest::new(),
+            include_folders: false,
+            folder_functions: false,
+            assets_by_path: false,
+            embedded_tree: false,
+            tree: false,
+            glob: false,
+            blobs: vec![],
+            packed: None,
+            lookup: None,
+            path_keys: None,
+            served_files: None,
+            web: None,
+            debug: false,
+        }
+    }
+
+    pub fn file() -> File {
+        File {
+            identifier: quote::format_ident!("BAR"),
+            index: 123,
+            relative_path: "bar".into(),
+            absolute_path: "/foo/bar".into(),
+            contents: vec![],
+            normalization: None,
+            cfg: None,
+            obfuscated: None,
+            blob: None,
+            slice: None,
+            data: collections::BTreeMap::new(),
+            modified: None,
+            cache: None,
+        }
+    }
+
+    pub fn blob() -> Blob {
+        Blob {
+            absolute_path: "/foo/bar".into(),
+            contents: vec![],
+            normalized: false,
+            references: 2,
+        }
+    }
+
+    pub fn folder() -> Folder {
+        Folder {
+            identifier: quote::format_ident!("foo"),
+            forest: Forest::new(),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod error;
+mod files;
+mod main;
+
+pub use files::main as files;
+pub use main::*;
@@ -0,0 +1,84 @@
+use super::configuration;
+use serde::de;
+use std::fmt;
+
+impl<'a> serde::Deserialize<'a> for configuration::Alignment {
+    fn deserialize<T>(deserializer: T) -> Result<configuration::Alignment, T::Error>
+    where
+        T: serde::Deserializer<'a>,
+    {
+        deserializer.deserialize_u64(Visitor)
+    }
+}
+
+struct Visitor;
+
+impl de::Visitor<'_> for Visitor {
+    type Value = configuration::Alignment;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a power of two (like `1`, `2`, `4`, or `8`)")
+    }
+
+    fn visit_i64<T>(self, value: i64) -> Result<Self::Value, T>
+    where
+        T: de::Error,
+    {
+        match usize::try_from(value) {
+            Ok(alignment) if alignment.is_power_of_two() => Ok(configuration::Alignment(alignment)),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Signed(value),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_u64<T>(self, value: u64) -> Result<Self::Value, T>
+    where
+        T: de::Error,
+    {
+        match usize::try_from(value) {
+            Ok(alignment) if alignment.is_power_of_two() => Ok(configuration::Alignment(alignment)),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(value),
+                &self,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(test)]
+    mod deserialize {
+        use super::*;
+        use std::cmp;
+
+        #[derive(cmp::PartialEq, Debug, serde::Deserialize)]
+        struct Binding {
+            alignment: configuration::Alignment,
+        }
+
+        #[test]
+        fn handles_power_of_two() {
+            let actual: Result<Binding, _> = toml::from_str("alignment = 16");
+
+            let actual = actual.unwrap();
+            let expected = Binding {
+                alignment: configuration::Alignment(16),
+            };
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn given_invalid_value_it_errs() {
+            let actual = ["alignment = 0", "alignment = 12", "alignment = -4"]
+                .map(|string| toml::from_str::<Binding>(string).is_err());
+
+            let expected = [true, true, true];
+            assert_eq!(actual, expected);
+        }
+    }
+}
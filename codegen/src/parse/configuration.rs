@@ -0,0 +1,197 @@
+use std::cmp;
+use std::collections;
+use std::path;
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Configuration {
+    pub paths: Patterns,
+    pub bundles: Option<collections::BTreeMap<String, Bundle>>,
+    pub base_folder: Option<path::PathBuf>,
+    pub root_folder_variable: Option<String>,
+    pub include_folders: Option<bool>,
+    pub folder_functions: Option<bool>,
+    pub assets_by_path: Option<bool>,
+    pub embedded_tree: Option<bool>,
+    pub tree: Option<bool>,
+    pub glob: Option<bool>,
+    pub order: Option<Order>,
+    pub lookup: Option<Lookup>,
+    pub web: Option<Web>,
+    pub serve: Option<bool>,
+    pub template: Option<Template>,
+    pub validate: Option<Vec<Validation>>,
+    pub cfg: Option<Vec<Cfg>>,
+    pub text: Option<Text>,
+    pub obfuscate: Option<Obfuscate>,
+    pub deduplicate: Option<bool>,
+    pub storage: Option<Storage>,
+    pub alignment: Option<Alignment>,
+    pub dev_mode: Option<DevMode>,
+    pub overlay_env: Option<String>,
+    pub debug: Option<bool>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields, untagged)]
+pub enum Template {
+    Default {
+        initializer: Option<Path>,
+        initializer_arguments: Option<Vec<InitializerArgument>>,
+        identifiers: Option<bool>,
+    },
+    Visitors(Vec<CustomVisitor>),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InitializerArgument {
+    AbsolutePath,
+    Id,
+    Index,
+    Name,
+    RelativePath,
+    Size,
+}
+
+#[derive(cmp::PartialEq, Debug, Eq)]
+pub struct Path(pub syn::Path);
+
+#[derive(cmp::PartialEq, Debug, Eq)]
+pub struct Alignment(pub usize);
+
+#[derive(cmp::PartialEq, Debug, Eq)]
+pub struct Predicate(pub syn::Meta);
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomVisitor {
+    pub visit_base: Option<Path>,
+    pub visit_folder: Option<Path>,
+    pub visit_empty_folder: Option<Path>,
+    pub visit_file: Path,
+    pub extended_inputs: Option<bool>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields, untagged)]
+pub enum Validation {
+    Builtin {
+        paths: Patterns,
+        validator: Validator,
+    },
+    Command {
+        paths: Patterns,
+        command: Vec<String>,
+    },
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Validator {
+    Json,
+    Toml,
+    Utf8,
+    Xml,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Bundle {
+    pub paths: Patterns,
+    pub cfg: Predicate,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Cfg {
+    pub paths: Patterns,
+    pub predicate: Predicate,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Text {
+    pub paths: Patterns,
+    pub normalize_line_endings: Option<LineEnding>,
+    pub strip_bom: Option<bool>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Obfuscate {
+    pub paths: Patterns,
+    pub key_env: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+pub enum Patterns {
+    Lines(String),
+    List(Vec<String>),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEnding {
+    Crlf,
+    Lf,
+}
+
+#[derive(cmp::PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Storage {
+    Files,
+    Packed,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Lookup {
+    pub case_insensitive: Option<bool>,
+    pub normalize: Option<bool>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Web {
+    pub index_files: Option<Vec<String>>,
+    pub fallback: Option<String>,
+    pub trailing_slash: Option<TrailingSlash>,
+    pub cache: Option<Cache>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrailingSlash {
+    Ignore,
+    Redirect,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Cache {
+    pub rules: Option<Vec<CacheRule>>,
+    pub fingerprint: Option<Patterns>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CacheRule {
+    pub paths: Patterns,
+    pub cache_control: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Order {
+    Path,
+    Pattern,
+    Tree,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DevMode {
+    Filesystem,
+}
@@ -1,5 +1,7 @@
+mod alignment;
 mod configuration;
 mod parse_configuration;
 mod parse_configuration_from_string;
 mod parse_type;
 mod path;
+mod predicate;
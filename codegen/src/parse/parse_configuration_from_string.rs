@@ -0,0 +1,868 @@
+use super::configuration;
+use crate::model;
+use toml::de;
+
+pub fn main(string: &str) -> Result<model::Configuration, de::Error> {
+    let configuration: configuration::Configuration = toml::from_str(string)?;
+
+    if configuration.alignment.is_some()
+        && configuration.storage != Some(configuration::Storage::Packed)
+    {
+        return Err(serde::de::Error::custom(
+            "alignment requires storage = 'packed'",
+        ));
+    }
+
+    if let Some(configuration::Template::Default {
+        initializer: None,
+        initializer_arguments: Some(_),
+        ..
+    }) = configuration.template
+    {
+        return Err(serde::de::Error::custom(
+            "template.initializer_arguments requires template.initializer",
+        ));
+    }
+
+    for (field, is_configured) in [
+        ("cfg", configuration.cfg.is_some()),
+        ("bundles", configuration.bundles.is_some()),
+    ] {
+        if is_configured {
+            if let Some(configuration::Template::Visitors(_)) = configuration.template {
+                return Err(serde::de::Error::custom(format!(
+                    "{field} requires the default template, not visitors"
+                )));
+            }
+
+            if configuration.storage == Some(configuration::Storage::Packed) {
+                return Err(serde::de::Error::custom(format!(
+                    "{field} is not supported with storage = 'packed'"
+                )));
+            }
+        }
+    }
+
+    if configuration.obfuscate.is_some() {
+        // These would embed the plaintext of obfuscated files.
+        for (field, is_configured) in [
+            ("deduplicate", configuration.deduplicate == Some(true)),
+            (
+                "storage = 'packed'",
+                configuration.storage == Some(configuration::Storage::Packed),
+            ),
+            ("dev_mode", configuration.dev_mode.is_some()),
+            ("overlay_env", configuration.overlay_env.is_some()),
+        ] {
+            if is_configured {
+                return Err(serde::de::Error::custom(format!(
+                    "obfuscate is not supported with {field}"
+                )));
+            }
+        }
+    }
+
+    Ok(configuration.into())
+}
+
+const DEFAULT_INITIALIZER_ARGUMENTS: [model::InitializerArgument; 2] = [
+    model::InitializerArgument::RelativePath,
+    model::InitializerArgument::AbsolutePath,
+];
+
+impl From<configuration::Configuration> for model::Configuration {
+    fn from(configuration: configuration::Configuration) -> Self {
+        model::Configuration {
+            paths: configuration.paths.into(),
+            bundles: configuration
+                .bundles
+                .unwrap_or_default()
+                .into_values()
+                .map(|bundle| model::Bundle {
+                    paths: bundle.paths.into(),
+                    predicate: bundle.cfg.0,
+                })
+                .collect(),
+            base_folder: configuration.base_folder.unwrap_or_default(),
+            root_folder_variable: configuration
+                .root_folder_variable
+                .unwrap_or_else(|| "CARGO_MANIFEST_DIR".into()),
+            include_folders: configuration.include_folders.unwrap_or(false),
+            folder_functions: configuration.folder_functions.unwrap_or(false),
+            assets_by_path: configuration.assets_by_path.unwrap_or(false),
+            embedded_tree: configuration.embedded_tree.unwrap_or(false),
+            tree: configuration.tree.unwrap_or(false),
+            glob: configuration.glob.unwrap_or(false),
+            order: match configuration.order {
+                None | Some(configuration::Order::Tree) => model::Order::Tree,
+                Some(configuration::Order::Path) => model::Order::Path,
+                Some(configuration::Order::Pattern) => model::Order::Pattern,
+            },
+            path_lookup: configuration
+                .lookup
+                .map(|lookup| lookup.into())
+                .unwrap_or_default(),
+            web: configuration.web.map(|web| web.into()),
+            serve: configuration.serve.unwrap_or(false),
+            template: match configuration.template {
+                None => model::Template::Default {
+                    initializer: None,
+                    initializer_arguments: DEFAULT_INITIALIZER_ARGUMENTS.into(),
+                    identifiers: true,
+                },
+                Some(template) => template.into(),
+            },
+            validations: configuration
+                .validate
+                .unwrap_or_default()
+                .into_iter()
+                .map(|validation| validation.into())
+                .collect(),
+            cfgs: configuration
+                .cfg
+                .unwrap_or_default()
+                .into_iter()
+                .map(|cfg| model::Cfg {
+                    paths: cfg.paths.into(),
+                    predicate: cfg.predicate.0,
+                })
+                .collect(),
+            text: configuration.text.map(|text| text.into()),
+            obfuscation: configuration.obfuscate.map(|obfuscate| model::Obfuscation {
+                paths: obfuscate.paths.into(),
+                key_variable: obfuscate.key_env,
+            }),
+            deduplicate: configuration.deduplicate.unwrap_or(false),
+            storage: match configuration.storage {
+                None | Some(configuration::Storage::Files) => model::Storage::Files,
+                Some(configuration::Storage::Packed) => model::Storage::Packed {
+                    alignment: configuration.alignment.map(|alignment| alignment.0),
+                },
+            },
+            dev_mode: configuration.dev_mode.map(|dev_mode| match dev_mode {
+                configuration::DevMode::Filesystem => model::DevMode::Filesystem,
+            }),
+            overlay_env: configuration.overlay_env,
+            debug: configuration.debug.unwrap_or(false),
+        }
+    }
+}
+
+impl From<configuration::Lookup> for model::PathLookup {
+    fn from(lookup: configuration::Lookup) -> Self {
+        model::PathLookup {
+            case_insensitive: lookup.case_insensitive.unwrap_or(false),
+            normalize: lookup.normalize.unwrap_or(false),
+        }
+    }
+}
+
+impl From<configuration::Web> for model::Web {
+    fn from(web: configuration::Web) -> Self {
+        model::Web {
+            index_files: web.index_files.unwrap_or_default(),
+            fallback: web.fallback,
+            trailing_slash: match web.trailing_slash {
+                None | Some(configuration::TrailingSlash::Ignore) => model::TrailingSlash::Ignore,
+                Some(configuration::TrailingSlash::Redirect) => model::TrailingSlash::Redirect,
+            },
+            cache: web.cache.map(|cache| model::Cache {
+                rules: cache
+                    .rules
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|rule| model::CacheRule {
+                        paths: rule.paths.into(),
+                        cache_control: rule.cache_control,
+                    })
+                    .collect(),
+                fingerprint: cache.fingerprint.map(Into::into).unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+impl From<configuration::Template> for model::Template {
+    fn from(template: configuration::Template) -> Self {
+        match template {
+            configuration::Template::Default {
+                initializer,
+                initializer_arguments,
+                identifiers,
+            } => model::Template::Default {
+                initializer: initializer.map(|value| value.0),
+                initializer_arguments: initializer_arguments
+                    .map(|arguments| {
+                        arguments
+                            .into_iter()
+                            .map(|argument| argument.into())
+                            .collect()
+                    })
+                    .unwrap_or_else(|| DEFAULT_INITIALIZER_ARGUMENTS.into()),
+                identifiers: identifiers.unwrap_or(true),
+            },
+            configuration::Template::Visitors(visitors) => model::Template::Visitors(
+                visitors.into_iter().map(|visitor| visitor.into()).collect(),
+            ),
+        }
+    }
+}
+
+impl From<configuration::InitializerArgument> for model::InitializerArgument {
+    fn from(argument: configuration::InitializerArgument) -> Self {
+        match argument {
+            configuration::InitializerArgument::AbsolutePath => {
+                model::InitializerArgument::AbsolutePath
+            }
+            configuration::InitializerArgument::Id => model::InitializerArgument::Id,
+            configuration::InitializerArgument::Index => model::InitializerArgument::Index,
+            configuration::InitializerArgument::Name => model::InitializerArgument::Name,
+            configuration::InitializerArgument::RelativePath => {
+                model::InitializerArgument::RelativePath
+            }
+            configuration::InitializerArgument::Size => model::InitializerArgument::Size,
+        }
+    }
+}
+
+impl From<configuration::CustomVisitor> for model::CustomVisitor {
+    fn from(visitor: configuration::CustomVisitor) -> Self {
+        model::CustomVisitor {
+            visit_base: visitor.visit_base.map(|value| value.0),
+            visit_folder: visitor.visit_folder.map(|value| value.0),
+            visit_empty_folder: visitor.visit_empty_folder.map(|value| value.0),
+            visit_file: visitor.visit_file.0,
+            extended_inputs: visitor.extended_inputs.unwrap_or(false),
+        }
+    }
+}
+
+impl From<configuration::Validation> for model::Validation {
+    fn from(validation: configuration::Validation) -> Self {
+        match validation {
+            configuration::Validation::Builtin { paths, validator } => model::Validation {
+                paths: paths.into(),
+                validator: match validator {
+                    configuration::Validator::Json => model::Validator::Json,
+                    configuration::Validator::Toml => model::Validator::Toml,
+                    configuration::Validator::Utf8 => model::Validator::Utf8,
+                    configuration::Validator::Xml => model::Validator::Xml,
+                },
+            },
+
+            configuration::Validation::Command { paths, command } => model::Validation {
+                paths: paths.into(),
+                validator: model::Validator::Command(command),
+            },
+        }
+    }
+}
+
+impl From<configuration::Patterns> for String {
+    fn from(patterns: configuration::Patterns) -> Self {
+        match patterns {
+            configuration::Patterns::Lines(patterns) => patterns,
+            configuration::Patterns::List(patterns) => patterns.join("\n"),
+        }
+    }
+}
+
+impl From<configuration::Text> for model::Text {
+    fn from(text: configuration::Text) -> Self {
+        model::Text {
+            paths: text.paths.into(),
+            normalization: model::Normalization {
+                line_endings: text
+                    .normalize_line_endings
+                    .map(|line_ending| match line_ending {
+                        configuration::LineEnding::Crlf => model::LineEnding::Crlf,
+                        configuration::LineEnding::Lf => model::LineEnding::Lf,
+                    }),
+                strip_bom: text.strip_bom.unwrap_or(false),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path;
+
+    #[test]
+    fn handles_valid_configuration_with_required_fields_only_using_defaults() {
+        let actual = main("paths = '/a/b/**'");
+
+        let actual = actual.unwrap();
+        let expected = model::Configuration {
+            paths: "/a/b/**".into(),
+            bundles: vec![],
+            base_folder: path::PathBuf::new(),
+            root_folder_variable: "CARGO_MANIFEST_DIR".into(),
+            include_folders: false,
+            folder_functions: false,
+            assets_by_path: false,
+            embedded_tree: false,
+            tree: false,
+            glob: false,
+            order: model::Order::Tree,
+            path_lookup: model::PathLookup::default(),
+            web: None,
+            serve: false,
+            template: model::Template::Default {
+                initializer: None,
+                initializer_arguments: vec![
+                    model::InitializerArgument::RelativePath,
+                    model::InitializerArgument::AbsolutePath,
+                ],
+                identifiers: true,
+            },
+            validations: vec![],
+            cfgs: vec![],
+            text: None,
+            obfuscation: None,
+            deduplicate: false,
+            storage: model::Storage::Files,
+            dev_mode: None,
+            overlay_env: None,
+            debug: false,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_optional_fields() {
+        let actual = main(
+            "
+paths = '/my/assets/**'
+base_folder = 'my_base'
+root_folder_variable = 'MY_ROOT_FOLDER'
+include_folders = true
+folder_functions = true
+assets_by_path = true
+embedded_tree = true
+tree = true
+glob = true
+order = 'pattern'
+lookup.case_insensitive = true
+lookup.normalize = true
+serve = true
+template.initializer = 'my_macro'
+template.identifiers = false
+deduplicate = true
+dev_mode = 'filesystem'
+overlay_env = 'MY_OVERLAY_FOLDER'
+debug = true
+",
+        );
+
+        let actual = actual.unwrap();
+        let expected = model::Configuration {
+            paths: "/my/assets/**".into(),
+            bundles: vec![],
+            base_folder: "my_base".into(),
+            root_folder_variable: "MY_ROOT_FOLDER".into(),
+            include_folders: true,
+            folder_functions: true,
+            assets_by_path: true,
+            embedded_tree: true,
+            tree: true,
+            glob: true,
+            order: model::Order::Pattern,
+            path_lookup: model::PathLookup {
+                case_insensitive: true,
+                normalize: true,
+            },
+            web: None,
+            serve: true,
+            template: model::Template::Default {
+                initializer: Some(syn::parse_str("my_macro").unwrap()),
+                initializer_arguments: vec![
+                    model::InitializerArgument::RelativePath,
+                    model::InitializerArgument::AbsolutePath,
+                ],
+                identifiers: false,
+            },
+            validations: vec![],
+            cfgs: vec![],
+            text: None,
+            obfuscation: None,
+            deduplicate: true,
+            storage: model::Storage::Files,
+            dev_mode: Some(model::DevMode::Filesystem),
+            overlay_env: Some("MY_OVERLAY_FOLDER".into()),
+            debug: true,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_template_visitors() {
+        let actual = main(
+            "
+paths = ''
+
+[[template]]
+visit_file = 'file'
+
+[[template]]
+visit_base = 'my_base'
+visit_folder = 'my_folder'
+visit_empty_folder = 'my_empty_folder'
+visit_file = 'my_file'
+extended_inputs = true
+",
+        );
+
+        let actual = actual.unwrap().template;
+        let expected = model::Template::Visitors(vec![
+            model::CustomVisitor {
+                visit_base: None,
+                visit_folder: None,
+                visit_empty_folder: None,
+                visit_file: syn::parse_str("file").unwrap(),
+                extended_inputs: false,
+            },
+            model::CustomVisitor {
+                visit_base: Some(syn::parse_str("my_base").unwrap()),
+                visit_folder: Some(syn::parse_str("my_folder").unwrap()),
+                visit_empty_folder: Some(syn::parse_str("my_empty_folder").unwrap()),
+                visit_file: syn::parse_str("my_file").unwrap(),
+                extended_inputs: true,
+            },
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_validations() {
+        let actual = main(
+            "
+paths = ''
+
+[[validate]]
+paths = '*.json'
+validator = 'json'
+
+[[validate]]
+paths = '*.svg'
+command = ['xmllint', '--noout']
+",
+        );
+
+        let actual = actual.unwrap().validations;
+        let expected = vec![
+            model::Validation {
+                paths: "*.json".into(),
+                validator: model::Validator::Json,
+            },
+            model::Validation {
+                paths: "*.svg".into(),
+                validator: model::Validator::Command(vec!["xmllint".into(), "--noout".into()]),
+            },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_cfg() {
+        let actual = main(
+            "
+paths = ''
+
+[[cfg]]
+paths = 'debug/**'
+predicate = 'debug_assertions'
+
+[[cfg]]
+paths = 'win/**'
+predicate = 'target_os = \"windows\"'
+",
+        );
+
+        let actual = actual.unwrap().cfgs;
+        let expected = vec![
+            model::Cfg {
+                paths: "debug/**".into(),
+                predicate: syn::parse_quote! { debug_assertions },
+            },
+            model::Cfg {
+                paths: "win/**".into(),
+                predicate: syn::parse_quote! { target_os = "windows" },
+            },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_bundles() {
+        let actual = main(
+            "
+paths = '/public/**'
+
+[bundles.admin]
+paths = '/admin/**'
+cfg = 'feature = \"admin-ui\"'
+
+[bundles.debug]
+paths = '/debug/**'
+cfg = 'debug_assertions'
+",
+        );
+
+        let actual = actual.unwrap().bundles;
+        let expected = vec![
+            model::Bundle {
+                paths: "/admin/**".into(),
+                predicate: syn::parse_quote! { feature = "admin-ui" },
+            },
+            model::Bundle {
+                paths: "/debug/**".into(),
+                predicate: syn::parse_quote! { debug_assertions },
+            },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_obfuscate() {
+        let actual = main(
+            "
+paths = ''
+
+[obfuscate]
+paths = '/licenses/**'
+key_env = 'ASSET_KEY'
+",
+        );
+
+        let actual = actual.unwrap().obfuscation;
+        let expected = Some(model::Obfuscation {
+            paths: "/licenses/**".into(),
+            key_variable: "ASSET_KEY".into(),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_patterns_as_arrays() {
+        let actual = main(
+            "
+paths = ['/a/**', '/b/**']
+
+[bundles.c]
+paths = ['/c/**']
+cfg = 'unix'
+
+[[validate]]
+paths = ['*.json', '*.geojson']
+validator = 'json'
+
+[[cfg]]
+paths = []
+predicate = 'unix'
+
+[text]
+paths = ['*.md', '*.txt']
+
+[obfuscate]
+paths = ['/licenses/**', '*.key']
+key_env = 'ASSET_KEY'
+
+[web.cache]
+fingerprint = \"*.js\\n*.css\"
+
+[[web.cache.rules]]
+paths = ['*.html', '*.htm']
+cache_control = 'no-cache'
+",
+        );
+
+        let actual = actual.unwrap();
+        let cache = actual.web.unwrap().cache.unwrap();
+        let actual = [
+            actual.paths,
+            actual.bundles[0].paths.clone(),
+            actual.validations[0].paths.clone(),
+            actual.cfgs[0].paths.clone(),
+            actual.text.unwrap().paths,
+            actual.obfuscation.unwrap().paths,
+            cache.fingerprint,
+            cache.rules[0].paths.clone(),
+        ];
+
+        let expected = [
+            "/a/**\n/b/**",
+            "/c/**",
+            "*.json\n*.geojson",
+            "",
+            "*.md\n*.txt",
+            "/licenses/**\n*.key",
+            "*.js\n*.css",
+            "*.html\n*.htm",
+        ]
+        .map(String::from);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_text() {
+        let actual = main(
+            "
+paths = ''
+
+[text]
+paths = '*.md'
+normalize_line_endings = 'lf'
+strip_bom = true
+",
+        );
+
+        let actual = actual.unwrap().text;
+        let expected = Some(model::Text {
+            paths: "*.md".into(),
+            normalization: model::Normalization {
+                line_endings: Some(model::LineEnding::Lf),
+                strip_bom: true,
+            },
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_web() {
+        let actual = main(
+            "
+paths = ''
+
+[web]
+index_files = ['index.html']
+fallback = 'index.html'
+trailing_slash = 'redirect'
+",
+        );
+
+        let actual = actual.unwrap().web;
+        let expected = Some(model::Web {
+            index_files: vec!["index.html".into()],
+            fallback: Some("index.html".into()),
+            trailing_slash: model::TrailingSlash::Redirect,
+            cache: None,
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_web_cache() {
+        let actual = main(
+            "
+paths = ''
+
+[web.cache]
+fingerprint = ['*.js', '*.css']
+
+[[web.cache.rules]]
+paths = '*.html'
+cache_control = 'no-cache'
+",
+        );
+
+        let actual = actual.unwrap().web;
+        let expected = Some(model::Web {
+            index_files: vec![],
+            fallback: None,
+            trailing_slash: model::TrailingSlash::Ignore,
+            cache: Some(model::Cache {
+                rules: vec![model::CacheRule {
+                    paths: "*.html".into(),
+                    cache_control: "no-cache".into(),
+                }],
+                fingerprint: "*.js\n*.css".into(),
+            }),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_packed_storage() {
+        let actual = main(
+            "
+paths = ''
+storage = 'packed'
+alignment = 8
+",
+        );
+
+        let actual = actual.unwrap().storage;
+        let expected = model::Storage::Packed { alignment: Some(8) };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_alignment_without_packed_storage_it_errs() {
+        let actual = main(
+            "
+paths = ''
+alignment = 8
+",
+        );
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_cfg_with_template_visitors_it_errs() {
+        let actual = main(
+            "
+paths = ''
+
+[[cfg]]
+paths = '*'
+predicate = 'unix'
+
+[[template]]
+visit_file = 'visit_file'
+",
+        );
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_cfg_with_packed_storage_it_errs() {
+        let actual = main(
+            "
+paths = ''
+storage = 'packed'
+
+[[cfg]]
+paths = '*'
+predicate = 'unix'
+",
+        );
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_bundles_with_template_visitors_it_errs() {
+        let actual = main(
+            "
+paths = ''
+
+[bundles.a]
+paths = '*'
+cfg = 'unix'
+
+[[template]]
+visit_file = 'visit_file'
+",
+        );
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_obfuscate_with_deduplicate_it_errs() {
+        let actual = main(
+            "
+paths = ''
+deduplicate = true
+
+[obfuscate]
+paths = '*'
+key_env = 'ASSET_KEY'
+",
+        );
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn handles_valid_configuration_with_initializer_arguments() {
+        let actual = main(
+            "
+paths = ''
+template.initializer = 'my_macro'
+template.initializer_arguments = ['name', 'id', 'index', 'size']
+",
+        );
+
+        let actual = actual.unwrap().template;
+        let expected = model::Template::Default {
+            initializer: Some(syn::parse_str("my_macro").unwrap()),
+            initializer_arguments: vec![
+                model::InitializerArgument::Name,
+                model::InitializerArgument::Id,
+                model::InitializerArgument::Index,
+                model::InitializerArgument::Size,
+            ],
+            identifiers: true,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_initializer_arguments_without_initializer_it_errs() {
+        let actual = main(
+            "
+paths = ''
+template.initializer_arguments = ['name']
+",
+        );
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_validation_with_both_validator_and_command_it_errs() {
+        let actual = main(
+            "
+paths = ''
+
+[[validate]]
+paths = '*.json'
+validator = 'json'
+command = ['true']
+",
+        );
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_ill_formed_configuration_it_errs() {
+        let actual = main("paths = #");
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_required_field_is_missing_it_errs() {
+        let actual = main("");
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn given_unknown_field_it_errs() {
+        let actual = main(
+            "
+paths = 'abc'
+unknown = ''
+",
+        );
+
+        let actual = actual.is_err();
+        assert!(actual);
+    }
+}
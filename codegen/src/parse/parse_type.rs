@@ -43,6 +43,7 @@ fn parse_structure(item: parse::ParseStream) -> syn::Result<model::Type<()>> {
 
     Ok(model::Type {
         name: derive_input.ident,
+        lifetimes: derive_input.generics.lifetimes().count(),
         structure,
     })
 }
@@ -56,6 +57,7 @@ fn parse_type_alias(item: parse::ParseStream) -> syn::Result<model::Type<()>> {
 
     Ok(model::Type {
         name,
+        lifetimes: 0,
         structure: model::TypeStructure::TypeAlias(()),
     })
 }
@@ -71,6 +73,7 @@ mod tests {
         let actual = actual.unwrap();
         let expected = model::Type {
             name: quote::format_ident!("MyUnit"),
+            lifetimes: 0,
             structure: model::TypeStructure::Unit,
         };
         assert_eq!(actual, expected);
@@ -83,6 +86,7 @@ mod tests {
         let actual = actual.unwrap();
         let expected = model::Type {
             name: quote::format_ident!("MyTypeAlias"),
+            lifetimes: 0,
             structure: model::TypeStructure::TypeAlias(()),
         };
         assert_eq!(actual, expected);
@@ -100,6 +104,7 @@ mod tests {
         let actual = actual.unwrap();
         let expected = model::Type {
             name: quote::format_ident!("MyNamedFields"),
+            lifetimes: 0,
             structure: model::TypeStructure::NamedFields(vec![
                 (quote::format_ident!("ab"), ()),
                 (quote::format_ident!("bc"), ()),
@@ -116,6 +121,21 @@ mod tests {
         let actual = actual.unwrap();
         let expected = model::Type {
             name: quote::format_ident!("MyTupleFields"),
+            lifetimes: 0,
+            structure: model::TypeStructure::TupleFields(vec![(), ()]),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_lifetimes() {
+        let actual =
+            syn::parse_str::<model::Type<()>>("pub struct MyLifetimes<'a, 'b>(&'a str, &'b str);");
+
+        let actual = actual.unwrap();
+        let expected = model::Type {
+            name: quote::format_ident!("MyLifetimes"),
+            lifetimes: 2,
             structure: model::TypeStructure::TupleFields(vec![(), ()]),
         };
         assert_eq!(actual, expected);
@@ -0,0 +1,89 @@
+use super::configuration;
+use serde::de;
+use std::fmt;
+
+impl<'a> serde::Deserialize<'a> for configuration::Predicate {
+    fn deserialize<T>(deserializer: T) -> Result<configuration::Predicate, T::Error>
+    where
+        T: serde::Deserializer<'a>,
+    {
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+struct Visitor;
+
+impl de::Visitor<'_> for Visitor {
+    type Value = configuration::Predicate;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a configuration predicate (like `unix` or `feature = \"x\"`)")
+    }
+
+    fn visit_str<T>(self, string: &str) -> Result<Self::Value, T>
+    where
+        T: de::Error,
+    {
+        match syn::parse_str(string) {
+            Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(string), &self)),
+            Ok(value) => Ok(configuration::Predicate(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(test)]
+    mod deserialize {
+        use super::*;
+        use std::cmp;
+
+        #[derive(cmp::PartialEq, Debug, serde::Deserialize)]
+        struct Binding {
+            name: configuration::Predicate,
+        }
+
+        #[test]
+        fn handles_name() {
+            let actual: Result<Binding, _> = toml::from_str("name = 'debug_assertions'");
+
+            let actual = actual.unwrap();
+            let expected = Binding {
+                name: configuration::Predicate(syn::parse_str("debug_assertions").unwrap()),
+            };
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_name_value() {
+            let actual: Result<Binding, _> = toml::from_str("name = 'feature = \"a\"'");
+
+            let actual = actual.unwrap();
+            let expected = Binding {
+                name: configuration::Predicate(syn::parse_str("feature = \"a\"").unwrap()),
+            };
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_list() {
+            let actual: Result<Binding, _> = toml::from_str("name = 'all(unix, not(test))'");
+
+            let actual = actual.unwrap();
+            let expected = Binding {
+                name: configuration::Predicate(syn::parse_str("all(unix, not(test))").unwrap()),
+            };
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn given_invalid_value_it_errs() {
+            let actual: Result<Binding, _> = toml::from_str("name = 'a b'");
+
+            let actual = actual.is_err();
+            assert!(actual);
+        }
+    }
+}
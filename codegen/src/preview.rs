@@ -0,0 +1,186 @@
+use crate::model;
+use std::collections;
+use std::fmt::Write;
+use syn::ext::IdentExt;
+
+pub fn main(view: &model::View) -> String {
+    let mut report = String::new();
+    write_selection(&mut report, &view.forest);
+    write_forest(&mut report, &view.forest);
+    write_collisions(&mut report, &view.forest);
+    report
+}
+
+fn write_selection(report: &mut String, forest: &model::Forest) {
+    let mut files = model::files(forest).collect::<Vec<_>>();
+    files.sort_by_key(|file| file.index);
+
+    writeln!(report, "Selected files ({}):", files.len()).unwrap();
+    for file in files {
+        writeln!(report, "  {}", file.relative_path).unwrap();
+    }
+}
+
+fn write_forest(report: &mut String, forest: &model::Forest) {
+    report.push_str("\nForest:\n  base\n");
+    write_trees(report, forest, 2);
+}
+
+fn write_trees(report: &mut String, forest: &model::Forest, depth: usize) {
+    let indentation = "  ".repeat(depth);
+
+    for (name, tree) in forest {
+        match tree {
+            model::Tree::File(file) => {
+                let identifier = file.identifier.unraw();
+                writeln!(
+                    report,
+                    "{indentation}{name} -> {identifier} (index {})",
+                    file.index,
+                )
+                .unwrap();
+            }
+
+            model::Tree::Folder(folder) => {
+                let identifier = folder.identifier.unraw();
+                writeln!(report, "{indentation}{name}/ -> {identifier}").unwrap();
+                write_trees(report, &folder.forest, depth + 1);
+            }
+        }
+    }
+}
+
+fn write_collisions(report: &mut String, forest: &model::Forest) {
+    let mut collisions = vec![];
+    collect_collisions(forest, &mut vec![String::from("base")], &mut collisions);
+
+    report.push_str("\nIdentifier collisions:\n");
+    if collisions.is_empty() {
+        report.push_str("  none\n");
+    }
+    for (path, names) in collisions {
+        let names = names
+            .iter()
+            .map(|name| format!("{name:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(report, "  {path}: {names}").unwrap();
+    }
+}
+
+fn collect_collisions(
+    forest: &model::Forest,
+    module: &mut Vec<String>,
+    collisions: &mut Vec<(String, Vec<String>)>,
+) {
+    let mut names_by_identifier = collections::BTreeMap::<_, Vec<_>>::new();
+
+    for (name, tree) in forest {
+        let identifier = match tree {
+            model::Tree::File(file) => file.identifier.unraw().to_string(),
+            model::Tree::Folder(folder) => folder.identifier.unraw().to_string(),
+        };
+        names_by_identifier
+            .entry(identifier)
+            .or_default()
+            .push(name.clone());
+    }
+
+    for (identifier, names) in names_by_identifier {
+        if names.len() > 1 {
+            let path = [module.join("::"), identifier].join("::");
+            collisions.push((path, names));
+        }
+    }
+
+    for tree in forest.values() {
+        if let model::Tree::Folder(folder) = tree {
+            module.push(folder.identifier.unraw().to_string());
+            collect_collisions(&folder.forest, module, collisions);
+            module.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = main(&model::View {
+            forest: [
+                (
+                    "a-b".into(),
+                    model::Tree::File(model::File {
+                        identifier: quote::format_ident!("r#A_B"),
+                        index: 1,
+                        relative_path: "a-b".into(),
+                        ..model::stubs::file()
+                    }),
+                ),
+                (
+                    "a_b".into(),
+                    model::Tree::File(model::File {
+                        identifier: quote::format_ident!("r#A_B"),
+                        index: 2,
+                        relative_path: "a_b".into(),
+                        ..model::stubs::file()
+                    }),
+                ),
+                (
+                    "c".into(),
+                    model::Tree::Folder(model::Folder {
+                        identifier: quote::format_ident!("r#c"),
+                        forest: [(
+                            "d.txt".into(),
+                            model::Tree::File(model::File {
+                                identifier: quote::format_ident!("r#D_TXT"),
+                                index: 0,
+                                relative_path: "c/d.txt".into(),
+                                ..model::stubs::file()
+                            }),
+                        )]
+                        .into_iter()
+                        .collect(),
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..model::stubs::view()
+        });
+
+        let expected = "Selected files (3):
+  c/d.txt
+  a-b
+  a_b
+
+Forest:
+  base
+    a-b -> A_B (index 1)
+    a_b -> A_B (index 2)
+    c/ -> c
+      d.txt -> D_TXT (index 0)
+
+Identifier collisions:
+  base::A_B: \"a-b\", \"a_b\"
+";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_collisions_it_reports_none() {
+        let actual = main(&model::stubs::view());
+
+        let expected = "Selected files (0):
+
+Forest:
+  base
+
+Identifier collisions:
+  none
+";
+        assert_eq!(actual, expected);
+    }
+}
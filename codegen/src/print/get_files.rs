@@ -0,0 +1,55 @@
+use crate::model;
+
+pub fn main(forest: &model::Forest) -> Vec<&model::File> {
+    let mut files = model::files(forest).collect::<Vec<_>>();
+    files.sort_by_key(|file| file.index);
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let forest = [
+            (
+                "a".into(),
+                model::Tree::File(model::File {
+                    index: 2,
+                    ..model::stubs::file()
+                }),
+            ),
+            (
+                "b".into(),
+                model::Tree::Folder(model::Folder {
+                    forest: [(
+                        "c".into(),
+                        model::Tree::File(model::File {
+                            index: 0,
+                            ..model::stubs::file()
+                        }),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::folder()
+                }),
+            ),
+            (
+                "d".into(),
+                model::Tree::File(model::File {
+                    index: 1,
+                    ..model::stubs::file()
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let actual = main(&forest);
+
+        let actual = actual.iter().map(|file| file.index).collect::<Vec<_>>();
+        let expected = vec![0, 1, 2];
+        assert_eq!(actual, expected);
+    }
+}
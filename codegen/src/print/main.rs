@@ -0,0 +1,182 @@
+use super::print_blobs;
+use super::print_data_type;
+use super::print_embedded_tree;
+use super::print_folders;
+use super::print_forest;
+use super::print_lookup;
+use super::print_packed;
+use super::print_resolve_path;
+use super::print_service;
+use super::print_tree;
+use super::print_web;
+use super::print_with_debug;
+use crate::model;
+
+pub fn main(item: proc_macro2::TokenStream, view: model::View) -> proc_macro2::TokenStream {
+    let code = print_code(&item, &view, print_packed::main(&view));
+    print_with_debug::main(&view, code, || {
+        // Debug information need not repeat the contents of a packed blob.
+        print_code(&item, &view, print_packed::main_elided(&view))
+    })
+}
+
+fn print_code(
+    item: &proc_macro2::TokenStream,
+    view: &model::View,
+    packed: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let visits = view
+        .visitors
+        .iter()
+        .map(|visitor| print_forest::main(view, visitor))
+        .collect::<proc_macro2::TokenStream>();
+    let blobs = print_blobs::main(view);
+    let embedded_tree = print_embedded_tree::main(view);
+    let tree = print_tree::main(view);
+    let folders = print_folders::main(view);
+    let lookup = print_lookup::main(view);
+    let resolve_path = print_resolve_path::main(view);
+    let web = print_web::main(view);
+    let service = print_service::main(view);
+    let data_type = print_data_type::main(view);
+
+    quote::quote! {
+        #item
+
+        #blobs
+
+        #packed
+
+        #visits
+
+        #data_type
+
+        #embedded_tree
+
+        #tree
+
+        #folders
+
+        #lookup
+
+        #resolve_path
+
+        #web
+
+        #service
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = main(
+            quote::quote! { pub type Asset = &'static str; },
+            model::View {
+                type_: quote::format_ident!("Asset"),
+                type_lifetimes: 0,
+                visitors: vec![
+                    model::Visitor::Array(model::Initializer::Default(
+                        model::TypeStructure::TypeAlias(model::Populator::ContentsStr),
+                    )),
+                    model::Visitor::Identifiers,
+                ],
+                forest: [(
+                    "a.b".into(),
+                    model::Tree::File(model::File {
+                        identifier: quote::format_ident!("A_B"),
+                        index: 0,
+                        relative_path: "a.b".into(),
+                        absolute_path: "/a.b".into(),
+                        ..model::stubs::file()
+                    }),
+                )]
+                .into_iter()
+                .collect(),
+                include_folders: false,
+                folder_functions: true,
+                assets_by_path: true,
+                embedded_tree: true,
+                tree: true,
+                glob: true,
+                blobs: vec![],
+                packed: None,
+                lookup: None,
+                path_keys: None,
+                served_files: None,
+                web: None,
+                debug: false,
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub type Asset = &'static str;
+
+            pub static ASSETS: [Asset; 1usize] = [
+                include_str!("/a.b"),
+            ];
+
+            pub mod base {
+                pub fn all() -> &'static [super::Asset] {
+                    &super::ASSETS
+                }
+
+                pub const fn path() -> &'static str {
+                    ""
+                }
+
+                #[doc = "a.b"]
+                pub static A_B: &super::Asset = &super::ASSETS[0usize];
+            }
+
+            pub static ASSETS_BY_PATH: [&Asset; 1usize] = [&ASSETS[0usize],];
+
+            pub fn get_by_path(relative_path: &str) -> Option<&'static Asset> {
+                ::iftree::runtime::EmbeddedTree::get(&Assets, relative_path)
+            }
+
+            pub struct Assets;
+
+            impl ::iftree::runtime::EmbeddedTree for Assets {
+                type Asset = Asset;
+
+                fn assets(&self) -> &'static [&'static Asset] {
+                    &ASSETS_BY_PATH
+                }
+
+                fn relative_paths(&self) -> &'static [&'static str] {
+                    static RELATIVE_PATHS: [&str; 1usize] = ["a.b",];
+                    &RELATIVE_PATHS
+                }
+            }
+
+            pub fn glob(
+                patterns: &str,
+            ) -> Result<::iftree::runtime::Glob<Asset>, ::iftree::runtime::PatternError> {
+                ::iftree::runtime::EmbeddedTree::glob(&Assets, patterns)
+            }
+
+            pub static TREE: ::iftree::runtime::Folder<Asset> = {
+                ::iftree::runtime::Folder {
+                    name: "",
+                    relative_path: "",
+                    parent: None,
+                    entries: &[
+                        ::iftree::runtime::Entry::File(::iftree::runtime::File {
+                            name: "a.b",
+                            relative_path: "a.b",
+                            asset: &ASSETS[0usize],
+                            parent: &TREE,
+                        }),
+                    ],
+                }
+            };
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+}
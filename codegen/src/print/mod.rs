@@ -0,0 +1,25 @@
+mod count_files;
+mod get_files;
+mod main;
+mod print_blobs;
+mod print_cfg;
+mod print_count;
+mod print_data;
+mod print_data_type;
+mod print_embedded_tree;
+mod print_folders;
+mod print_forest;
+mod print_index;
+mod print_initializer;
+mod print_lookup;
+mod print_normalization;
+mod print_packed;
+mod print_populator;
+mod print_resolve_path;
+mod print_service;
+mod print_static_type;
+mod print_tree;
+mod print_web;
+mod print_with_debug;
+
+pub use main::main;
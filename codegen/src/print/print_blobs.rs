@@ -0,0 +1,169 @@
+use super::get_files;
+use crate::model;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    if view.blobs.is_empty() || view.packed.is_some() {
+        proc_macro2::TokenStream::new()
+    } else {
+        let length = view.blobs.len();
+        let predicates = get_predicates(view);
+        let blobs = view
+            .blobs
+            .iter()
+            .zip(predicates)
+            .map(|(blob, predicates)| {
+                let contents = print_contents(blob);
+                match predicates {
+                    None => quote::quote! { #contents, },
+                    Some(predicates) => quote::quote! {
+                        #[cfg(any(#(#predicates),*))]
+                        #contents,
+                        #[cfg(not(any(#(#predicates),*)))]
+                        &[],
+                    },
+                }
+            })
+            .collect::<proc_macro2::TokenStream>();
+
+        quote::quote! {
+            static BLOBS: [&[u8]; #length] = [
+                #blobs
+            ];
+        }
+    }
+}
+
+/// Gets the predicates of the files referencing each blob, or `None` if any of
+/// these files is unconditional, so that unused blobs are not embedded.
+fn get_predicates(view: &model::View) -> Vec<Option<Vec<&syn::Meta>>> {
+    let mut predicates = vec![Some(vec![]); view.blobs.len()];
+
+    for file in get_files::main(&view.forest) {
+        if let Some(blob) = file.blob {
+            predicates[blob] = match (predicates[blob].take(), &file.cfg) {
+                (Some(mut predicates), Some(predicate)) => {
+                    predicates.push(&**predicate);
+                    Some(predicates)
+                }
+                _ => None,
+            };
+        }
+    }
+
+    predicates
+        .into_iter()
+        .map(|predicates| predicates.filter(|predicates| !predicates.is_empty()))
+        .collect()
+}
+
+fn print_contents(blob: &model::Blob) -> proc_macro2::TokenStream {
+    if blob.normalized {
+        let contents = proc_macro2::Literal::byte_string(&blob.contents);
+        quote::quote! { #contents }
+    } else {
+        let absolute_path = &blob.absolute_path;
+        quote::quote! { include_bytes!(#absolute_path) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_blobs() {
+        let actual = main(&model::View {
+            blobs: vec![],
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_packing_it_prints_no_blobs() {
+        let actual = main(&model::View {
+            blobs: vec![model::stubs::blob()],
+            packed: Some(model::Packed {
+                contents: vec![],
+                alignment: None,
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_blobs() {
+        let actual = main(&model::View {
+            blobs: vec![
+                model::Blob {
+                    absolute_path: "/a".into(),
+                    contents: b"b".to_vec(),
+                    normalized: false,
+                    references: 2,
+                },
+                model::Blob {
+                    absolute_path: "/c".into(),
+                    contents: b"d".to_vec(),
+                    normalized: true,
+                    references: 3,
+                },
+            ],
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            static BLOBS: [&[u8]; 2usize] = [
+                include_bytes!("/a"),
+                b"d",
+            ];
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_blob_of_conditional_files_only_it_embeds_blob_conditionally() {
+        let actual = main(&model::View {
+            forest: ["a", "b"]
+                .into_iter()
+                .map(|name| {
+                    (
+                        name.into(),
+                        model::Tree::File(model::File {
+                            blob: Some(0),
+                            cfg: Some(syn::parse_str(name).unwrap()),
+                            ..model::stubs::file()
+                        }),
+                    )
+                })
+                .collect(),
+            blobs: vec![model::Blob {
+                absolute_path: "/a".into(),
+                contents: b"b".to_vec(),
+                normalized: false,
+                references: 2,
+            }],
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            static BLOBS: [&[u8]; 1usize] = [
+                #[cfg(any(a, b))]
+                include_bytes!("/a"),
+                #[cfg(not(any(a, b)))]
+                &[],
+            ];
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+}
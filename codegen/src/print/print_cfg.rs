@@ -0,0 +1,37 @@
+use crate::model;
+
+pub fn main(file: &model::File) -> proc_macro2::TokenStream {
+    match &file.cfg {
+        None => proc_macro2::TokenStream::new(),
+        Some(predicate) => quote::quote! { #[cfg(#predicate)] },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_unconditional_file() {
+        let actual = main(&model::File {
+            cfg: None,
+            ..model::stubs::file()
+        });
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_conditional_file() {
+        let actual = main(&model::File {
+            cfg: Some(syn::parse_str("feature = \"a\"").unwrap()),
+            ..model::stubs::file()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { #[cfg(feature = "a")] }.to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::model;
+
+/// Counts files as a constant expression, which only counts a conditional file
+/// if its predicate holds.
+pub fn main(files: &[&model::File]) -> proc_macro2::TokenStream {
+    let mut count = 0_usize;
+    let mut predicates = vec![];
+
+    for file in files {
+        match &file.cfg {
+            None => count += 1,
+            Some(predicate) => predicates.push(predicate),
+        }
+    }
+
+    quote::quote! { #count #(+ (cfg!(#predicates) as usize))* }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_unconditional_files() {
+        let actual = main(&[&model::stubs::file(), &model::stubs::file()]);
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { 2usize }.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_conditional_files() {
+        let actual = main(&[
+            &model::File {
+                cfg: Some(syn::parse_str("a").unwrap()),
+                ..model::stubs::file()
+            },
+            &model::stubs::file(),
+            &model::File {
+                cfg: Some(syn::parse_str("not(b)").unwrap()),
+                ..model::stubs::file()
+            },
+        ]);
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            1usize + (cfg!(a) as usize) + (cfg!(not(b)) as usize)
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+}
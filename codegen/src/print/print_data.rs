@@ -0,0 +1,129 @@
+use crate::model;
+
+pub fn main(type_: &syn::Ident, data: &model::Data) -> proc_macro2::TokenStream {
+    match data {
+        model::Data::Null => quote::quote! { #type_::Null },
+
+        model::Data::Boolean(value) => quote::quote! { #type_::Boolean(#value) },
+
+        model::Data::Integer(value) => quote::quote! { #type_::Integer(#value) },
+
+        model::Data::Float(value) => {
+            let value = print_float(*value);
+            quote::quote! { #type_::Float(#value) }
+        }
+
+        model::Data::String(value) => quote::quote! { #type_::String(#value) },
+
+        model::Data::Array(values) => {
+            let contents = values
+                .iter()
+                .map(|value| {
+                    let value = main(type_, value);
+                    quote::quote! { #value, }
+                })
+                .collect::<proc_macro2::TokenStream>();
+            quote::quote! { #type_::Array(&[#contents]) }
+        }
+
+        model::Data::Table(entries) => {
+            let contents = entries
+                .iter()
+                .map(|(key, value)| {
+                    let value = main(type_, value);
+                    quote::quote! { (#key, #value), }
+                })
+                .collect::<proc_macro2::TokenStream>();
+            quote::quote! { #type_::Table(&[#contents]) }
+        }
+    }
+}
+
+fn print_float(value: f64) -> proc_macro2::TokenStream {
+    if value.is_nan() {
+        quote::quote! { f64::NAN }
+    } else if value == f64::INFINITY {
+        quote::quote! { f64::INFINITY }
+    } else if value == f64::NEG_INFINITY {
+        quote::quote! { f64::NEG_INFINITY }
+    } else {
+        let value = proc_macro2::Literal::f64_suffixed(value);
+        quote::quote! { #value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_scalars() {
+        let actual = main(
+            &quote::format_ident!("AssetValue"),
+            &model::Data::Array(vec![
+                model::Data::Null,
+                model::Data::Boolean(true),
+                model::Data::Integer(-2),
+                model::Data::Float(0.5),
+                model::Data::String("a".into()),
+            ]),
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            AssetValue::Array(&[
+                AssetValue::Null,
+                AssetValue::Boolean(true),
+                AssetValue::Integer(-2i64),
+                AssetValue::Float(0.5f64),
+                AssetValue::String("a"),
+            ])
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_table() {
+        let actual = main(
+            &quote::format_ident!("AssetValue"),
+            &model::Data::Table(vec![
+                ("a".into(), model::Data::Table(vec![])),
+                ("b".into(), model::Data::Array(vec![])),
+            ]),
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            AssetValue::Table(&[
+                ("a", AssetValue::Table(&[])),
+                ("b", AssetValue::Array(&[])),
+            ])
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_special_floats() {
+        let actual = main(
+            &quote::format_ident!("AssetValue"),
+            &model::Data::Array(vec![
+                model::Data::Float(f64::NAN),
+                model::Data::Float(f64::INFINITY),
+                model::Data::Float(f64::NEG_INFINITY),
+            ]),
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            AssetValue::Array(&[
+                AssetValue::Float(f64::NAN),
+                AssetValue::Float(f64::INFINITY),
+                AssetValue::Float(f64::NEG_INFINITY),
+            ])
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::model;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    if is_data_used(view) {
+        print_type(&get_name(&view.type_))
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+fn is_data_used(view: &model::View) -> bool {
+    view.visitors.iter().any(|visitor| match visitor {
+        model::Visitor::Array(model::Initializer::Default(structure)) => match structure {
+            model::TypeStructure::Unit => false,
+            model::TypeStructure::TypeAlias(populator) => is_parsed(populator),
+            model::TypeStructure::NamedFields(fields) => {
+                fields.iter().any(|(_, populator)| is_parsed(populator))
+            }
+            model::TypeStructure::TupleFields(populators) => populators.iter().any(is_parsed),
+        },
+        _ => false,
+    })
+}
+
+fn is_parsed(populator: &model::Populator) -> bool {
+    matches!(populator, model::Populator::Parsed(_))
+}
+
+/// Names the type of parsed data after the asset type to avoid collisions.
+pub fn get_name(type_: &syn::Ident) -> syn::Ident {
+    quote::format_ident!("{}Value", type_)
+}
+
+fn print_type(name: &syn::Ident) -> proc_macro2::TokenStream {
+    quote::quote! {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub enum #name {
+            Null,
+            Boolean(bool),
+            Integer(i64),
+            Float(f64),
+            String(&'static str),
+            Array(&'static [#name]),
+            Table(&'static [(&'static str, #name)]),
+        }
+
+        impl #name {
+            pub fn get(&self, key: &str) -> Option<&'static #name> {
+                match *self {
+                    #name::Table(entries) => entries
+                        .iter()
+                        .find(|(other_key, _)| *other_key == key)
+                        .map(|(_, value)| value),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_parsed_populator() {
+        let actual = main(&model::View {
+            visitors: vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TypeAlias(model::Populator::ContentsStr),
+            ))],
+            ..model::stubs::view()
+        });
+
+        let actual = actual.is_empty();
+        assert!(actual);
+    }
+
+    #[test]
+    fn handles_parsed_populator() {
+        let actual = main(&model::View {
+            visitors: vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TupleFields(vec![
+                    model::Populator::RelativePath,
+                    model::Populator::Parsed(model::Format::Json),
+                ]),
+            ))],
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = print_type(&quote::format_ident!("FooValue")).to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,380 @@
+use super::get_files;
+use super::print_cfg;
+use super::print_count;
+use super::print_index;
+use super::print_static_type;
+use crate::model;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    if (view.assets_by_path || view.embedded_tree)
+        && view
+            .visitors
+            .iter()
+            .any(|visitor| matches!(visitor, model::Visitor::Array(_)))
+    {
+        let type_ = print_static_type::main(view);
+        let mut files = get_files::main(&view.forest);
+        let conditional_files = files
+            .iter()
+            .copied()
+            .filter(|file| file.cfg.is_some())
+            .collect::<Vec<_>>();
+        files.sort_by(|left, right| left.relative_path.cmp(&right.relative_path));
+        let length = print_count::main(&files);
+        let assets = files
+            .iter()
+            .map(|file| {
+                let cfg = print_cfg::main(file);
+                let index = print_index::main(&conditional_files, file.index);
+                quote::quote! { #cfg &ASSETS[#index], }
+            })
+            .collect::<proc_macro2::TokenStream>();
+        let assets = quote::quote! {
+            static ASSETS_BY_PATH: [&#type_; #length] = [#assets];
+        };
+        let relative_paths = files
+            .iter()
+            .map(|file| {
+                let cfg = print_cfg::main(file);
+                let relative_path = &file.relative_path;
+                quote::quote! { #cfg #relative_path, }
+            })
+            .collect::<proc_macro2::TokenStream>();
+        let relative_paths = quote::quote! {
+            static RELATIVE_PATHS: [&str; #length] = [#relative_paths];
+        };
+        let assets_by_path = print_assets_by_path(view, &type_, &assets, &relative_paths);
+        let embedded_tree = print_embedded_tree(view, &type_, &assets, &relative_paths);
+
+        quote::quote! {
+            #assets_by_path
+
+            #embedded_tree
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+fn print_assets_by_path(
+    view: &model::View,
+    type_: &proc_macro2::TokenStream,
+    assets: &proc_macro2::TokenStream,
+    relative_paths: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if view.assets_by_path {
+        let get_by_path = print_get_by_path(view, type_, relative_paths);
+
+        quote::quote! {
+            pub #assets
+
+            #get_by_path
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+/// Prints the lookup in `ASSETS_BY_PATH`, which reuses the implementation of
+/// `EmbeddedTree` if generated to not embed the relative paths twice.
+fn print_get_by_path(
+    view: &model::View,
+    type_: &proc_macro2::TokenStream,
+    relative_paths: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let relative_path = match view.path_keys {
+        None => quote::quote! { relative_path },
+        Some(_) => quote::quote! { resolve_path(relative_path)? },
+    };
+
+    if view.embedded_tree {
+        quote::quote! {
+            pub fn get_by_path(relative_path: &str) -> Option<&'static #type_> {
+                ::iftree::runtime::EmbeddedTree::get(&Assets, #relative_path)
+            }
+        }
+    } else {
+        quote::quote! {
+            pub fn get_by_path(relative_path: &str) -> Option<&'static #type_> {
+                #relative_paths
+                let index = RELATIVE_PATHS.binary_search(&#relative_path).ok()?;
+                Some(ASSETS_BY_PATH[index])
+            }
+        }
+    }
+}
+
+/// Prints the unit struct `Assets`, which reuses `ASSETS_BY_PATH` if generated
+/// to not embed the sorted assets twice.
+fn print_embedded_tree(
+    view: &model::View,
+    type_: &proc_macro2::TokenStream,
+    assets: &proc_macro2::TokenStream,
+    relative_paths: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if view.embedded_tree {
+        let assets = if view.assets_by_path {
+            proc_macro2::TokenStream::new()
+        } else {
+            assets.clone()
+        };
+        let glob = print_glob(view, type_);
+
+        quote::quote! {
+            pub struct Assets;
+
+            impl ::iftree::runtime::EmbeddedTree for Assets {
+                type Asset = #type_;
+
+                fn assets(&self) -> &'static [&'static #type_] {
+                    #assets
+                    &ASSETS_BY_PATH
+                }
+
+                fn relative_paths(&self) -> &'static [&'static str] {
+                    #relative_paths
+                    &RELATIVE_PATHS
+                }
+            }
+
+            #glob
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+fn print_glob(view: &model::View, type_: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if view.glob {
+        quote::quote! {
+            pub fn glob(
+                patterns: &str,
+            ) -> Result<::iftree::runtime::Glob<#type_>, ::iftree::runtime::PatternError> {
+                ::iftree::runtime::EmbeddedTree::glob(&Assets, patterns)
+            }
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_array_sorted_by_path() {
+        let actual = main(&model::View {
+            type_: quote::format_ident!("Asset"),
+            assets_by_path: true,
+            embedded_tree: true,
+            visitors: vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::Unit,
+            ))],
+            forest: [
+                (
+                    "b".into(),
+                    model::Tree::File(model::File {
+                        index: 0,
+                        relative_path: "b".into(),
+                        ..model::stubs::file()
+                    }),
+                ),
+                (
+                    "a".into(),
+                    model::Tree::File(model::File {
+                        index: 1,
+                        relative_path: "a".into(),
+                        ..model::stubs::file()
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static ASSETS_BY_PATH: [&Asset; 2usize] = [&ASSETS[1usize], &ASSETS[0usize],];
+
+            pub fn get_by_path(relative_path: &str) -> Option<&'static Asset> {
+                ::iftree::runtime::EmbeddedTree::get(&Assets, relative_path)
+            }
+
+            pub struct Assets;
+
+            impl ::iftree::runtime::EmbeddedTree for Assets {
+                type Asset = Asset;
+
+                fn assets(&self) -> &'static [&'static Asset] {
+                    &ASSETS_BY_PATH
+                }
+
+                fn relative_paths(&self) -> &'static [&'static str] {
+                    static RELATIVE_PATHS: [&str; 2usize] = ["a", "b",];
+                    &RELATIVE_PATHS
+                }
+            }
+
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_embedded_tree_it_searches_relative_paths() {
+        let actual = main(&model::View {
+            type_: quote::format_ident!("Asset"),
+            assets_by_path: true,
+            embedded_tree: false,
+            visitors: vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::Unit,
+            ))],
+            forest: [(
+                "a".into(),
+                model::Tree::File(model::File {
+                    index: 0,
+                    relative_path: "a".into(),
+                    ..model::stubs::file()
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static ASSETS_BY_PATH: [&Asset; 1usize] = [&ASSETS[0usize],];
+
+            pub fn get_by_path(relative_path: &str) -> Option<&'static Asset> {
+                static RELATIVE_PATHS: [&str; 1usize] = ["a",];
+                let index = RELATIVE_PATHS.binary_search(&relative_path).ok()?;
+                Some(ASSETS_BY_PATH[index])
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_assets_by_path_it_keeps_sorted_assets_private() {
+        let actual = main(&model::View {
+            type_: quote::format_ident!("Asset"),
+            assets_by_path: false,
+            embedded_tree: true,
+            visitors: vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::Unit,
+            ))],
+            forest: [(
+                "a".into(),
+                model::Tree::File(model::File {
+                    index: 0,
+                    relative_path: "a".into(),
+                    ..model::stubs::file()
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub struct Assets;
+
+            impl ::iftree::runtime::EmbeddedTree for Assets {
+                type Asset = Asset;
+
+                fn assets(&self) -> &'static [&'static Asset] {
+                    static ASSETS_BY_PATH: [&Asset; 1usize] = [&ASSETS[0usize],];
+                    &ASSETS_BY_PATH
+                }
+
+                fn relative_paths(&self) -> &'static [&'static str] {
+                    static RELATIVE_PATHS: [&str; 1usize] = ["a",];
+                    &RELATIVE_PATHS
+                }
+            }
+
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_path_keys_it_resolves_path_to_get_by_path() {
+        let actual = print_get_by_path(
+            &model::View {
+                embedded_tree: true,
+                path_keys: Some(model::PathKeys {
+                    lookup: model::PathLookup::default(),
+                    keys: vec![],
+                }),
+                ..model::stubs::view()
+            },
+            &quote::quote! { Asset },
+            &proc_macro2::TokenStream::new(),
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub fn get_by_path(relative_path: &str) -> Option<&'static Asset> {
+                ::iftree::runtime::EmbeddedTree::get(&Assets, resolve_path(relative_path)?)
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_glob() {
+        let actual = print_glob(
+            &model::View {
+                glob: true,
+                ..model::stubs::view()
+            },
+            &quote::quote! { Asset },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub fn glob(
+                patterns: &str,
+            ) -> Result<::iftree::runtime::Glob<Asset>, ::iftree::runtime::PatternError> {
+                ::iftree::runtime::EmbeddedTree::glob(&Assets, patterns)
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_neither_assets_by_path_nor_embedded_tree_it_prints_nothing() {
+        let actual = main(&model::View {
+            assets_by_path: false,
+            visitors: vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::Unit,
+            ))],
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_array_it_prints_nothing() {
+        let actual = main(&model::View {
+            assets_by_path: true,
+            visitors: vec![model::Visitor::Identifiers],
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+}
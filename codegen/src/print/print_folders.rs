@@ -0,0 +1,122 @@
+use super::print_count;
+use crate::model;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    if view.include_folders {
+        let mut folders = vec![];
+        collect_folders(&view.forest, "", &mut folders);
+        let length = folders.len();
+
+        quote::quote! {
+            pub static FOLDERS: [::iftree::runtime::FolderInfo; #length] = [#(#folders)*];
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+/// Collects folders in preorder, like a depth-first walk.
+fn collect_folders(
+    forest: &model::Forest,
+    parent_path: &str,
+    folders: &mut Vec<proc_macro2::TokenStream>,
+) {
+    for (name, tree) in forest {
+        if let model::Tree::Folder(model::Folder { forest, .. }) = tree {
+            let relative_path = if parent_path.is_empty() {
+                name.into()
+            } else {
+                format!("{parent_path}/{name}")
+            };
+            let folder_count = forest
+                .values()
+                .filter(|tree| matches!(tree, model::Tree::Folder(_)))
+                .count();
+            let file_count = print_count::main(
+                &forest
+                    .values()
+                    .filter_map(|tree| match tree {
+                        model::Tree::File(file) => Some(file),
+                        model::Tree::Folder(_) => None,
+                    })
+                    .collect::<Vec<_>>(),
+            );
+
+            folders.push(quote::quote! {
+                ::iftree::runtime::FolderInfo {
+                    relative_path: #relative_path,
+                    folder_count: #folder_count,
+                    file_count: #file_count,
+                },
+            });
+            collect_folders(forest, &relative_path, folders);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_without_folders_included() {
+        let actual = main(&model::View {
+            include_folders: false,
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {}.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_folders_included() {
+        let actual = main(&model::View {
+            forest: [
+                (
+                    "a".into(),
+                    model::Tree::Folder(model::Folder {
+                        forest: [
+                            (
+                                "b".into(),
+                                model::Tree::Folder(model::Folder {
+                                    forest: model::Forest::new(),
+                                    ..model::stubs::folder()
+                                }),
+                            ),
+                            ("c".into(), model::Tree::File(model::stubs::file())),
+                            ("d".into(), model::Tree::File(model::stubs::file())),
+                        ]
+                        .into_iter()
+                        .collect(),
+                        ..model::stubs::folder()
+                    }),
+                ),
+                ("e".into(), model::Tree::File(model::stubs::file())),
+            ]
+            .into_iter()
+            .collect(),
+            include_folders: true,
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static FOLDERS: [::iftree::runtime::FolderInfo; 2usize] = [
+                ::iftree::runtime::FolderInfo {
+                    relative_path: "a",
+                    folder_count: 1usize,
+                    file_count: 2usize,
+                },
+                ::iftree::runtime::FolderInfo {
+                    relative_path: "a/b",
+                    folder_count: 0usize,
+                    file_count: 0usize,
+                },
+            ];
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,1062 @@
+use super::count_files;
+use super::get_files;
+use super::print_cfg;
+use super::print_count;
+use super::print_index;
+use super::print_initializer;
+use super::print_static_type;
+use crate::model;
+
+pub fn main(view: &model::View, visitor: &model::Visitor) -> proc_macro2::TokenStream {
+    let conditional_files = get_files::main(&view.forest)
+        .into_iter()
+        .filter(|file| file.cfg.is_some())
+        .collect::<Vec<_>>();
+    let static_type = print_static_type::main(view);
+    let context = Context {
+        type_: &view.type_,
+        static_type: &static_type,
+        visitor,
+        conditional_files: &conditional_files,
+        folder_functions: view.folder_functions,
+        depth: 0,
+        folder_path: "",
+    };
+    let contents = match visitor {
+        // Array elements follow the file indices, which need not be in tree
+        // order.
+        model::Visitor::Array(_) => get_files::main(&view.forest)
+            .into_iter()
+            .map(|file| print_file(&context, &file.relative_path, file))
+            .collect(),
+        _ => print_forest(&context, &view.forest),
+    };
+
+    match visitor {
+        model::Visitor::Array(_) => {
+            let type_ = &view.type_;
+            let length = print_count::main(&get_files::main(&view.forest));
+            quote::quote! { pub static ASSETS: [#type_; #length] = [#contents]; }
+        }
+
+        model::Visitor::Identifiers => {
+            let functions = print_folder_functions(&context, &view.forest, "");
+            quote::quote! {
+                pub mod base {
+                    #functions
+
+                    #contents
+                }
+            }
+        }
+
+        model::Visitor::Custom(model::CustomVisitor {
+            visit_base: None, ..
+        }) => contents,
+
+        model::Visitor::Custom(model::CustomVisitor {
+            visit_base: Some(macro_),
+            extended_inputs,
+            ..
+        }) => {
+            let length = count_files::main(&view.forest);
+            let extension = if *extended_inputs {
+                let range = print_index_range(&context, &view.forest);
+                quote::quote! { #range, }
+            } else {
+                proc_macro2::TokenStream::new()
+            };
+            quote::quote! { #macro_! { #length, #extension #contents } }
+        }
+    }
+}
+
+struct Context<'a> {
+    type_: &'a syn::Ident,
+    static_type: &'a proc_macro2::TokenStream,
+    visitor: &'a model::Visitor,
+    conditional_files: &'a [&'a model::File],
+    folder_functions: bool,
+    depth: usize,
+    folder_path: &'a str,
+}
+
+fn print_forest(context: &Context, forest: &model::Forest) -> proc_macro2::TokenStream {
+    forest
+        .iter()
+        .map(|(name, tree)| match tree {
+            model::Tree::File(file) => print_file(context, name, file),
+            model::Tree::Folder(folder) => print_folder(context, name, folder),
+        })
+        .collect()
+}
+
+fn print_file(context: &Context, name: &str, file: &model::File) -> proc_macro2::TokenStream {
+    match context.visitor {
+        model::Visitor::Array(initializer) => {
+            let cfg = print_cfg::main(file);
+            let index = print_index::main(context.conditional_files, file.index);
+            let element = print_initializer::main(context.type_, initializer, file, &index);
+            quote::quote! { #cfg #element, }
+        }
+
+        model::Visitor::Identifiers => {
+            let identifier = &file.identifier;
+            let root_path = (0..context.depth + 1)
+                .map(|_| quote::quote! { super:: })
+                .collect::<proc_macro2::TokenStream>();
+            let type_ = context.type_;
+            let cfg = print_cfg::main(file);
+            let index = print_index::main(context.conditional_files, file.index);
+            quote::quote! {
+                #[doc = #name]
+                #cfg
+                pub static #identifier: &#root_path #type_ = &#root_path ASSETS[#index];
+            }
+        }
+
+        model::Visitor::Custom(model::CustomVisitor {
+            visit_file,
+            extended_inputs,
+            ..
+        }) => {
+            let id = &file.identifier;
+            let index = file.index;
+            let relative_path = &file.relative_path;
+            let absolute_path = &file.absolute_path;
+            let extension = if *extended_inputs {
+                let depth = context.depth;
+                quote::quote! { , #depth }
+            } else {
+                proc_macro2::TokenStream::new()
+            };
+            quote::quote! {
+                #visit_file! { #name, #id, #index, #relative_path, #absolute_path #extension }
+            }
+        }
+    }
+}
+
+fn print_folder(context: &Context, name: &str, folder: &model::Folder) -> proc_macro2::TokenStream {
+    let folder_path = if context.folder_path.is_empty() {
+        name.into()
+    } else {
+        format!("{}/{name}", context.folder_path)
+    };
+    let contents = print_forest(
+        &Context {
+            depth: context.depth + 1,
+            folder_path: &folder_path,
+            ..*context
+        },
+        &folder.forest,
+    );
+
+    match context.visitor {
+        model::Visitor::Array(_) => contents,
+
+        model::Visitor::Identifiers => {
+            let identifier = &folder.identifier;
+            let functions = print_folder_functions(
+                &Context {
+                    depth: context.depth + 1,
+                    ..*context
+                },
+                &folder.forest,
+                &folder_path,
+            );
+            quote::quote! {
+                #[doc = #name]
+                pub mod #identifier {
+                    #functions
+
+                    #contents
+                }
+            }
+        }
+
+        model::Visitor::Custom(model::CustomVisitor {
+            visit_empty_folder: Some(macro_),
+            extended_inputs,
+            ..
+        }) if folder.forest.is_empty() => {
+            let id = &folder.identifier;
+            let extension = if *extended_inputs {
+                let depth = context.depth;
+                quote::quote! { , #depth, #folder_path }
+            } else {
+                proc_macro2::TokenStream::new()
+            };
+            quote::quote! { #macro_! { #name, #id #extension } }
+        }
+
+        model::Visitor::Custom(model::CustomVisitor {
+            visit_folder: None, ..
+        }) => contents,
+
+        model::Visitor::Custom(model::CustomVisitor {
+            visit_folder: Some(macro_),
+            extended_inputs,
+            ..
+        }) => {
+            let id = &folder.identifier;
+            let extension = if *extended_inputs {
+                let depth = context.depth;
+                let length = count_files::main(&folder.forest);
+                let range = print_index_range(context, &folder.forest);
+                quote::quote! { #depth, #folder_path, #length, #range, }
+            } else {
+                proc_macro2::TokenStream::new()
+            };
+            quote::quote! { #macro_! { #name, #id, #extension #contents } }
+        }
+    }
+}
+
+/// Prints the functions `all` and `path` of a folder module if configured. The
+/// files of the folder are contiguous in `ASSETS` as checked before.
+fn print_folder_functions(
+    context: &Context,
+    forest: &model::Forest,
+    folder_path: &str,
+) -> proc_macro2::TokenStream {
+    if context.folder_functions {
+        let root_path = (0..context.depth + 1)
+            .map(|_| quote::quote! { super:: })
+            .collect::<proc_macro2::TokenStream>();
+        let type_ = context.static_type;
+        let all = if context.depth == 0 {
+            quote::quote! { &#root_path ASSETS }
+        } else {
+            let range = print_index_range(context, forest);
+            quote::quote! { &#root_path ASSETS[#range] }
+        };
+        quote::quote! {
+            pub fn all() -> &'static [#root_path #type_] {
+                #all
+            }
+
+            pub const fn path() -> &'static str {
+                #folder_path
+            }
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+/// Spans the indices into `ASSETS` of the files in a forest, which are
+/// contiguous as checked before.
+fn print_index_range(context: &Context, forest: &model::Forest) -> proc_macro2::TokenStream {
+    let files = get_files::main(forest);
+    let (start, end) = match (files.first(), files.last()) {
+        (Some(first), Some(last)) => (first.index, last.index + 1),
+        _ => (0, 0),
+    };
+    let start = print_index::main(context.conditional_files, start);
+    let end = print_index::main(context.conditional_files, end);
+    quote::quote! { #start..#end }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_array() {
+        let actual = main(
+            &model::View {
+                type_: quote::format_ident!("Asset"),
+                forest: [
+                    (
+                        "0".into(),
+                        model::Tree::File(model::File {
+                            relative_path: "a".into(),
+                            ..model::stubs::file()
+                        }),
+                    ),
+                    (
+                        "1".into(),
+                        model::Tree::Folder(model::Folder {
+                            forest: [(
+                                "2".into(),
+                                model::Tree::File(model::File {
+                                    relative_path: "b/c".into(),
+                                    ..model::stubs::file()
+                                }),
+                            )]
+                            .into_iter()
+                            .collect(),
+                            ..model::stubs::folder()
+                        }),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                ..model::stubs::view()
+            },
+            &model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TypeAlias(model::Populator::RelativePath),
+            )),
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static ASSETS: [Asset; 2usize] = [
+                "a",
+                "b/c",
+            ];
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_array_it_orders_elements_by_index() {
+        let actual = main(
+            &model::View {
+                type_: quote::format_ident!("Asset"),
+                forest: [
+                    (
+                        "a".into(),
+                        model::Tree::File(model::File {
+                            index: 1,
+                            relative_path: "a".into(),
+                            ..model::stubs::file()
+                        }),
+                    ),
+                    (
+                        "b".into(),
+                        model::Tree::File(model::File {
+                            index: 0,
+                            relative_path: "b".into(),
+                            ..model::stubs::file()
+                        }),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                ..model::stubs::view()
+            },
+            &model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TypeAlias(model::Populator::RelativePath),
+            )),
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static ASSETS: [Asset; 2usize] = [
+                "b",
+                "a",
+            ];
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_cfg_it_prints_conditional_elements() {
+        let actual = main(
+            &model::View {
+                type_: quote::format_ident!("Asset"),
+                forest: [
+                    (
+                        "a".into(),
+                        model::Tree::File(model::File {
+                            index: 0,
+                            relative_path: "a".into(),
+                            cfg: Some(syn::parse_str("debug_assertions").unwrap()),
+                            ..model::stubs::file()
+                        }),
+                    ),
+                    (
+                        "b".into(),
+                        model::Tree::File(model::File {
+                            index: 1,
+                            relative_path: "b".into(),
+                            ..model::stubs::file()
+                        }),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                ..model::stubs::view()
+            },
+            &model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::TypeAlias(model::Populator::RelativePath),
+            )),
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static ASSETS: [Asset; 1usize + (cfg!(debug_assertions) as usize)] = [
+                #[cfg(debug_assertions)]
+                "a",
+                "b",
+            ];
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(test)]
+    mod handles_identifiers {
+        use super::*;
+
+        #[test]
+        fn handles_empty_set() {
+            let actual = main(
+                &model::View {
+                    forest: model::Forest::new(),
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Identifiers,
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! { pub mod base {} }.to_string();
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn given_lifetimes_it_prints_static_type() {
+            let actual = main(
+                &model::View {
+                    type_lifetimes: 1,
+                    forest: model::Forest::new(),
+                    folder_functions: true,
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Identifiers,
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                pub mod base {
+                    pub fn all() -> &'static [super::Foo<'static>] {
+                        &super::ASSETS
+                    }
+
+                    pub const fn path() -> &'static str {
+                        ""
+                    }
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_files() {
+            let actual = main(
+                &model::View {
+                    type_: quote::format_ident!("Asset"),
+                    forest: [
+                        (
+                            "0".into(),
+                            model::Tree::File(model::File {
+                                identifier: quote::format_ident!("A"),
+                                index: 1,
+                                ..model::stubs::file()
+                            }),
+                        ),
+                        (
+                            "1".into(),
+                            model::Tree::File(model::File {
+                                identifier: quote::format_ident!("BC"),
+                                index: 0,
+                                ..model::stubs::file()
+                            }),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Identifiers,
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                pub mod base {
+                    #[doc = "0"]
+                    pub static A: &super::Asset = &super::ASSETS[1usize];
+
+                    #[doc = "1"]
+                    pub static BC: &super::Asset = &super::ASSETS[0usize];
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_folders() {
+            let actual = main(
+                &model::View {
+                    type_: quote::format_ident!("Asset"),
+                    forest: [
+                        (
+                            "0".into(),
+                            model::Tree::File(model::File {
+                                identifier: quote::format_ident!("A"),
+                                index: 0,
+                                ..model::stubs::file()
+                            }),
+                        ),
+                        (
+                            "1".into(),
+                            model::Tree::Folder(model::Folder {
+                                identifier: quote::format_ident!("b"),
+                                forest: [
+                                    (
+                                        "2".into(),
+                                        model::Tree::Folder(model::Folder {
+                                            identifier: quote::format_ident!("a"),
+                                            forest: [(
+                                                "3".into(),
+                                                model::Tree::File(model::File {
+                                                    identifier: quote::format_ident!("B"),
+                                                    index: 2,
+                                                    ..model::stubs::file()
+                                                }),
+                                            )]
+                                            .into_iter()
+                                            .collect(),
+                                        }),
+                                    ),
+                                    (
+                                        "4".into(),
+                                        model::Tree::File(model::File {
+                                            identifier: quote::format_ident!("C"),
+                                            index: 1,
+                                            ..model::stubs::file()
+                                        }),
+                                    ),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            }),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    folder_functions: true,
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Identifiers,
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                pub mod base {
+                    pub fn all() -> &'static [super::Asset] {
+                        &super::ASSETS
+                    }
+
+                    pub const fn path() -> &'static str {
+                        ""
+                    }
+
+                    #[doc = "0"]
+                    pub static A: &super::Asset = &super::ASSETS[0usize];
+
+                    #[doc = "1"]
+                    pub mod b {
+                        pub fn all() -> &'static [super::super::Asset] {
+                            &super::super::ASSETS[1usize..3usize]
+                        }
+
+                        pub const fn path() -> &'static str {
+                            "1"
+                        }
+
+                        #[doc = "2"]
+                        pub mod a {
+                            pub fn all() -> &'static [super::super::super::Asset] {
+                                &super::super::super::ASSETS[2usize..3usize]
+                            }
+
+                            pub const fn path() -> &'static str {
+                                "1/2"
+                            }
+
+                            #[doc = "3"]
+                            pub static B: &super::super::super::Asset =
+                                &super::super::super::ASSETS[2usize];
+                        }
+
+                        #[doc = "4"]
+                        pub static C: &super::super::Asset = &super::super::ASSETS[1usize];
+                    }
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn given_cfg_it_adjusts_folder_slices() {
+            let actual = main(
+                &model::View {
+                    forest: [
+                        (
+                            "0".into(),
+                            model::Tree::File(model::File {
+                                identifier: quote::format_ident!("A"),
+                                index: 0,
+                                cfg: Some(syn::parse_str("unix").unwrap()),
+                                ..model::stubs::file()
+                            }),
+                        ),
+                        (
+                            "1".into(),
+                            model::Tree::Folder(model::Folder {
+                                identifier: quote::format_ident!("b"),
+                                forest: [(
+                                    "2".into(),
+                                    model::Tree::File(model::File {
+                                        identifier: quote::format_ident!("C"),
+                                        index: 1,
+                                        ..model::stubs::file()
+                                    }),
+                                )]
+                                .into_iter()
+                                .collect(),
+                            }),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    folder_functions: true,
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Identifiers,
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                pub mod base {
+                    pub fn all() -> &'static [super::Foo] {
+                        &super::ASSETS
+                    }
+
+                    pub const fn path() -> &'static str {
+                        ""
+                    }
+
+                    #[doc = "0"]
+                    #[cfg(unix)]
+                    pub static A: &super::Foo = &super::ASSETS[0usize];
+
+                    #[doc = "1"]
+                    pub mod b {
+                        pub fn all() -> &'static [super::super::Foo] {
+                            &super::super::ASSETS[
+                                0usize + (cfg!(unix) as usize)..1usize + (cfg!(unix) as usize)
+                            ]
+                        }
+
+                        pub const fn path() -> &'static str {
+                            "1"
+                        }
+
+                        #[doc = "2"]
+                        pub static C: &super::super::Foo =
+                            &super::super::ASSETS[0usize + (cfg!(unix) as usize)];
+                    }
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_empty_folder() {
+            let actual = main(
+                &model::View {
+                    type_: quote::format_ident!("Asset"),
+                    forest: [(
+                        "a".into(),
+                        model::Tree::Folder(model::Folder {
+                            identifier: quote::format_ident!("a"),
+                            forest: model::Forest::new(),
+                        }),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    folder_functions: true,
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Identifiers,
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                pub mod base {
+                    pub fn all() -> &'static [super::Asset] {
+                        &super::ASSETS
+                    }
+
+                    pub const fn path() -> &'static str {
+                        ""
+                    }
+
+                    #[doc = "a"]
+                    pub mod a {
+                        pub fn all() -> &'static [super::super::Asset] {
+                            &super::super::ASSETS[0usize..0usize]
+                        }
+
+                        pub const fn path() -> &'static str {
+                            "a"
+                        }
+                    }
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[cfg(test)]
+    mod handles_custom {
+        use super::*;
+
+        #[test]
+        fn handles_with_options() {
+            let actual = main(
+                &model::View {
+                    forest: [
+                        (
+                            "0".into(),
+                            model::Tree::File(model::File {
+                                identifier: quote::format_ident!("A"),
+                                index: 0,
+                                relative_path: "a".into(),
+                                absolute_path: "/a".into(),
+                                ..model::stubs::file()
+                            }),
+                        ),
+                        (
+                            "1".into(),
+                            model::Tree::Folder(model::Folder {
+                                identifier: quote::format_ident!("b"),
+                                forest: [
+                                    (
+                                        "2".into(),
+                                        model::Tree::Folder(model::Folder {
+                                            identifier: quote::format_ident!("a"),
+                                            forest: [(
+                                                "3".into(),
+                                                model::Tree::File(model::File {
+                                                    identifier: quote::format_ident!("B"),
+                                                    index: 2,
+                                                    relative_path: "b/a/b".into(),
+                                                    absolute_path: "/b/a/b".into(),
+                                                    ..model::stubs::file()
+                                                }),
+                                            )]
+                                            .into_iter()
+                                            .collect(),
+                                        }),
+                                    ),
+                                    (
+                                        "4".into(),
+                                        model::Tree::File(model::File {
+                                            identifier: quote::format_ident!("C"),
+                                            index: 1,
+                                            relative_path: "b/c".into(),
+                                            absolute_path: "/b/c".into(),
+                                            ..model::stubs::file()
+                                        }),
+                                    ),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            }),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Custom(model::CustomVisitor {
+                    visit_base: Some(syn::parse_str("visit_base").unwrap()),
+                    visit_folder: Some(syn::parse_str("visit_folder").unwrap()),
+                    visit_empty_folder: None,
+                    visit_file: syn::parse_str("visit_file").unwrap(),
+                    extended_inputs: false,
+                }),
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                visit_base! {
+                    3usize,
+                    visit_file! { "0", A, 0usize, "a", "/a" }
+                    visit_folder! {
+                        "1",
+                        b,
+                        visit_folder! {
+                            "2",
+                            a,
+                            visit_file! { "3", B, 2usize, "b/a/b", "/b/a/b" }
+                        }
+                        visit_file! { "4", C, 1usize, "b/c", "/b/c" }
+                    }
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_extended_inputs() {
+            let actual = main(
+                &model::View {
+                    forest: [
+                        (
+                            "0".into(),
+                            model::Tree::File(model::File {
+                                identifier: quote::format_ident!("A"),
+                                index: 0,
+                                relative_path: "a".into(),
+                                absolute_path: "/a".into(),
+                                ..model::stubs::file()
+                            }),
+                        ),
+                        (
+                            "b".into(),
+                            model::Tree::Folder(model::Folder {
+                                identifier: quote::format_ident!("b"),
+                                forest: [(
+                                    "c".into(),
+                                    model::Tree::Folder(model::Folder {
+                                        identifier: quote::format_ident!("c"),
+                                        forest: [
+                                            (
+                                                "1".into(),
+                                                model::Tree::File(model::File {
+                                                    identifier: quote::format_ident!("D"),
+                                                    index: 1,
+                                                    relative_path: "b/c/d".into(),
+                                                    absolute_path: "/b/c/d".into(),
+                                                    ..model::stubs::file()
+                                                }),
+                                            ),
+                                            (
+                                                "2".into(),
+                                                model::Tree::File(model::File {
+                                                    identifier: quote::format_ident!("E"),
+                                                    index: 2,
+                                                    relative_path: "b/c/e".into(),
+                                                    absolute_path: "/b/c/e".into(),
+                                                    ..model::stubs::file()
+                                                }),
+                                            ),
+                                        ]
+                                        .into_iter()
+                                        .collect(),
+                                    }),
+                                )]
+                                .into_iter()
+                                .collect(),
+                            }),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Custom(model::CustomVisitor {
+                    visit_base: Some(syn::parse_str("visit_base").unwrap()),
+                    visit_folder: Some(syn::parse_str("visit_folder").unwrap()),
+                    visit_empty_folder: None,
+                    visit_file: syn::parse_str("visit_file").unwrap(),
+                    extended_inputs: true,
+                }),
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                visit_base! {
+                    3usize,
+                    0usize..3usize,
+                    visit_file! { "0", A, 0usize, "a", "/a", 0usize }
+                    visit_folder! {
+                        "b",
+                        b,
+                        0usize,
+                        "b",
+                        2usize,
+                        1usize..3usize,
+                        visit_folder! {
+                            "c",
+                            c,
+                            1usize,
+                            "b/c",
+                            2usize,
+                            1usize..3usize,
+                            visit_file! { "1", D, 1usize, "b/c/d", "/b/c/d", 2usize }
+                            visit_file! { "2", E, 2usize, "b/c/e", "/b/c/e", 2usize }
+                        }
+                    }
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_empty_folders() {
+            let actual = main(
+                &model::View {
+                    forest: [
+                        (
+                            "a".into(),
+                            model::Tree::Folder(model::Folder {
+                                identifier: quote::format_ident!("a"),
+                                forest: model::Forest::new(),
+                            }),
+                        ),
+                        (
+                            "b".into(),
+                            model::Tree::Folder(model::Folder {
+                                identifier: quote::format_ident!("b"),
+                                forest: [(
+                                    "c".into(),
+                                    model::Tree::Folder(model::Folder {
+                                        identifier: quote::format_ident!("c"),
+                                        forest: model::Forest::new(),
+                                    }),
+                                )]
+                                .into_iter()
+                                .collect(),
+                            }),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Custom(model::CustomVisitor {
+                    visit_base: None,
+                    visit_folder: Some(syn::parse_str("visit_folder").unwrap()),
+                    visit_empty_folder: Some(syn::parse_str("visit_empty_folder").unwrap()),
+                    visit_file: syn::parse_str("visit_file").unwrap(),
+                    extended_inputs: true,
+                }),
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                visit_empty_folder! { "a", a, 0usize, "a" }
+                visit_folder! {
+                    "b",
+                    b,
+                    0usize,
+                    "b",
+                    0usize,
+                    0usize..0usize,
+                    visit_empty_folder! { "c", c, 1usize, "b/c" }
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_without_visit_base() {
+            let actual = main(
+                &model::View {
+                    forest: [(
+                        "0".into(),
+                        model::Tree::Folder(model::Folder {
+                            identifier: quote::format_ident!("a"),
+                            forest: [(
+                                "1".into(),
+                                model::Tree::File(model::File {
+                                    identifier: quote::format_ident!("B"),
+                                    index: 0,
+                                    relative_path: "a/b".into(),
+                                    absolute_path: "/a/b".into(),
+                                    ..model::stubs::file()
+                                }),
+                            )]
+                            .into_iter()
+                            .collect(),
+                        }),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Custom(model::CustomVisitor {
+                    visit_base: None,
+                    visit_folder: Some(syn::parse_str("visit_folder").unwrap()),
+                    visit_empty_folder: None,
+                    visit_file: syn::parse_str("visit_file").unwrap(),
+                    extended_inputs: false,
+                }),
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                visit_folder! {
+                    "0",
+                    a,
+                    visit_file! { "1", B, 0usize, "a/b", "/a/b" }
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn handles_without_visit_folder() {
+            let actual = main(
+                &model::View {
+                    forest: [(
+                        "0".into(),
+                        model::Tree::Folder(model::Folder {
+                            identifier: quote::format_ident!("a"),
+                            forest: [(
+                                "1".into(),
+                                model::Tree::File(model::File {
+                                    identifier: quote::format_ident!("B"),
+                                    index: 0,
+                                    relative_path: "a/b".into(),
+                                    absolute_path: "/a/b".into(),
+                                    ..model::stubs::file()
+                                }),
+                            )]
+                            .into_iter()
+                            .collect(),
+                        }),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::view()
+                },
+                &model::Visitor::Custom(model::CustomVisitor {
+                    visit_base: Some(syn::parse_str("visit_base").unwrap()),
+                    visit_folder: None,
+                    visit_empty_folder: None,
+                    visit_file: syn::parse_str("visit_file").unwrap(),
+                    extended_inputs: false,
+                }),
+            );
+
+            let actual = actual.to_string();
+            let expected = quote::quote! {
+                visit_base! {
+                    1usize,
+                    visit_file! { "1", B, 0usize, "a/b", "/a/b" }
+                }
+            }
+            .to_string();
+            assert_eq!(actual, expected);
+        }
+    }
+}
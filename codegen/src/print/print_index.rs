@@ -0,0 +1,51 @@
+use crate::model;
+
+/// Prints a file index as an index into `ASSETS` as a constant expression,
+/// which skips preceding conditional files whose predicates do not hold.
+pub fn main(conditional_files: &[&model::File], index: usize) -> proc_macro2::TokenStream {
+    let predicates = conditional_files
+        .iter()
+        .filter(|other| other.index < index)
+        .filter_map(|other| other.cfg.as_ref())
+        .collect::<Vec<_>>();
+    let index = index - predicates.len();
+
+    quote::quote! { #index #(+ (cfg!(#predicates) as usize))* }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_conditional_files() {
+        let actual = main(&[], 2);
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { 2usize }.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_preceding_conditional_files() {
+        let actual = main(
+            &[
+                &model::File {
+                    index: 0,
+                    cfg: Some(syn::parse_str("unix").unwrap()),
+                    ..model::stubs::file()
+                },
+                &model::File {
+                    index: 3,
+                    cfg: Some(syn::parse_str("windows").unwrap()),
+                    ..model::stubs::file()
+                },
+            ],
+            2,
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { 1usize + (cfg!(unix) as usize) }.to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,3 +1,4 @@
+use super::print_data_type;
 use super::print_populator;
 use crate::model;
 
@@ -5,10 +6,13 @@ pub fn main(
     type_: &syn::Ident,
     initializer: &model::Initializer,
     file: &model::File,
+    index: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     match initializer {
         model::Initializer::Default(populators) => print_default(type_, populators, file),
-        model::Initializer::Macro(name) => print_macro(name, file),
+        model::Initializer::Macro { macro_, arguments } => {
+            print_macro(macro_, arguments, file, index)
+        }
     }
 }
 
@@ -17,9 +21,10 @@ fn print_default(
     populators: &model::TypeStructure<model::Populator>,
     file: &model::File,
 ) -> proc_macro2::TokenStream {
+    let data_type = print_data_type::get_name(type_);
     let context = print_populator::Context {
-        relative_path: &file.relative_path,
-        absolute_path: &file.absolute_path,
+        data_type: Some(&data_type),
+        ..print_populator::Context::new(file)
     };
 
     match populators {
@@ -53,11 +58,42 @@ fn print_default(
     }
 }
 
-fn print_macro(macro_: &syn::Path, file: &model::File) -> proc_macro2::TokenStream {
-    let relative_path = &file.relative_path;
-    let absolute_path = &file.absolute_path;
+fn print_macro(
+    macro_: &syn::Path,
+    arguments: &[model::InitializerArgument],
+    file: &model::File,
+    index: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let arguments = arguments.iter().map(|argument| match argument {
+        model::InitializerArgument::AbsolutePath => {
+            let absolute_path = &file.absolute_path;
+            quote::quote! { #absolute_path }
+        }
+
+        model::InitializerArgument::Id => {
+            let identifier = &file.identifier;
+            quote::quote! { #identifier }
+        }
+
+        model::InitializerArgument::Index => index.clone(),
 
-    quote::quote! { #macro_!(#relative_path, #absolute_path) }
+        model::InitializerArgument::Name => {
+            let name = file.relative_path.rsplit('/').next().unwrap_or_default();
+            quote::quote! { #name }
+        }
+
+        model::InitializerArgument::RelativePath => {
+            let relative_path = &file.relative_path;
+            quote::quote! { #relative_path }
+        }
+
+        model::InitializerArgument::Size => {
+            let size = file.contents.len();
+            quote::quote! { #size }
+        }
+    });
+
+    quote::quote! { #macro_!(#(#arguments),*) }
 }
 
 #[cfg(test)]
@@ -81,6 +117,7 @@ mod tests {
                     absolute_path: "/a/b".into(),
                     ..model::stubs::file()
                 },
+                &quote::quote! { 123usize },
             );
 
             let actual = actual.to_string();
@@ -104,6 +141,7 @@ mod tests {
                     &quote::format_ident!("MyUnit"),
                     &model::Initializer::Default(model::TypeStructure::Unit),
                     &model::stubs::file(),
+                    &quote::quote! { 123usize },
                 );
 
                 let actual = actual.to_string();
@@ -122,6 +160,7 @@ mod tests {
                         absolute_path: "/a/b".into(),
                         ..model::stubs::file()
                     },
+                    &quote::quote! { 123usize },
                 );
 
                 let actual = actual.to_string();
@@ -141,6 +180,7 @@ mod tests {
                         absolute_path: "/a/b".into(),
                         ..model::stubs::file()
                     },
+                    &quote::quote! { 123usize },
                 );
 
                 let actual = actual.to_string();
@@ -164,6 +204,7 @@ mod tests {
                         relative_path: "b".into(),
                         ..model::stubs::file()
                     },
+                    &quote::quote! { 123usize },
                 );
 
                 let actual = actual.to_string();
@@ -182,16 +223,57 @@ mod tests {
     fn macro_() {
         let actual = main(
             &quote::format_ident!("Foo"),
-            &model::Initializer::Macro(syn::parse_str("abc").unwrap()),
+            &model::Initializer::Macro {
+                macro_: syn::parse_str("abc").unwrap(),
+                arguments: vec![
+                    model::InitializerArgument::RelativePath,
+                    model::InitializerArgument::AbsolutePath,
+                ],
+            },
             &model::File {
                 relative_path: "b".into(),
                 absolute_path: "/a/b".into(),
                 ..model::stubs::file()
             },
+            &quote::quote! { 123usize },
         );
 
         let actual = actual.to_string();
         let expected = quote::quote! { abc!("b", "/a/b") }.to_string();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn macro_with_arguments() {
+        let actual = main(
+            &quote::format_ident!("Foo"),
+            &model::Initializer::Macro {
+                macro_: syn::parse_str("abc").unwrap(),
+                arguments: vec![
+                    model::InitializerArgument::Name,
+                    model::InitializerArgument::Id,
+                    model::InitializerArgument::Index,
+                    model::InitializerArgument::RelativePath,
+                    model::InitializerArgument::AbsolutePath,
+                    model::InitializerArgument::Size,
+                ],
+            },
+            &model::File {
+                identifier: quote::format_ident!("r#C_D"),
+                index: 12,
+                relative_path: "a/b/c.d".into(),
+                absolute_path: "/x/a/b/c.d".into(),
+                contents: b"xyz".to_vec(),
+                ..model::stubs::file()
+            },
+            &quote::quote! { 12usize },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            abc!("c.d", r#C_D, 12usize, "a/b/c.d", "/x/a/b/c.d", 3usize)
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,431 @@
+use super::get_files;
+use super::print_cfg;
+use super::print_normalization;
+use super::print_populator;
+use crate::model;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    match &view.lookup {
+        None => proc_macro2::TokenStream::new(),
+
+        Some(model::Lookup {
+            filesystem,
+            overlay_variable,
+        }) => {
+            let files = get_files::main(&view.forest);
+
+            let filesystem_static = match filesystem {
+                None => proc_macro2::TokenStream::new(),
+                Some(filesystem) => {
+                    let filesystem = print_filesystem(filesystem);
+                    quote::quote! {
+                        #[cfg(debug_assertions)]
+                        static ASSET_FILESYSTEM: std::sync::LazyLock<::iftree::runtime::Filesystem> =
+                            std::sync::LazyLock::new(|| #filesystem);
+                    }
+                }
+            };
+            let is_filesystem = filesystem.is_some();
+            let get = print_get(&files, is_filesystem, overlay_variable.as_deref());
+            let relative_paths = print_relative_paths(&files, is_filesystem);
+
+            quote::quote! {
+                #filesystem_static
+
+                #get
+
+                #relative_paths
+
+                pub fn iter_assets() -> impl Iterator<
+                    Item = (std::borrow::Cow<'static, str>, std::borrow::Cow<'static, [u8]>),
+                > {
+                    asset_relative_paths()
+                        .into_iter()
+                        .filter_map(|relative_path| {
+                            let contents = get_asset(&relative_path)?;
+                            Some((relative_path, contents))
+                        })
+                }
+            }
+        }
+    }
+}
+
+fn print_get(
+    files: &[&model::File],
+    is_filesystem: bool,
+    overlay_variable: Option<&str>,
+) -> proc_macro2::TokenStream {
+    let embedded = print_get_embedded(files);
+
+    let contents = if is_filesystem {
+        quote::quote! {
+            #[cfg(not(debug_assertions))]
+            let contents: Option<std::borrow::Cow<'static, [u8]>> = #embedded;
+            #[cfg(debug_assertions)]
+            let contents: Option<std::borrow::Cow<'static, [u8]>> =
+                ASSET_FILESYSTEM.get(relative_path).map(Into::into);
+        }
+    } else {
+        quote::quote! {
+            let contents: Option<std::borrow::Cow<'static, [u8]>> = #embedded;
+        }
+    };
+
+    let overlay = match overlay_variable {
+        None => proc_macro2::TokenStream::new(),
+        Some(overlay_variable) => quote::quote! {
+            if let Some(contents) = ::iftree::runtime::Overlay::from_env(#overlay_variable)
+                .and_then(|overlay| overlay.get(relative_path))
+            {
+                return Some(contents.into());
+            }
+        },
+    };
+
+    quote::quote! {
+        pub fn get_asset(relative_path: &str) -> Option<std::borrow::Cow<'static, [u8]>> {
+            #overlay
+            #contents
+            contents
+        }
+    }
+}
+
+fn print_get_embedded(files: &[&model::File]) -> proc_macro2::TokenStream {
+    let cases = files
+        .iter()
+        .map(|file| {
+            let cfg = print_cfg::main(file);
+            let relative_path = &file.relative_path;
+            let contents = print_populator::main(
+                &model::Populator::ContentsBytes,
+                &print_populator::Context::new(file),
+            );
+            quote::quote! { #cfg #relative_path => Some((&#contents[..]).into()), }
+        })
+        .collect::<proc_macro2::TokenStream>();
+
+    quote::quote! {
+        match relative_path {
+            #cases
+            _ => None,
+        }
+    }
+}
+
+fn print_relative_paths(files: &[&model::File], is_filesystem: bool) -> proc_macro2::TokenStream {
+    let embedded = print_relative_paths_embedded(files);
+
+    if is_filesystem {
+        quote::quote! {
+            #[cfg(not(debug_assertions))]
+            pub fn asset_relative_paths() -> Vec<std::borrow::Cow<'static, str>> {
+                #embedded
+            }
+
+            #[cfg(debug_assertions)]
+            pub fn asset_relative_paths() -> Vec<std::borrow::Cow<'static, str>> {
+                ASSET_FILESYSTEM
+                    .relative_paths()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect()
+            }
+        }
+    } else {
+        quote::quote! {
+            pub fn asset_relative_paths() -> Vec<std::borrow::Cow<'static, str>> {
+                #embedded
+            }
+        }
+    }
+}
+
+fn print_relative_paths_embedded(files: &[&model::File]) -> proc_macro2::TokenStream {
+    let relative_paths = files
+        .iter()
+        .map(|file| {
+            let cfg = print_cfg::main(file);
+            let relative_path = &file.relative_path;
+            quote::quote! { #cfg #relative_path.into(), }
+        })
+        .collect::<proc_macro2::TokenStream>();
+
+    quote::quote! { vec![#relative_paths] }
+}
+
+fn print_filesystem(filesystem: &model::Filesystem) -> proc_macro2::TokenStream {
+    let model::Filesystem {
+        base_folder,
+        paths,
+        bundles,
+        order,
+        text,
+    } = filesystem;
+    let order = match order {
+        model::Order::Path => quote::quote! { Path },
+        model::Order::Pattern => quote::quote! { Pattern },
+        model::Order::Tree => quote::quote! { Tree },
+    };
+    let filesystem = quote::quote! {
+        ::iftree::runtime::Filesystem::new(#base_folder, #paths)
+            .with_order(::iftree::runtime::Order::#order)
+    };
+    let filesystem = match text {
+        None => filesystem,
+        Some(model::Text {
+            paths,
+            normalization,
+        }) => {
+            let normalization = print_normalization::main(*normalization);
+            quote::quote! { #filesystem.with_text(#paths, #normalization) }
+        }
+    };
+
+    if bundles.is_empty() {
+        filesystem
+    } else {
+        let bundles = bundles
+            .iter()
+            .map(|model::Bundle { paths, predicate }| {
+                quote::quote! {
+                    #[cfg(#predicate)]
+                    let filesystem = filesystem.with_paths(#paths);
+                }
+            })
+            .collect::<proc_macro2::TokenStream>();
+
+        quote::quote! {{
+            let filesystem = #filesystem;
+            #bundles
+            filesystem
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_lookup() {
+        let actual = main(&model::View {
+            lookup: None,
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_filesystem() {
+        let actual = main(&model::View {
+            forest: get_forest(),
+            lookup: Some(model::Lookup {
+                filesystem: Some(model::Filesystem {
+                    base_folder: "/a".into(),
+                    paths: "/**".into(),
+                    bundles: vec![],
+                    order: model::Order::Tree,
+                    text: None,
+                }),
+                overlay_variable: None,
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            #[cfg(debug_assertions)]
+            static ASSET_FILESYSTEM: std::sync::LazyLock<::iftree::runtime::Filesystem> =
+                std::sync::LazyLock::new(|| ::iftree::runtime::Filesystem::new("/a", "/**")
+                    .with_order(::iftree::runtime::Order::Tree));
+
+            pub fn get_asset(relative_path: &str) -> Option<std::borrow::Cow<'static, [u8]>> {
+                #[cfg(not(debug_assertions))]
+                let contents: Option<std::borrow::Cow<'static, [u8]>> = match relative_path {
+                    "c/d" => Some((&{
+                        const _: &[u8] = include_bytes!("/a/c/d");
+                        BLOBS[2usize]
+                    }[..]).into()),
+                    "b" => Some((&include_bytes!("/a/b")[..]).into()),
+                    _ => None,
+                };
+                #[cfg(debug_assertions)]
+                let contents: Option<std::borrow::Cow<'static, [u8]>> =
+                    ASSET_FILESYSTEM.get(relative_path).map(Into::into);
+                contents
+            }
+
+            #[cfg(not(debug_assertions))]
+            pub fn asset_relative_paths() -> Vec<std::borrow::Cow<'static, str>> {
+                vec!["c/d".into(), "b".into(),]
+            }
+
+            #[cfg(debug_assertions)]
+            pub fn asset_relative_paths() -> Vec<std::borrow::Cow<'static, str>> {
+                ASSET_FILESYSTEM
+                    .relative_paths()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect()
+            }
+
+            pub fn iter_assets() -> impl Iterator<
+                Item = (std::borrow::Cow<'static, str>, std::borrow::Cow<'static, [u8]>),
+            > {
+                asset_relative_paths()
+                    .into_iter()
+                    .filter_map(|relative_path| {
+                        let contents = get_asset(&relative_path)?;
+                        Some((relative_path, contents))
+                    })
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_bundles_it_adds_their_paths() {
+        let actual = print_filesystem(&model::Filesystem {
+            base_folder: "/a".into(),
+            paths: "/b/**".into(),
+            bundles: vec![model::Bundle {
+                paths: "/c/**".into(),
+                predicate: syn::parse_quote! { feature = "c" },
+            }],
+            order: model::Order::Tree,
+            text: None,
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            let filesystem = ::iftree::runtime::Filesystem::new("/a", "/b/**")
+                .with_order(::iftree::runtime::Order::Tree);
+            #[cfg(feature = "c")]
+            let filesystem = filesystem.with_paths("/c/**");
+            filesystem
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_text_it_normalizes() {
+        let actual = print_filesystem(&model::Filesystem {
+            base_folder: "/a".into(),
+            paths: "/**".into(),
+            bundles: vec![],
+            order: model::Order::Tree,
+            text: Some(model::Text {
+                paths: "*.md".into(),
+                normalization: model::Normalization {
+                    line_endings: Some(model::LineEnding::Crlf),
+                    strip_bom: true,
+                },
+            }),
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            ::iftree::runtime::Filesystem::new("/a", "/**")
+                .with_order(::iftree::runtime::Order::Tree)
+                .with_text(
+                    "*.md",
+                    ::iftree::runtime::Normalization {
+                        line_ending: Some(::iftree::runtime::LineEnding::Crlf),
+                        strip_bom: true,
+                    }
+                )
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_overlay() {
+        let actual = main(&model::View {
+            forest: get_forest(),
+            lookup: Some(model::Lookup {
+                filesystem: None,
+                overlay_variable: Some("MY_OVERLAY".into()),
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub fn get_asset(relative_path: &str) -> Option<std::borrow::Cow<'static, [u8]>> {
+                if let Some(contents) = ::iftree::runtime::Overlay::from_env("MY_OVERLAY")
+                    .and_then(|overlay| overlay.get(relative_path))
+                {
+                    return Some(contents.into());
+                }
+                let contents: Option<std::borrow::Cow<'static, [u8]>> = match relative_path {
+                    "c/d" => Some((&{
+                        const _: &[u8] = include_bytes!("/a/c/d");
+                        BLOBS[2usize]
+                    }[..]).into()),
+                    "b" => Some((&include_bytes!("/a/b")[..]).into()),
+                    _ => None,
+                };
+                contents
+            }
+
+            pub fn asset_relative_paths() -> Vec<std::borrow::Cow<'static, str>> {
+                vec!["c/d".into(), "b".into(),]
+            }
+
+            pub fn iter_assets() -> impl Iterator<
+                Item = (std::borrow::Cow<'static, str>, std::borrow::Cow<'static, [u8]>),
+            > {
+                asset_relative_paths()
+                    .into_iter()
+                    .filter_map(|relative_path| {
+                        let contents = get_asset(&relative_path)?;
+                        Some((relative_path, contents))
+                    })
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    fn get_forest() -> model::Forest {
+        [
+            (
+                "b".into(),
+                model::Tree::File(model::File {
+                    index: 1,
+                    relative_path: "b".into(),
+                    absolute_path: "/a/b".into(),
+                    ..model::stubs::file()
+                }),
+            ),
+            (
+                "c".into(),
+                model::Tree::Folder(model::Folder {
+                    forest: [(
+                        "d".into(),
+                        model::Tree::File(model::File {
+                            index: 0,
+                            relative_path: "c/d".into(),
+                            absolute_path: "/a/c/d".into(),
+                            blob: Some(2),
+                            ..model::stubs::file()
+                        }),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    ..model::stubs::folder()
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
@@ -0,0 +1,45 @@
+use crate::model;
+
+pub fn main(normalization: model::Normalization) -> proc_macro2::TokenStream {
+    let model::Normalization {
+        line_endings,
+        strip_bom,
+    } = normalization;
+    let line_ending = match line_endings {
+        None => quote::quote! { None },
+        Some(model::LineEnding::Crlf) => {
+            quote::quote! { Some(::iftree::runtime::LineEnding::Crlf) }
+        }
+        Some(model::LineEnding::Lf) => quote::quote! { Some(::iftree::runtime::LineEnding::Lf) },
+    };
+
+    quote::quote! {
+        ::iftree::runtime::Normalization {
+            line_ending: #line_ending,
+            strip_bom: #strip_bom,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = main(model::Normalization {
+            line_endings: Some(model::LineEnding::Lf),
+            strip_bom: true,
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            ::iftree::runtime::Normalization {
+                line_ending: Some(::iftree::runtime::LineEnding::Lf),
+                strip_bom: true,
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::model;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    print_packed(view, |contents| {
+        let contents = proc_macro2::Literal::byte_string(contents);
+        quote::quote! { *#contents }
+    })
+}
+
+/// Prints the blob without its contents, which are redundant for debugging.
+pub fn main_elided(view: &model::View) -> proc_macro2::TokenStream {
+    print_packed(view, |_| quote::quote! { .. })
+}
+
+fn print_packed(
+    view: &model::View,
+    print_contents: fn(&[u8]) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match &view.packed {
+        None => proc_macro2::TokenStream::new(),
+
+        Some(model::Packed {
+            contents,
+            alignment: None,
+        }) => {
+            let length = contents.len();
+            let contents = print_contents(contents);
+
+            quote::quote! {
+                static BLOB: [u8; #length] = #contents;
+            }
+        }
+
+        Some(model::Packed {
+            contents,
+            alignment: Some(alignment),
+        }) => {
+            let length = contents.len();
+            let contents = print_contents(contents);
+            let alignment = proc_macro2::Literal::usize_unsuffixed(*alignment);
+
+            quote::quote! {
+                static BLOB: &[u8; #length] = {
+                    #[repr(C, align(#alignment))]
+                    struct Aligned<T>(T);
+
+                    static ALIGNED: Aligned<[u8; #length]> = Aligned(#contents);
+
+                    &ALIGNED.0
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_packing() {
+        let actual = main(&model::View {
+            packed: None,
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_packing() {
+        let actual = main(&model::View {
+            packed: Some(model::Packed {
+                contents: b"ab".to_vec(),
+                alignment: None,
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            static BLOB: [u8; 2usize] = *b"ab";
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_packing_with_alignment() {
+        let actual = main(&model::View {
+            packed: Some(model::Packed {
+                contents: b"ab".to_vec(),
+                alignment: Some(8),
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            static BLOB: &[u8; 2usize] = {
+                #[repr(C, align(8))]
+                struct Aligned<T>(T);
+
+                static ALIGNED: Aligned<[u8; 2usize]> = Aligned(*b"ab");
+
+                &ALIGNED.0
+            };
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_elided_packing() {
+        let actual = main_elided(&model::View {
+            packed: Some(model::Packed {
+                contents: b"ab".to_vec(),
+                alignment: None,
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            static BLOB: [u8; 2usize] = ..;
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+}
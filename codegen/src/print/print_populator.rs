@@ -0,0 +1,871 @@
+use super::print_data;
+use super::print_normalization;
+#[cfg(feature = "compression")]
+use crate::compress;
+use crate::get_etag;
+use crate::model;
+use std::cmp;
+use std::collections;
+use std::str;
+
+pub fn main(populator: &model::Populator, context: &Context) -> proc_macro2::TokenStream {
+    let relative_path = context.relative_path;
+    let absolute_path = context.absolute_path;
+
+    match populator {
+        model::Populator::CacheControl => {
+            let cache_control = &get_cache(context).cache_control;
+            quote::quote! { #cache_control }
+        }
+
+        model::Populator::ContentsBytes => print_contents_bytes(context),
+
+        model::Populator::ContentsStr => print_contents_str(context),
+
+        model::Populator::Etag => {
+            let etag = get_etag::main(context.contents);
+            quote::quote! { #etag }
+        }
+
+        model::Populator::FingerprintedPath => {
+            let fingerprinted_path = get_cache(context)
+                .fingerprinted_path
+                .as_deref()
+                .unwrap_or(relative_path);
+            quote::quote! { #fingerprinted_path }
+        }
+
+        model::Populator::GetBytes => {
+            let read = match context.normalization {
+                None => quote::quote! { std::fs::read(#absolute_path).unwrap() },
+                Some(normalization) => print_read_normalized(absolute_path, normalization),
+            };
+            let contents = match context.obfuscated {
+                None => {
+                    let contents = print_contents_bytes(context);
+                    quote::quote! { (&#contents[..]).into() }
+                }
+                Some(obfuscated) => {
+                    let contents = print_deobfuscated(obfuscated);
+                    quote::quote! {{
+                        const _: &[u8] = include_bytes!(#absolute_path);
+                        #contents.into()
+                    }}
+                }
+            };
+
+            quote::quote! {{
+                fn get() -> std::borrow::Cow<'static, [u8]> {
+                    if cfg!(debug_assertions) {
+                        #read.into()
+                    } else {
+                        #contents
+                    }
+                }
+
+                get
+            }}
+        }
+
+        model::Populator::GetDecompressed => {
+            let compressed = print_compressed(context.contents);
+
+            quote::quote! {{
+                fn get() -> &'static [u8] {
+                    const _: &[u8] = include_bytes!(#absolute_path);
+                    static CONTENTS: std::sync::OnceLock<Vec<u8>> = std::sync::OnceLock::new();
+                    CONTENTS.get_or_init(|| ::iftree::runtime::decompress(#compressed))
+                }
+
+                get
+            }}
+        }
+
+        model::Populator::GetDecompressedStr => {
+            let compressed = print_compressed(context.contents);
+
+            quote::quote! {{
+                fn get() -> &'static str {
+                    const _: &str = include_str!(#absolute_path);
+                    static CONTENTS: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+                    CONTENTS.get_or_init(|| {
+                        String::from_utf8(::iftree::runtime::decompress(#compressed)).unwrap()
+                    })
+                }
+
+                get
+            }}
+        }
+
+        model::Populator::GetStr => {
+            let read = match context.normalization {
+                None => quote::quote! { std::fs::read_to_string(#absolute_path).unwrap() },
+                Some(normalization) => {
+                    let read = print_read_normalized(absolute_path, normalization);
+                    quote::quote! { String::from_utf8(#read).unwrap() }
+                }
+            };
+            let contents = match context.obfuscated {
+                None => {
+                    let contents = print_contents_str(context);
+                    quote::quote! { #contents.into() }
+                }
+                Some(obfuscated) => {
+                    let contents = print_deobfuscated(obfuscated);
+                    quote::quote! {{
+                        const _: &str = include_str!(#absolute_path);
+                        String::from_utf8(#contents).unwrap().into()
+                    }}
+                }
+            };
+
+            quote::quote! {{
+                fn get() -> std::borrow::Cow<'static, str> {
+                    if cfg!(debug_assertions) {
+                        #read.into()
+                    } else {
+                        #contents
+                    }
+                }
+
+                get
+            }}
+        }
+
+        model::Populator::Parsed(format) => {
+            let data_type = context
+                .data_type
+                .expect("Data type is known for parsed fields");
+            let data = print_data::main(data_type, &context.data[format]);
+            quote::quote! {{
+                const _: &[u8] = include_bytes!(#absolute_path);
+                &#data
+            }}
+        }
+
+        model::Populator::RelativePath => quote::quote! { #relative_path },
+    }
+}
+
+fn get_cache<'a>(context: &Context<'a>) -> &'a model::FileCache {
+    context
+        .cache
+        .expect("Cache metadata is computed for cache fields")
+}
+
+fn print_contents_bytes(context: &Context) -> proc_macro2::TokenStream {
+    let absolute_path = context.absolute_path;
+
+    let contents = match (print_shared_contents(context), context.normalization) {
+        (None, None) => return quote::quote! { include_bytes!(#absolute_path) },
+        (Some(contents), _) => contents,
+        (None, Some(_)) => {
+            let contents = proc_macro2::Literal::byte_string(context.contents);
+            quote::quote! { #contents }
+        }
+    };
+
+    print_tracked(context, contents)
+}
+
+fn print_contents_str(context: &Context) -> proc_macro2::TokenStream {
+    let absolute_path = context.absolute_path;
+
+    let contents = match (
+        str::from_utf8(context.contents),
+        print_shared_contents(context),
+        context.normalization,
+    ) {
+        (_, None, None) | (Err(_), _, _) => return quote::quote! { include_str!(#absolute_path) },
+
+        (Ok(_), Some(contents), _) => quote::quote! {
+            match std::str::from_utf8(#contents) {
+                Ok(contents) => contents,
+                Err(_) => unreachable!(),
+            }
+        },
+
+        (Ok(contents), None, Some(_)) => quote::quote! { #contents },
+    };
+
+    print_tracked(context, contents)
+}
+
+fn print_tracked(
+    context: &Context,
+    contents: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if context.slice.is_some() {
+        // Including each packed file would defeat the purpose of packing.
+        return contents;
+    }
+
+    let absolute_path = context.absolute_path;
+
+    quote::quote! {{
+        const _: &[u8] = include_bytes!(#absolute_path);
+        #contents
+    }}
+}
+
+#[cfg(feature = "compression")]
+fn print_compressed(contents: &[u8]) -> proc_macro2::TokenStream {
+    let compressed = proc_macro2::Literal::byte_string(&compress::main(contents));
+    quote::quote! { #compressed }
+}
+
+#[cfg(not(feature = "compression"))]
+fn print_compressed(_: &[u8]) -> proc_macro2::TokenStream {
+    unreachable!()
+}
+
+fn print_deobfuscated(obfuscated: &model::Obfuscated) -> proc_macro2::TokenStream {
+    let model::Obfuscated {
+        key_variable,
+        nonce,
+        contents,
+    } = obfuscated;
+    let contents = proc_macro2::Literal::byte_string(contents);
+
+    quote::quote! {
+        ::iftree::runtime::deobfuscate(env!(#key_variable), &[#(#nonce),*], #contents)
+    }
+}
+
+fn print_shared_contents(context: &Context) -> Option<proc_macro2::TokenStream> {
+    match (context.slice, context.blob) {
+        (Some(model::Slice { offset, length }), _) => Some(quote::quote! {
+            BLOB.split_at(#offset).1.split_at(#length).0
+        }),
+        (None, Some(blob)) => Some(quote::quote! { BLOBS[#blob] }),
+        (None, None) => None,
+    }
+}
+
+fn print_read_normalized(
+    absolute_path: &str,
+    normalization: model::Normalization,
+) -> proc_macro2::TokenStream {
+    let normalization = print_normalization::main(normalization);
+
+    quote::quote! {
+        ::iftree::runtime::normalize(&std::fs::read(#absolute_path).unwrap(), #normalization,)
+    }
+}
+
+#[derive(Clone, cmp::PartialEq, Debug)]
+pub struct Context<'a> {
+    pub relative_path: &'a str,
+    pub absolute_path: &'a str,
+    pub contents: &'a [u8],
+    pub normalization: Option<model::Normalization>,
+    pub obfuscated: Option<&'a model::Obfuscated>,
+    pub blob: Option<usize>,
+    pub slice: Option<model::Slice>,
+    pub data: &'a collections::BTreeMap<model::Format, model::Data>,
+    pub data_type: Option<&'a syn::Ident>,
+    pub cache: Option<&'a model::FileCache>,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(file: &'a model::File) -> Self {
+        Context {
+            relative_path: &file.relative_path,
+            absolute_path: &file.absolute_path,
+            contents: &file.contents,
+            normalization: file.normalization,
+            obfuscated: file.obfuscated.as_deref(),
+            blob: file.blob,
+            slice: file.slice,
+            data: &file.data,
+            data_type: None,
+            cache: file.cache.as_deref(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod stubs {
+    use super::*;
+
+    pub fn context<'a>() -> Context<'a> {
+        Context {
+            relative_path: "bar",
+            absolute_path: "/foo/bar",
+            contents: &[],
+            normalization: None,
+            obfuscated: None,
+            blob: None,
+            slice: None,
+            data: &DATA,
+            data_type: None,
+            cache: None,
+        }
+    }
+
+    pub fn cache() -> model::FileCache {
+        model::FileCache {
+            cache_control: "no-cache".into(),
+            fingerprinted_path: None,
+        }
+    }
+
+    pub fn normalization() -> model::Normalization {
+        model::Normalization {
+            line_endings: Some(model::LineEnding::Lf),
+            strip_bom: true,
+        }
+    }
+
+    static DATA: collections::BTreeMap<model::Format, model::Data> = collections::BTreeMap::new();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_cache_control() {
+        let actual = main(
+            &model::Populator::CacheControl,
+            &Context {
+                cache: Some(&stubs::cache()),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { "no-cache" }.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_contents_bytes() {
+        let actual = main(
+            &model::Populator::ContentsBytes,
+            &Context {
+                absolute_path: "/a/b",
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { include_bytes!("/a/b") }.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_contents_str() {
+        let actual = main(
+            &model::Populator::ContentsStr,
+            &Context {
+                absolute_path: "/a/b",
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { include_str!("/a/b") }.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn handles_get_decompressed() {
+        let actual = main(
+            &model::Populator::GetDecompressed,
+            &Context {
+                absolute_path: "/a/b",
+                contents: b"a",
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let compressed = proc_macro2::Literal::byte_string(&compress::main(b"a"));
+        let expected = quote::quote! {{
+            fn get() -> &'static [u8] {
+                const _: &[u8] = include_bytes!("/a/b");
+                static CONTENTS: std::sync::OnceLock<Vec<u8>> = std::sync::OnceLock::new();
+                CONTENTS.get_or_init(|| ::iftree::runtime::decompress(#compressed))
+            }
+
+            get
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn handles_get_decompressed_str() {
+        let actual = main(
+            &model::Populator::GetDecompressedStr,
+            &Context {
+                absolute_path: "/a/b",
+                contents: b"a",
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let compressed = proc_macro2::Literal::byte_string(&compress::main(b"a"));
+        let expected = quote::quote! {{
+            fn get() -> &'static str {
+                const _: &str = include_str!("/a/b");
+                static CONTENTS: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+                CONTENTS.get_or_init(|| {
+                    String::from_utf8(::iftree::runtime::decompress(#compressed)).unwrap()
+                })
+            }
+
+            get
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_obfuscated_get_bytes() {
+        let actual = main(
+            &model::Populator::GetBytes,
+            &Context {
+                absolute_path: "/a/b",
+                obfuscated: Some(&model::Obfuscated {
+                    key_variable: "KEY".into(),
+                    nonce: [1; 12],
+                    contents: b"xyz".to_vec(),
+                }),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            fn get() -> std::borrow::Cow<'static, [u8]> {
+                if cfg!(debug_assertions) {
+                    std::fs::read("/a/b").unwrap().into()
+                } else {{
+                    const _: &[u8] = include_bytes!("/a/b");
+                    ::iftree::runtime::deobfuscate(
+                        env!("KEY"),
+                        &[1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8],
+                        b"xyz"
+                    )
+                    .into()
+                }}
+            }
+
+            get
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_obfuscated_get_str() {
+        let actual = main(
+            &model::Populator::GetStr,
+            &Context {
+                absolute_path: "/a/b",
+                obfuscated: Some(&model::Obfuscated {
+                    key_variable: "KEY".into(),
+                    nonce: [1; 12],
+                    contents: b"xyz".to_vec(),
+                }),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            fn get() -> std::borrow::Cow<'static, str> {
+                if cfg!(debug_assertions) {
+                    std::fs::read_to_string("/a/b").unwrap().into()
+                } else {{
+                    const _: &str = include_str!("/a/b");
+                    String::from_utf8(::iftree::runtime::deobfuscate(
+                        env!("KEY"),
+                        &[1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8],
+                        b"xyz"
+                    ))
+                    .unwrap()
+                    .into()
+                }}
+            }
+
+            get
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_etag() {
+        let actual = main(
+            &model::Populator::Etag,
+            &Context {
+                contents: b"a",
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { "\"ca978112ca1bbdcafac231b39a23dc4d\"" }.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_fingerprinted_path() {
+        let actual = [Some("a/b.0.c"), None].map(|fingerprinted_path| {
+            main(
+                &model::Populator::FingerprintedPath,
+                &Context {
+                    relative_path: "a/b.c",
+                    cache: Some(&model::FileCache {
+                        fingerprinted_path: fingerprinted_path.map(String::from),
+                        ..stubs::cache()
+                    }),
+                    ..stubs::context()
+                },
+            )
+            .to_string()
+        });
+
+        let expected = [
+            quote::quote! { "a/b.0.c" }.to_string(),
+            quote::quote! { "a/b.c" }.to_string(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_get_bytes() {
+        let actual = main(
+            &model::Populator::GetBytes,
+            &Context {
+                absolute_path: "/a/b",
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            fn get() -> std::borrow::Cow<'static, [u8]> {
+                if cfg!(debug_assertions) {
+                    std::fs::read("/a/b").unwrap().into()
+                } else {
+                    (&include_bytes!("/a/b")[..]).into()
+                }
+            }
+
+            get
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_get_str() {
+        let actual = main(
+            &model::Populator::GetStr,
+            &Context {
+                absolute_path: "/a/b",
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            fn get() -> std::borrow::Cow<'static, str> {
+                if cfg!(debug_assertions) {
+                    std::fs::read_to_string("/a/b").unwrap().into()
+                } else {
+                    include_str!("/a/b").into()
+                }
+            }
+
+            get
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_parsed() {
+        let actual = main(
+            &model::Populator::Parsed(model::Format::Json),
+            &Context {
+                absolute_path: "/a/b",
+                data: &[
+                    (model::Format::Json, model::Data::Boolean(true)),
+                    (model::Format::Toml, model::Data::Null),
+                ]
+                .into_iter()
+                .collect(),
+                data_type: Some(&quote::format_ident!("AssetValue")),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            const _: &[u8] = include_bytes!("/a/b");
+            &AssetValue::Boolean(true)
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_normalized_contents_bytes() {
+        let actual = main(
+            &model::Populator::ContentsBytes,
+            &Context {
+                absolute_path: "/a/b",
+                contents: b"c\n",
+                normalization: Some(stubs::normalization()),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            const _: &[u8] = include_bytes!("/a/b");
+            b"c\n"
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_normalized_contents_str() {
+        let actual = main(
+            &model::Populator::ContentsStr,
+            &Context {
+                absolute_path: "/a/b",
+                contents: b"c\n",
+                normalization: Some(stubs::normalization()),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            const _: &[u8] = include_bytes!("/a/b");
+            "c\n"
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_normalized_contents_are_invalid_utf_8_it_includes_str() {
+        let actual = main(
+            &model::Populator::ContentsStr,
+            &Context {
+                absolute_path: "/a/b",
+                contents: b"\xFF",
+                normalization: Some(stubs::normalization()),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { include_str!("/a/b") }.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_normalized_get_bytes() {
+        let actual = main(
+            &model::Populator::GetBytes,
+            &Context {
+                absolute_path: "/a/b",
+                contents: b"c\r\n",
+                normalization: Some(model::Normalization {
+                    line_endings: Some(model::LineEnding::Crlf),
+                    strip_bom: true,
+                }),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            fn get() -> std::borrow::Cow<'static, [u8]> {
+                if cfg!(debug_assertions) {
+                    ::iftree::runtime::normalize(
+                        &std::fs::read("/a/b").unwrap(),
+                        ::iftree::runtime::Normalization {
+                            line_ending: Some(::iftree::runtime::LineEnding::Crlf),
+                            strip_bom: true,
+                        },
+                    )
+                    .into()
+                } else {
+                    (&{
+                        const _: &[u8] = include_bytes!("/a/b");
+                        b"c\r\n"
+                    }[..])
+                        .into()
+                }
+            }
+
+            get
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_normalized_get_str() {
+        let actual = main(
+            &model::Populator::GetStr,
+            &Context {
+                absolute_path: "/a/b",
+                contents: b"c",
+                normalization: Some(model::Normalization {
+                    line_endings: None,
+                    strip_bom: false,
+                }),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            fn get() -> std::borrow::Cow<'static, str> {
+                if cfg!(debug_assertions) {
+                    String::from_utf8(::iftree::runtime::normalize(
+                        &std::fs::read("/a/b").unwrap(),
+                        ::iftree::runtime::Normalization {
+                            line_ending: None,
+                            strip_bom: false,
+                        },
+                    ))
+                    .unwrap()
+                    .into()
+                } else {
+                    {
+                        const _: &[u8] = include_bytes!("/a/b");
+                        "c"
+                    }
+                    .into()
+                }
+            }
+
+            get
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_deduplicated_contents_bytes() {
+        let actual = main(
+            &model::Populator::ContentsBytes,
+            &Context {
+                absolute_path: "/a/b",
+                blob: Some(1),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            const _: &[u8] = include_bytes!("/a/b");
+            BLOBS[1usize]
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_deduplicated_contents_str() {
+        let actual = main(
+            &model::Populator::ContentsStr,
+            &Context {
+                absolute_path: "/a/b",
+                contents: b"c",
+                blob: Some(1),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {{
+            const _: &[u8] = include_bytes!("/a/b");
+            match std::str::from_utf8(BLOBS[1usize]) {
+                Ok(contents) => contents,
+                Err(_) => unreachable!(),
+            }
+        }}
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_packed_contents_bytes() {
+        let actual = main(
+            &model::Populator::ContentsBytes,
+            &Context {
+                absolute_path: "/a/b",
+                blob: Some(1),
+                slice: Some(model::Slice {
+                    offset: 2,
+                    length: 3,
+                }),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            BLOB.split_at(2usize).1.split_at(3usize).0
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_packed_contents_str() {
+        let actual = main(
+            &model::Populator::ContentsStr,
+            &Context {
+                absolute_path: "/a/b",
+                contents: b"c",
+                slice: Some(model::Slice {
+                    offset: 2,
+                    length: 1,
+                }),
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            match std::str::from_utf8(BLOB.split_at(2usize).1.split_at(1usize).0) {
+                Ok(contents) => contents,
+                Err(_) => unreachable!(),
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_relative_path() {
+        let actual = main(
+            &model::Populator::RelativePath,
+            &Context {
+                relative_path: "a/b",
+                ..stubs::context()
+            },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { "a/b" }.to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,116 @@
+use super::get_files;
+use super::print_cfg;
+use super::print_count;
+use crate::model;
+use std::collections;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    match &view.path_keys {
+        None => proc_macro2::TokenStream::new(),
+
+        Some(model::PathKeys { lookup, keys }) => {
+            let file_by_relative_path = get_files::main(&view.forest)
+                .into_iter()
+                .map(|file| (file.relative_path.as_str(), file))
+                .collect::<collections::HashMap<_, _>>();
+            let length = print_count::main(
+                &keys
+                    .iter()
+                    .map(|key| file_by_relative_path[key.relative_path.as_str()])
+                    .collect::<Vec<_>>(),
+            );
+            let entries = keys
+                .iter()
+                .map(|model::PathKey { key, relative_path }| {
+                    let cfg = print_cfg::main(file_by_relative_path[relative_path.as_str()]);
+                    quote::quote! { #cfg (#key, #relative_path), }
+                })
+                .collect::<proc_macro2::TokenStream>();
+            let normalize = lookup.normalize;
+            let case_insensitive = lookup.case_insensitive;
+
+            quote::quote! {
+                pub fn resolve_path(relative_path: &str) -> Option<&'static str> {
+                    static KEYS: [(&str, &str); #length] = [#entries];
+                    let key = ::iftree::runtime::get_lookup_key(
+                        relative_path,
+                        #normalize,
+                        #case_insensitive,
+                    )?;
+                    let index = KEYS.binary_search_by(|(other, _)| other.cmp(&&*key)).ok()?;
+                    Some(KEYS[index].1)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_path_keys() {
+        let actual = main(&model::View {
+            path_keys: Some(model::PathKeys {
+                lookup: model::PathLookup {
+                    case_insensitive: true,
+                    normalize: false,
+                },
+                keys: vec![
+                    model::PathKey {
+                        key: "a/b".into(),
+                        relative_path: "A/b".into(),
+                    },
+                    model::PathKey {
+                        key: "c".into(),
+                        relative_path: "c".into(),
+                    },
+                ],
+            }),
+            forest: [
+                (
+                    "A".into(),
+                    model::Tree::Folder(model::Folder {
+                        forest: [("b".into(), get_file("A/b", None))].into_iter().collect(),
+                        ..model::stubs::folder()
+                    }),
+                ),
+                ("c".into(), get_file("c", Some("unix"))),
+            ]
+            .into_iter()
+            .collect(),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub fn resolve_path(relative_path: &str) -> Option<&'static str> {
+                static KEYS: [(&str, &str); 1usize + (cfg!(unix) as usize)] =
+                    [("a/b", "A/b"), #[cfg(unix)] ("c", "c"),];
+                let key = ::iftree::runtime::get_lookup_key(relative_path, false, true,)?;
+                let index = KEYS.binary_search_by(|(other, _)| other.cmp(&&*key)).ok()?;
+                Some(KEYS[index].1)
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_path_keys_it_prints_nothing() {
+        let actual = main(&model::stubs::view());
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+
+    fn get_file(relative_path: &str, cfg: Option<&str>) -> model::Tree {
+        model::Tree::File(model::File {
+            relative_path: relative_path.into(),
+            cfg: cfg.map(|cfg| Box::new(syn::parse_str(cfg).unwrap())),
+            ..model::stubs::file()
+        })
+    }
+}
@@ -0,0 +1,270 @@
+use super::get_files;
+use super::print_cfg;
+use super::print_count;
+use super::print_populator;
+use crate::model;
+use std::collections;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    match &view.served_files {
+        None => proc_macro2::TokenStream::new(),
+
+        Some(served_files) => {
+            let files = get_files::main(&view.forest);
+            let file_by_relative_path = files
+                .into_iter()
+                .map(|file| (file.relative_path.as_str(), file))
+                .collect::<collections::HashMap<_, _>>();
+
+            let length = print_count::main(
+                &served_files
+                    .iter()
+                    .map(|served_file| file_by_relative_path[served_file.relative_path.as_str()])
+                    .collect::<Vec<_>>(),
+            );
+            let served_files = served_files
+                .iter()
+                .map(|served_file| print_served_file(&file_by_relative_path, served_file))
+                .collect::<proc_macro2::TokenStream>();
+            let resolve_with = match view.path_keys {
+                None => proc_macro2::TokenStream::new(),
+                Some(_) => quote::quote! { .resolve_with(resolve_path) },
+            };
+            let web = match view.web {
+                None => proc_macro2::TokenStream::new(),
+                Some(_) => quote::quote! { .web(&WEB) },
+            };
+
+            quote::quote! {
+                pub static SERVED_FILES: [::iftree::runtime::ServedFile; #length] = [
+                    #served_files
+                ];
+
+                pub fn service() -> ::iftree::runtime::ServeAssets {
+                    ::iftree::runtime::ServeAssets::new(&SERVED_FILES) #resolve_with #web
+                }
+            }
+        }
+    }
+}
+
+fn print_served_file(
+    file_by_relative_path: &collections::HashMap<&str, &model::File>,
+    served_file: &model::ServedFile,
+) -> proc_macro2::TokenStream {
+    let model::ServedFile {
+        relative_path,
+        media_type,
+        etag,
+        last_modified,
+        cache_control,
+        fingerprinted_path,
+        variants,
+    } = served_file;
+    let cfg = print_cfg::main(file_by_relative_path[relative_path.as_str()]);
+    let contents = print_contents(file_by_relative_path, relative_path);
+    let last_modified = print_option(last_modified);
+    let cache_control = print_option(cache_control);
+    let fingerprinted_path = print_option(fingerprinted_path);
+    let variants = variants
+        .iter()
+        .map(
+            |model::Variant {
+                 encoding,
+                 relative_path,
+                 etag,
+             }| {
+                let cfg = print_cfg::main(file_by_relative_path[relative_path.as_str()]);
+                let contents = print_contents(file_by_relative_path, relative_path);
+                quote::quote! {
+                    #cfg ::iftree::runtime::Variant {
+                        encoding: #encoding,
+                        contents: #contents,
+                        etag: #etag,
+                    },
+                }
+            },
+        )
+        .collect::<proc_macro2::TokenStream>();
+
+    quote::quote! {
+        #cfg ::iftree::runtime::ServedFile {
+            relative_path: #relative_path,
+            contents: #contents,
+            media_type: #media_type,
+            etag: #etag,
+            last_modified: #last_modified,
+            cache_control: #cache_control,
+            fingerprinted_path: #fingerprinted_path,
+            variants: &[#variants],
+        },
+    }
+}
+
+fn print_option(value: &Option<String>) -> proc_macro2::TokenStream {
+    match value {
+        None => quote::quote! { None },
+        Some(value) => quote::quote! { Some(#value) },
+    }
+}
+
+fn print_contents(
+    file_by_relative_path: &collections::HashMap<&str, &model::File>,
+    relative_path: &str,
+) -> proc_macro2::TokenStream {
+    print_populator::main(
+        &model::Populator::ContentsBytes,
+        &print_populator::Context::new(file_by_relative_path[relative_path]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_served_files() {
+        let actual = main(&model::View {
+            forest: [
+                (
+                    "a.css".into(),
+                    model::Tree::File(model::File {
+                        relative_path: "a.css".into(),
+                        absolute_path: "/a.css".into(),
+                        ..model::stubs::file()
+                    }),
+                ),
+                (
+                    "a.css.gz".into(),
+                    model::Tree::File(model::File {
+                        relative_path: "a.css.gz".into(),
+                        absolute_path: "/a.css.gz".into(),
+                        ..model::stubs::file()
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            served_files: Some(vec![
+                model::ServedFile {
+                    relative_path: "a.css".into(),
+                    media_type: "text/css".into(),
+                    etag: "\"b\"".into(),
+                    last_modified: Some("c".into()),
+                    cache_control: Some("no-cache".into()),
+                    fingerprinted_path: Some("a.0.css".into()),
+                    variants: vec![model::Variant {
+                        encoding: "gzip".into(),
+                        relative_path: "a.css.gz".into(),
+                        etag: "\"d\"".into(),
+                    }],
+                },
+                model::ServedFile {
+                    relative_path: "a.css.gz".into(),
+                    media_type: "application/gzip".into(),
+                    etag: "\"d\"".into(),
+                    last_modified: None,
+                    cache_control: None,
+                    fingerprinted_path: None,
+                    variants: vec![],
+                },
+            ]),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static SERVED_FILES: [::iftree::runtime::ServedFile; 2usize] = [
+                ::iftree::runtime::ServedFile {
+                    relative_path: "a.css",
+                    contents: include_bytes!("/a.css"),
+                    media_type: "text/css",
+                    etag: "\"b\"",
+                    last_modified: Some("c"),
+                    cache_control: Some("no-cache"),
+                    fingerprinted_path: Some("a.0.css"),
+                    variants: &[
+                        ::iftree::runtime::Variant {
+                            encoding: "gzip",
+                            contents: include_bytes!("/a.css.gz"),
+                            etag: "\"d\"",
+                        },
+                    ],
+                },
+                ::iftree::runtime::ServedFile {
+                    relative_path: "a.css.gz",
+                    contents: include_bytes!("/a.css.gz"),
+                    media_type: "application/gzip",
+                    etag: "\"d\"",
+                    last_modified: None,
+                    cache_control: None,
+                    fingerprinted_path: None,
+                    variants: &[],
+                },
+            ];
+
+            pub fn service() -> ::iftree::runtime::ServeAssets {
+                ::iftree::runtime::ServeAssets::new(&SERVED_FILES)
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_path_keys_it_resolves_paths() {
+        let actual = main(&model::View {
+            served_files: Some(vec![]),
+            path_keys: Some(model::PathKeys {
+                lookup: model::PathLookup::default(),
+                keys: vec![],
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static SERVED_FILES: [::iftree::runtime::ServedFile; 0usize] = [];
+
+            pub fn service() -> ::iftree::runtime::ServeAssets {
+                ::iftree::runtime::ServeAssets::new(&SERVED_FILES).resolve_with(resolve_path)
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_web_it_resolves_web_paths() {
+        let actual = main(&model::View {
+            served_files: Some(vec![]),
+            web: Some(model::Web {
+                index_files: vec![],
+                fallback: None,
+                trailing_slash: model::TrailingSlash::Ignore,
+                cache: None,
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static SERVED_FILES: [::iftree::runtime::ServedFile; 0usize] = [];
+
+            pub fn service() -> ::iftree::runtime::ServeAssets {
+                ::iftree::runtime::ServeAssets::new(&SERVED_FILES).web(&WEB)
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_served_files_it_prints_nothing() {
+        let actual = main(&model::stubs::view());
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+}
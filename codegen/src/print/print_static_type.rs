@@ -0,0 +1,43 @@
+use crate::model;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    let type_ = &view.type_;
+
+    if view.type_lifetimes == 0 {
+        quote::quote! { #type_ }
+    } else {
+        let lifetimes = (0..view.type_lifetimes).map(|_| quote::quote! { 'static });
+        quote::quote! { #type_<#(#lifetimes),*> }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_lifetimes() {
+        let actual = main(&model::View {
+            type_: quote::format_ident!("Asset"),
+            type_lifetimes: 0,
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { Asset }.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_lifetimes() {
+        let actual = main(&model::View {
+            type_: quote::format_ident!("Asset"),
+            type_lifetimes: 2,
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { Asset<'static, 'static> }.to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,269 @@
+use super::get_files;
+use super::print_cfg;
+use super::print_index;
+use super::print_static_type;
+use crate::model;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    if view.tree
+        && view
+            .visitors
+            .iter()
+            .any(|visitor| matches!(visitor, model::Visitor::Array(_)))
+    {
+        let context = Context {
+            type_: print_static_type::main(view),
+            conditional_files: get_files::main(&view.forest)
+                .into_iter()
+                .filter(|file| file.cfg.is_some())
+                .collect(),
+        };
+        let mut folders = vec![];
+        let entries = print_entries(
+            &context,
+            &mut folders,
+            &Parent {
+                identifier: &quote::format_ident!("TREE"),
+                relative_path: "",
+            },
+            &view.forest,
+        );
+        let type_ = &context.type_;
+
+        quote::quote! {
+            pub static TREE: ::iftree::runtime::Folder<#type_> = {
+                #(#folders)*
+
+                ::iftree::runtime::Folder {
+                    name: "",
+                    relative_path: "",
+                    parent: None,
+                    entries: &[#entries],
+                }
+            };
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+struct Context<'a> {
+    type_: proc_macro2::TokenStream,
+    conditional_files: Vec<&'a model::File>,
+}
+
+struct Parent<'a> {
+    identifier: &'a syn::Ident,
+    relative_path: &'a str,
+}
+
+fn print_entries(
+    context: &Context,
+    folders: &mut Vec<proc_macro2::TokenStream>,
+    parent: &Parent,
+    forest: &model::Forest,
+) -> proc_macro2::TokenStream {
+    let parent_identifier = parent.identifier;
+
+    forest
+        .iter()
+        .map(|(name, tree)| match tree {
+            model::Tree::File(file) => {
+                let cfg = print_cfg::main(file);
+                let relative_path = &file.relative_path;
+                let index = print_index::main(&context.conditional_files, file.index);
+                quote::quote! {
+                    #cfg
+                    ::iftree::runtime::Entry::File(::iftree::runtime::File {
+                        name: #name,
+                        relative_path: #relative_path,
+                        asset: &ASSETS[#index],
+                        parent: &#parent_identifier,
+                    }),
+                }
+            }
+
+            model::Tree::Folder(model::Folder { forest, .. }) => {
+                let folder = print_folder(context, folders, parent, name, forest);
+                quote::quote! { ::iftree::runtime::Entry::Folder(&#folder), }
+            }
+        })
+        .collect()
+}
+
+fn print_folder(
+    context: &Context,
+    folders: &mut Vec<proc_macro2::TokenStream>,
+    parent: &Parent,
+    name: &str,
+    forest: &model::Forest,
+) -> syn::Ident {
+    // Reserve a position first to number folders in preorder.
+    let position = folders.len();
+    folders.push(proc_macro2::TokenStream::new());
+
+    let identifier = quote::format_ident!("FOLDER_{position}");
+    let relative_path = if parent.relative_path.is_empty() {
+        name.into()
+    } else {
+        format!("{}/{name}", parent.relative_path)
+    };
+    let entries = print_entries(
+        context,
+        folders,
+        &Parent {
+            identifier: &identifier,
+            relative_path: &relative_path,
+        },
+        forest,
+    );
+    let type_ = &context.type_;
+    let parent_identifier = parent.identifier;
+
+    folders[position] = quote::quote! {
+        static #identifier: ::iftree::runtime::Folder<#type_> = ::iftree::runtime::Folder {
+            name: #name,
+            relative_path: #relative_path,
+            parent: Some(&#parent_identifier),
+            entries: &[#entries],
+        };
+    };
+    identifier
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_array() {
+        let actual = main(&model::View {
+            type_: quote::format_ident!("Asset"),
+            tree: true,
+            visitors: vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::Unit,
+            ))],
+            forest: [
+                (
+                    "a".into(),
+                    model::Tree::Folder(model::Folder {
+                        forest: [
+                            (
+                                "b".into(),
+                                model::Tree::Folder(model::Folder {
+                                    forest: [(
+                                        "c".into(),
+                                        model::Tree::File(model::File {
+                                            index: 0,
+                                            relative_path: "a/b/c".into(),
+                                            ..model::stubs::file()
+                                        }),
+                                    )]
+                                    .into_iter()
+                                    .collect(),
+                                    ..model::stubs::folder()
+                                }),
+                            ),
+                            ("d".into(), model::Tree::Folder(model::stubs::folder())),
+                        ]
+                        .into_iter()
+                        .collect(),
+                        ..model::stubs::folder()
+                    }),
+                ),
+                (
+                    "e".into(),
+                    model::Tree::File(model::File {
+                        index: 1,
+                        relative_path: "e".into(),
+                        ..model::stubs::file()
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static TREE: ::iftree::runtime::Folder<Asset> = {
+                static FOLDER_0: ::iftree::runtime::Folder<Asset> = ::iftree::runtime::Folder {
+                    name: "a",
+                    relative_path: "a",
+                    parent: Some(&TREE),
+                    entries: &[
+                        ::iftree::runtime::Entry::Folder(&FOLDER_1),
+                        ::iftree::runtime::Entry::Folder(&FOLDER_2),
+                    ],
+                };
+
+                static FOLDER_1: ::iftree::runtime::Folder<Asset> = ::iftree::runtime::Folder {
+                    name: "b",
+                    relative_path: "a/b",
+                    parent: Some(&FOLDER_0),
+                    entries: &[
+                        ::iftree::runtime::Entry::File(::iftree::runtime::File {
+                            name: "c",
+                            relative_path: "a/b/c",
+                            asset: &ASSETS[0usize],
+                            parent: &FOLDER_1,
+                        }),
+                    ],
+                };
+
+                static FOLDER_2: ::iftree::runtime::Folder<Asset> = ::iftree::runtime::Folder {
+                    name: "d",
+                    relative_path: "a/d",
+                    parent: Some(&FOLDER_0),
+                    entries: &[],
+                };
+
+                ::iftree::runtime::Folder {
+                    name: "",
+                    relative_path: "",
+                    parent: None,
+                    entries: &[
+                        ::iftree::runtime::Entry::Folder(&FOLDER_0),
+                        ::iftree::runtime::Entry::File(::iftree::runtime::File {
+                            name: "e",
+                            relative_path: "e",
+                            asset: &ASSETS[1usize],
+                            parent: &TREE,
+                        }),
+                    ],
+                }
+            };
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_tree_it_prints_nothing() {
+        let actual = main(&model::View {
+            tree: false,
+            visitors: vec![model::Visitor::Array(model::Initializer::Default(
+                model::TypeStructure::Unit,
+            ))],
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_array_it_prints_nothing() {
+        let actual = main(&model::View {
+            tree: true,
+            visitors: vec![model::Visitor::Identifiers],
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,261 @@
+use super::get_files;
+use super::print_cfg;
+use super::print_count;
+use crate::model;
+
+pub fn main(view: &model::View) -> proc_macro2::TokenStream {
+    match &view.web {
+        None => proc_macro2::TokenStream::new(),
+
+        Some(model::Web {
+            index_files,
+            fallback,
+            trailing_slash,
+            cache,
+        }) => {
+            let fallback = match fallback {
+                None => quote::quote! { None },
+                Some(fallback) => quote::quote! { Some(#fallback) },
+            };
+            let trailing_slash = match trailing_slash {
+                model::TrailingSlash::Ignore => quote::quote! { Ignore },
+                model::TrailingSlash::Redirect => quote::quote! { Redirect },
+            };
+            let get = print_get(view);
+            let fingerprinted_paths = match cache {
+                None => proc_macro2::TokenStream::new(),
+                Some(_) => print_fingerprinted_paths(view),
+            };
+
+            quote::quote! {
+                pub static WEB: ::iftree::runtime::Web = ::iftree::runtime::Web {
+                    index_files: &[#(#index_files),*],
+                    fallback: #fallback,
+                    trailing_slash: ::iftree::runtime::TrailingSlash::#trailing_slash,
+                };
+
+                pub fn resolve_web_path(path: &str) -> Option<::iftree::runtime::WebPath> {
+                    #get
+                }
+
+                #fingerprinted_paths
+            }
+        }
+    }
+}
+
+fn print_get(view: &model::View) -> proc_macro2::TokenStream {
+    match view.path_keys {
+        None => {
+            let mut files = get_files::main(&view.forest);
+            files.sort_by_key(|file| &file.relative_path);
+            let length = print_count::main(&files);
+            let relative_paths = files
+                .into_iter()
+                .map(|file| {
+                    let cfg = print_cfg::main(file);
+                    let relative_path = &file.relative_path;
+                    quote::quote! { #cfg #relative_path, }
+                })
+                .collect::<proc_macro2::TokenStream>();
+
+            quote::quote! {
+                static RELATIVE_PATHS: [&str; #length] = [#relative_paths];
+                WEB.resolve(path, |relative_path| {
+                    let index = RELATIVE_PATHS.binary_search(&relative_path).ok()?;
+                    Some(RELATIVE_PATHS[index])
+                })
+            }
+        }
+
+        Some(_) => quote::quote! { WEB.resolve(path, resolve_path) },
+    }
+}
+
+fn print_fingerprinted_paths(view: &model::View) -> proc_macro2::TokenStream {
+    let mut entries = get_files::main(&view.forest)
+        .into_iter()
+        .filter_map(|file| {
+            let fingerprinted_path = file.cache.as_ref()?.fingerprinted_path.as_ref()?;
+            Some((file, fingerprinted_path))
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by_key(|(file, _)| &file.relative_path);
+    let length = print_count::main(&entries.iter().map(|(file, _)| *file).collect::<Vec<_>>());
+    let entries = entries
+        .into_iter()
+        .map(|(file, fingerprinted_path)| {
+            let cfg = print_cfg::main(file);
+            let relative_path = &file.relative_path;
+            quote::quote! { #cfg (#relative_path, #fingerprinted_path), }
+        })
+        .collect::<proc_macro2::TokenStream>();
+
+    quote::quote! {
+        pub static FINGERPRINTED_PATHS: [(&str, &str); #length] = [#entries];
+
+        pub fn get_fingerprinted_path(relative_path: &str) -> Option<&'static str> {
+            let index = FINGERPRINTED_PATHS
+                .binary_search_by(|(other, _)| other.cmp(&relative_path))
+                .ok()?;
+            Some(FINGERPRINTED_PATHS[index].1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_web() {
+        let actual = main(&model::View {
+            forest: ["b", "a"]
+                .into_iter()
+                .map(|relative_path| {
+                    (
+                        relative_path.into(),
+                        model::Tree::File(model::File {
+                            relative_path: relative_path.into(),
+                            ..model::stubs::file()
+                        }),
+                    )
+                })
+                .collect(),
+            web: Some(model::Web {
+                index_files: vec!["index.htm".into(), "index.html".into()],
+                fallback: Some("a".into()),
+                trailing_slash: model::TrailingSlash::Redirect,
+                cache: None,
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static WEB: ::iftree::runtime::Web = ::iftree::runtime::Web {
+                index_files: &["index.htm", "index.html"],
+                fallback: Some("a"),
+                trailing_slash: ::iftree::runtime::TrailingSlash::Redirect,
+            };
+
+            pub fn resolve_web_path(path: &str) -> Option<::iftree::runtime::WebPath> {
+                static RELATIVE_PATHS: [&str; 2usize] = ["a", "b",];
+                WEB.resolve(path, |relative_path| {
+                    let index = RELATIVE_PATHS.binary_search(&relative_path).ok()?;
+                    Some(RELATIVE_PATHS[index])
+                })
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_path_keys_it_resolves_paths() {
+        let actual = main(&model::View {
+            path_keys: Some(model::PathKeys {
+                lookup: model::PathLookup::default(),
+                keys: vec![],
+            }),
+            web: Some(model::Web {
+                index_files: vec![],
+                fallback: None,
+                trailing_slash: model::TrailingSlash::Ignore,
+                cache: None,
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static WEB: ::iftree::runtime::Web = ::iftree::runtime::Web {
+                index_files: &[],
+                fallback: None,
+                trailing_slash: ::iftree::runtime::TrailingSlash::Ignore,
+            };
+
+            pub fn resolve_web_path(path: &str) -> Option<::iftree::runtime::WebPath> {
+                WEB.resolve(path, resolve_path)
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_cache_it_prints_fingerprinted_paths() {
+        let actual = main(&model::View {
+            forest: [
+                ("b.css", Some("b.0.css")),
+                ("a.html", None),
+                ("a.js", Some("a.1.js")),
+            ]
+            .into_iter()
+            .map(|(relative_path, fingerprinted_path)| {
+                (
+                    relative_path.into(),
+                    model::Tree::File(model::File {
+                        relative_path: relative_path.into(),
+                        cache: Some(Box::new(model::FileCache {
+                            cache_control: "no-cache".into(),
+                            fingerprinted_path: fingerprinted_path.map(String::from),
+                        })),
+                        ..model::stubs::file()
+                    }),
+                )
+            })
+            .collect(),
+            path_keys: Some(model::PathKeys {
+                lookup: model::PathLookup::default(),
+                keys: vec![],
+            }),
+            web: Some(model::Web {
+                index_files: vec![],
+                fallback: None,
+                trailing_slash: model::TrailingSlash::Ignore,
+                cache: Some(model::Cache {
+                    rules: vec![],
+                    fingerprint: "*.css\n*.js".into(),
+                }),
+            }),
+            ..model::stubs::view()
+        });
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            pub static WEB: ::iftree::runtime::Web = ::iftree::runtime::Web {
+                index_files: &[],
+                fallback: None,
+                trailing_slash: ::iftree::runtime::TrailingSlash::Ignore,
+            };
+
+            pub fn resolve_web_path(path: &str) -> Option<::iftree::runtime::WebPath> {
+                WEB.resolve(path, resolve_path)
+            }
+
+            pub static FINGERPRINTED_PATHS: [(&str, &str); 2usize] = [
+                ("a.js", "a.1.js"),
+                ("b.css", "b.0.css"),
+            ];
+
+            pub fn get_fingerprinted_path(relative_path: &str) -> Option<&'static str> {
+                let index = FINGERPRINTED_PATHS
+                    .binary_search_by(|(other, _)| other.cmp(&relative_path))
+                    .ok()?;
+                Some(FINGERPRINTED_PATHS[index].1)
+            }
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_web_it_prints_nothing() {
+        let actual = main(&model::stubs::view());
+
+        let actual = actual.to_string();
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::model;
+
+pub fn main(
+    view: &model::View,
+    code: proc_macro2::TokenStream,
+    print_debug_code: impl FnOnce() -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if view.debug {
+        go(view, code, print_debug_code())
+    } else {
+        code
+    }
+}
+
+fn go(
+    view: &model::View,
+    code: proc_macro2::TokenStream,
+    debug_code: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let value = format!("{debug_code}{}", get_deduplication_report(&view.blobs));
+
+    quote::quote! {
+        #code
+
+        pub const DEBUG: &str = #value;
+    }
+}
+
+fn get_deduplication_report(blobs: &[model::Blob]) -> String {
+    if blobs.is_empty() {
+        String::new()
+    } else {
+        let files = blobs.iter().map(|blob| blob.references).sum::<usize>();
+        let blob_count = blobs.len();
+        let bytes_saved = blobs
+            .iter()
+            .map(|blob| (blob.references - 1) * blob.contents.len())
+            .sum::<usize>();
+
+        format!("\n\n// Deduplication saved {bytes_saved} bytes by sharing {blob_count} blob(s) among {files} files.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_no_debug() {
+        let actual = main(
+            &model::View {
+                debug: false,
+                ..model::stubs::view()
+            },
+            quote::quote! { mod abc {} },
+            || quote::quote! { mod abc {} },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! { mod abc {} }.to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_debug() {
+        let actual = main(
+            &model::View {
+                debug: true,
+                ..model::stubs::view()
+            },
+            quote::quote! { mod abc {} },
+            || quote::quote! { mod abc {} },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            mod abc {}
+
+            pub const DEBUG: &str = "mod abc { }";
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_debug_with_deduplication() {
+        let actual = main(
+            &model::View {
+                blobs: vec![
+                    model::Blob {
+                        contents: b"ab".to_vec(),
+                        references: 2,
+                        ..model::stubs::blob()
+                    },
+                    model::Blob {
+                        contents: b"cde".to_vec(),
+                        references: 3,
+                        ..model::stubs::blob()
+                    },
+                ],
+                debug: true,
+                ..model::stubs::view()
+            },
+            quote::quote! { mod abc {} },
+            || quote::quote! { mod abc {} },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            mod abc {}
+
+            pub const DEBUG: &str = "mod abc { }\n\n// Deduplication saved 8 bytes by sharing 2 blob(s) among 5 files.";
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_debug_with_separate_debug_code() {
+        let actual = main(
+            &model::View {
+                debug: true,
+                ..model::stubs::view()
+            },
+            quote::quote! { static A: [u8; 1usize] = *b"a"; },
+            || quote::quote! { static A: [u8; 1usize] = ..; },
+        );
+
+        let actual = actual.to_string();
+        let expected = quote::quote! {
+            static A: [u8; 1usize] = *b"a";
+
+            pub const DEBUG: &str = "static A : [u8 ; 1usize] = .. ;";
+        }
+        .to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,38 @@
+use iftree::runtime::DirEntry;
+use iftree::runtime::EmbeddedTree;
+
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+embedded_tree = true
+"
+)]
+pub struct Asset {
+    contents_str: &'static str,
+}
+
+fn main() {
+    assert_eq!(Assets.len(), 6);
+    assert_eq!(
+        Assets
+            .get("examples/assets/credits.md")
+            .unwrap()
+            .contents_str,
+        "Boo Far\n",
+    );
+    assert!(Assets.get("examples/assets/missing.md").is_none());
+
+    assert_eq!(
+        list_folder(&Assets, "examples/assets"),
+        vec![".env", "configuration/", "credits.md", "world/"],
+    );
+}
+
+fn list_folder(tree: &impl EmbeddedTree, folder: &str) -> Vec<String> {
+    tree.read_dir(folder)
+        .map(|entry| match entry {
+            DirEntry::File { .. } => entry.name().into(),
+            DirEntry::Folder { .. } => format!("{}/", entry.name()),
+        })
+        .collect()
+}
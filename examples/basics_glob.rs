@@ -0,0 +1,33 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+glob = true
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+fn main() {
+    assert_eq!(
+        relative_paths(glob("examples/assets/*/*.json").unwrap()),
+        vec![
+            "examples/assets/configuration/menu.json",
+            "examples/assets/world/physical_constants.json",
+        ],
+    );
+
+    assert_eq!(
+        relative_paths(glob("*.json\n!/examples/assets/configuration/").unwrap()),
+        vec![
+            "examples/assets/world/levels/tutorial.json",
+            "examples/assets/world/physical_constants.json",
+        ],
+    );
+
+    assert!(glob("examples/assets/[").is_err());
+}
+
+fn relative_paths(assets: impl Iterator<Item = &'static Asset>) -> Vec<&'static str> {
+    assets.map(|asset| asset.relative_path).collect()
+}
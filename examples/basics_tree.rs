@@ -0,0 +1,39 @@
+use iftree::runtime::Entry;
+
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+tree = true
+"
+)]
+pub struct Asset {
+    contents_str: &'static str,
+}
+
+fn main() {
+    let Some(Entry::File(credits)) = TREE.get("examples/assets/credits.md") else {
+        panic!("expected file");
+    };
+    assert_eq!(credits.asset.contents_str, "Boo Far\n");
+    assert_eq!(credits.parent().relative_path, "examples/assets");
+    assert!(TREE.get("examples/assets/seed.json").is_none());
+
+    let levels = TREE.read_dir("examples/assets/world/levels").unwrap();
+    assert_eq!(
+        levels.iter().map(Entry::name).collect::<Vec<_>>(),
+        vec!["tutorial.json"],
+    );
+
+    let Some(Entry::Folder(world)) = TREE.get("examples/assets/world") else {
+        panic!("expected folder");
+    };
+    assert_eq!(
+        world.walk().map(Entry::relative_path).collect::<Vec<_>>(),
+        vec![
+            "examples/assets/world/levels",
+            "examples/assets/world/levels/tutorial.json",
+            "examples/assets/world/physical_constants.json",
+        ],
+    );
+    assert_eq!(world.parent().unwrap().name, "assets");
+}
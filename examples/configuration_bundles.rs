@@ -0,0 +1,59 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/credits.md'
+
+[bundles.configuration]
+paths = '/examples/assets/configuration/**'
+cfg = 'debug_assertions'
+
+[bundles.world]
+paths = '/examples/assets/world/**'
+cfg = 'not(debug_assertions)'
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+fn main() {
+    let relative_paths = ASSETS
+        .iter()
+        .map(|asset| asset.relative_path)
+        .collect::<Vec<_>>();
+
+    if cfg!(debug_assertions) {
+        assert_eq!(
+            relative_paths,
+            vec![
+                "examples/assets/configuration/menu.json",
+                "examples/assets/configuration/translations.csv",
+                "examples/assets/credits.md",
+            ],
+        );
+    } else {
+        assert_eq!(
+            relative_paths,
+            vec![
+                "examples/assets/credits.md",
+                "examples/assets/world/levels/tutorial.json",
+                "examples/assets/world/physical_constants.json",
+            ],
+        );
+    }
+
+    // All bundles share the same asset type and `base` hierarchy.
+    assert_eq!(
+        base::examples::assets::CREDITS_MD.relative_path,
+        "examples/assets/credits.md",
+    );
+    #[cfg(debug_assertions)]
+    assert_eq!(
+        base::examples::assets::configuration::MENU_JSON.relative_path,
+        "examples/assets/configuration/menu.json",
+    );
+    #[cfg(not(debug_assertions))]
+    assert_eq!(
+        base::examples::assets::world::PHYSICAL_CONSTANTS_JSON.relative_path,
+        "examples/assets/world/physical_constants.json",
+    );
+}
@@ -0,0 +1,63 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+folder_functions = true
+
+[[cfg]]
+paths = '*.json'
+predicate = 'debug_assertions'
+
+[[cfg]]
+paths = '/examples/assets/world/**'
+predicate = 'not(debug_assertions)'
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+fn main() {
+    let relative_paths = ASSETS
+        .iter()
+        .map(|asset| asset.relative_path)
+        .collect::<Vec<_>>();
+
+    // JSON files in the world folder need both predicates to hold, which never
+    // happens.
+    if cfg!(debug_assertions) {
+        assert_eq!(
+            relative_paths,
+            vec![
+                "examples/assets/.env",
+                "examples/assets/configuration/menu.json",
+                "examples/assets/configuration/translations.csv",
+                "examples/assets/credits.md",
+            ],
+        );
+    } else {
+        assert_eq!(
+            relative_paths,
+            vec![
+                "examples/assets/.env",
+                "examples/assets/configuration/translations.csv",
+                "examples/assets/credits.md",
+            ],
+        );
+    }
+
+    assert_eq!(
+        base::examples::assets::CREDITS_MD.relative_path,
+        "examples/assets/credits.md",
+    );
+    // Identifiers of conditional files are conditional, too.
+    #[cfg(debug_assertions)]
+    assert_eq!(
+        base::examples::assets::configuration::MENU_JSON.relative_path,
+        "examples/assets/configuration/menu.json",
+    );
+
+    assert_eq!(
+        base::examples::assets::configuration::all().len(),
+        if cfg!(debug_assertions) { 2 } else { 1 },
+    );
+}
@@ -0,0 +1,31 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/localized_assets/**'
+deduplicate = true
+debug = true
+"
+)]
+pub struct Asset {
+    contents_bytes: &'static [u8],
+    contents_str: &'static str,
+}
+
+fn main() {
+    use base::examples::localized_assets::{de, en, fr};
+
+    assert!(std::ptr::eq(
+        de::LOGO_SVG.contents_bytes,
+        en::LOGO_SVG.contents_bytes,
+    ));
+    assert!(std::ptr::eq(
+        en::LOGO_SVG.contents_bytes,
+        fr::LOGO_SVG.contents_bytes,
+    ));
+    assert_eq!(
+        fr::LOGO_SVG.contents_str,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\"/>\n"
+    );
+    assert_eq!(de::GREETING_TXT.contents_str, "Hallo\n");
+
+    assert!(DEBUG.ends_with("// Deduplication saved 84 bytes by sharing 1 blob(s) among 3 files."));
+}
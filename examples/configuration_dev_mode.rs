@@ -0,0 +1,27 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+dev_mode = 'filesystem'
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+fn main() {
+    assert_eq!(
+        get_asset("examples/assets/credits.md").as_deref(),
+        Some(&b"Boo Far\n"[..]),
+    );
+    assert_eq!(get_asset("examples/assets/missing.md"), None);
+    assert_eq!(get_asset("examples/assets/../../Cargo.toml"), None);
+
+    let relative_paths = iter_assets()
+        .map(|(relative_path, _)| relative_path.into_owned())
+        .collect::<Vec<_>>();
+    let embedded_paths = ASSETS
+        .iter()
+        .map(|asset| asset.relative_path.to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(relative_paths, embedded_paths);
+}
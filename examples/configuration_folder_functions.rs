@@ -0,0 +1,33 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+folder_functions = true
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+fn main() {
+    use base::examples::assets;
+
+    assert_eq!(base::path(), "");
+    assert_eq!(base::all().len(), 6);
+
+    assert_eq!(assets::world::path(), "examples/assets/world");
+    assert_eq!(
+        assets::world::all()
+            .iter()
+            .map(|asset| asset.relative_path)
+            .collect::<Vec<_>>(),
+        vec![
+            "examples/assets/world/levels/tutorial.json",
+            "examples/assets/world/physical_constants.json",
+        ],
+    );
+
+    assert_eq!(
+        assets::world::levels::all()[0].relative_path,
+        assets::world::levels::TUTORIAL_JSON.relative_path,
+    );
+}
@@ -0,0 +1,58 @@
+use iftree::runtime::Entry;
+use iftree::runtime::FolderInfo;
+
+#[iftree::include_file_tree(
+    "
+paths = '''
+/examples/assets/**
+!*.json
+'''
+include_folders = true
+folder_functions = true
+tree = true
+"
+)]
+pub struct Asset;
+
+fn main() {
+    // Folders whose files are all excluded are still included.
+    assert_eq!(
+        FOLDERS,
+        [
+            FolderInfo {
+                relative_path: "examples",
+                folder_count: 1,
+                file_count: 0,
+            },
+            FolderInfo {
+                relative_path: "examples/assets",
+                folder_count: 2,
+                file_count: 2,
+            },
+            FolderInfo {
+                relative_path: "examples/assets/configuration",
+                folder_count: 0,
+                file_count: 1,
+            },
+            FolderInfo {
+                relative_path: "examples/assets/world",
+                folder_count: 1,
+                file_count: 0,
+            },
+            FolderInfo {
+                relative_path: "examples/assets/world/levels",
+                folder_count: 0,
+                file_count: 0,
+            },
+        ],
+    );
+
+    let levels = TREE.read_dir("examples/assets/world/levels").unwrap();
+    assert!(levels.is_empty());
+    assert!(matches!(
+        TREE.get("examples/assets/world"),
+        Some(Entry::Folder(_)),
+    ));
+
+    assert!(base::examples::assets::world::levels::all().is_empty());
+}
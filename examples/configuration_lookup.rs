@@ -0,0 +1,25 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/configuration/*'
+lookup.case_insensitive = true
+lookup.normalize = true
+assets_by_path = true
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+fn main() {
+    assert_eq!(
+        resolve_path("/Examples//Assets/./Configuration/MENU.json"),
+        Some("examples/assets/configuration/menu.json"),
+    );
+    assert_eq!(
+        get_by_path("EXAMPLES/assets/configuration/Translations.CSV")
+            .unwrap()
+            .relative_path,
+        "examples/assets/configuration/translations.csv",
+    );
+    assert!(get_by_path("examples/assets/configuration/../configuration/menu.json").is_none());
+}
@@ -0,0 +1,22 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+
+[obfuscate]
+paths = '*.json'
+# In practice, use a secret variable set in your build environment.
+key_env = 'CARGO_PKG_NAME'
+"
+)]
+pub struct Asset {
+    get_str: fn() -> std::borrow::Cow<'static, str>,
+}
+
+fn main() {
+    use base::examples::assets;
+
+    // Obfuscated files are decrypted at runtime.
+    assert_eq!((assets::configuration::MENU_JSON.get_str)(), "\"Start\"\n");
+
+    assert_eq!((assets::CREDITS_MD.get_str)(), "Boo Far\n");
+}
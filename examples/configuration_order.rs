@@ -0,0 +1,42 @@
+#[iftree::include_file_tree(
+    "
+paths = '''
+/examples/assets/world/**
+/examples/assets/credits.md
+/examples/assets/configuration/menu.json
+'''
+order = 'pattern'
+assets_by_path = true
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+fn main() {
+    assert_eq!(
+        ASSETS.each_ref().map(|asset| asset.relative_path),
+        [
+            "examples/assets/world/levels/tutorial.json",
+            "examples/assets/world/physical_constants.json",
+            "examples/assets/credits.md",
+            "examples/assets/configuration/menu.json",
+        ],
+    );
+
+    assert_eq!(
+        ASSETS_BY_PATH.map(|asset| asset.relative_path),
+        [
+            "examples/assets/configuration/menu.json",
+            "examples/assets/credits.md",
+            "examples/assets/world/levels/tutorial.json",
+            "examples/assets/world/physical_constants.json",
+        ],
+    );
+    assert_eq!(
+        get_by_path("examples/assets/credits.md")
+            .unwrap()
+            .relative_path,
+        "examples/assets/credits.md",
+    );
+}
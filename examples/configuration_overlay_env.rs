@@ -0,0 +1,39 @@
+use std::env;
+use std::fs;
+
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/credits.md'
+overlay_env = 'MY_ASSET_OVERRIDE_DIR'
+"
+)]
+pub struct Asset {
+    contents_str: &'static str,
+}
+
+fn main() {
+    assert_eq!(
+        get_asset("examples/assets/credits.md").as_deref(),
+        Some(&b"Boo Far\n"[..]),
+    );
+
+    let overlay = tempfile::tempdir().unwrap();
+    let path = overlay.path().join("examples/assets/credits.md");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, "Custom credits\n").unwrap();
+    let path = overlay.path().join("examples/assets/license.md");
+    fs::write(path, "Custom license\n").unwrap();
+    env::set_var("MY_ASSET_OVERRIDE_DIR", overlay.path());
+
+    assert_eq!(
+        get_asset("examples/assets/credits.md").as_deref(),
+        Some(&b"Custom credits\n"[..]),
+    );
+    assert_eq!(
+        get_asset("examples/assets/license.md").as_deref(),
+        Some(&b"Custom license\n"[..]),
+    );
+    assert_eq!(get_asset("examples/assets/../../Cargo.toml"), None);
+    assert_eq!(asset_relative_paths(), vec!["examples/assets/credits.md"]);
+    assert_eq!(base::examples::assets::CREDITS_MD.contents_str, "Boo Far\n");
+}
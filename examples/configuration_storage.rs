@@ -0,0 +1,21 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+storage = 'packed'
+alignment = 4
+"
+)]
+pub struct Asset {
+    contents_bytes: &'static [u8],
+    contents_str: &'static str,
+}
+
+fn main() {
+    use base::examples::assets;
+
+    assert_eq!(assets::CREDITS_MD.contents_str, "Boo Far\n");
+
+    for asset in &ASSETS {
+        assert_eq!(asset.contents_bytes.as_ptr().align_offset(4), 0);
+    }
+}
@@ -0,0 +1,35 @@
+macro_rules! my_initialize {
+    ($name:literal, $id:ident, $index:literal, $size:literal) => {
+        Asset {
+            name: $name,
+            id: stringify!($id),
+            index: $index,
+            size: $size,
+        }
+    };
+}
+
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+template.initializer = 'my_initialize'
+template.initializer_arguments = ['name', 'id', 'index', 'size']
+"
+)]
+pub struct Asset {
+    name: &'static str,
+    id: &'static str,
+    index: usize,
+    size: usize,
+}
+
+fn main() {
+    use base::examples::assets;
+
+    assert_eq!(assets::CREDITS_MD.name, "credits.md");
+    assert_eq!(assets::CREDITS_MD.id, "r#CREDITS_MD");
+    assert_eq!(assets::CREDITS_MD.index, 3);
+    assert_eq!(assets::CREDITS_MD.size, 8);
+
+    assert_eq!(ASSETS[3].name, "credits.md");
+}
@@ -0,0 +1,69 @@
+macro_rules! visit_base {
+    ($length:literal, $range:expr, $($contents:item)*) => {
+        pub const ALL_FILES: std::ops::Range<usize> = $range;
+
+        $($contents)*
+    };
+}
+
+macro_rules! visit_folder {
+    (
+        $name:literal,
+        $id:ident,
+        $depth:literal,
+        $relative_path:literal,
+        $length:literal,
+        $range:expr,
+        $($contents:item)*
+    ) => {
+        pub mod $id {
+            pub const DEPTH: usize = $depth;
+            pub const PATH: &str = $relative_path;
+            pub const LENGTH: usize = $length;
+            pub const FILES: std::ops::Range<usize> = $range;
+
+            $($contents)*
+        }
+    };
+}
+
+macro_rules! visit_file {
+    (
+        $name:literal,
+        $id:ident,
+        $index:literal,
+        $relative_path:literal,
+        $absolute_path:literal,
+        $depth:literal
+    ) => {
+        pub const $id: (usize, usize) = ($index, $depth);
+    };
+}
+
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+
+[[template]]
+visit_base = 'visit_base'
+visit_folder = 'visit_folder'
+visit_file = 'visit_file'
+extended_inputs = true
+"
+)]
+pub struct Asset;
+
+fn main() {
+    use examples::assets;
+
+    assert_eq!(ALL_FILES, 0..6);
+
+    assert_eq!(assets::DEPTH, 1);
+    assert_eq!(assets::PATH, "examples/assets");
+    assert_eq!(assets::LENGTH, 6);
+
+    assert_eq!(assets::world::levels::DEPTH, 3);
+    assert_eq!(assets::world::levels::PATH, "examples/assets/world/levels");
+    assert_eq!(assets::world::FILES, 4..6);
+    assert_eq!(assets::world::levels::TUTORIAL_JSON, (4, 4));
+}
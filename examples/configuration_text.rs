@@ -0,0 +1,21 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/credits.md'
+
+[text]
+paths = '*.md'
+normalize_line_endings = 'crlf'
+strip_bom = true
+"
+)]
+pub struct Asset {
+    contents_str: &'static str,
+    get_str: fn() -> std::borrow::Cow<'static, str>,
+}
+
+fn main() {
+    use base::examples::assets;
+
+    assert_eq!(assets::CREDITS_MD.contents_str, "Boo Far\r\n");
+    assert_eq!((assets::CREDITS_MD.get_str)(), "Boo Far\r\n");
+}
@@ -0,0 +1,33 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+
+[[validate]]
+paths = '*.md'
+validator = 'utf8'
+
+[[validate]]
+paths = '*.json'
+validator = 'json'
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+fn main() {
+    assert_eq!(
+        ASSETS
+            .iter()
+            .map(|asset| asset.relative_path)
+            .collect::<Vec<_>>(),
+        vec![
+            "examples/assets/.env",
+            "examples/assets/configuration/menu.json",
+            "examples/assets/configuration/translations.csv",
+            "examples/assets/credits.md",
+            "examples/assets/world/levels/tutorial.json",
+            "examples/assets/world/physical_constants.json",
+        ],
+    );
+}
@@ -0,0 +1,34 @@
+use iftree::runtime::WebPath;
+
+#[iftree::include_file_tree(
+    "
+paths = '**'
+base_folder = 'examples/assets'
+
+[web]
+index_files = ['credits.md', 'tutorial.json']
+fallback = 'credits.md'
+trailing_slash = 'redirect'
+"
+)]
+pub struct Asset;
+
+fn main() {
+    assert_eq!(resolve_web_path(""), Some(WebPath::File("credits.md")));
+    assert_eq!(
+        resolve_web_path("world/levels/"),
+        Some(WebPath::File("world/levels/tutorial.json")),
+    );
+    assert_eq!(
+        resolve_web_path("world/levels"),
+        Some(WebPath::Redirect("levels/".into())),
+    );
+    assert_eq!(
+        resolve_web_path("credits.md/"),
+        Some(WebPath::Redirect("../credits.md".into())),
+    );
+    assert_eq!(
+        resolve_web_path("some/client/route"),
+        Some(WebPath::File("credits.md")),
+    );
+}
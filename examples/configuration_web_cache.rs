@@ -0,0 +1,36 @@
+#[iftree::include_file_tree(
+    "
+paths = '**'
+base_folder = 'examples/assets/world'
+
+[web.cache]
+fingerprint = ['*.json']
+
+[[web.cache.rules]]
+paths = '/levels/*'
+cache_control = 'public, max-age=3600'
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+    etag: &'static str,
+    cache_control: &'static str,
+    fingerprinted_path: &'static str,
+}
+
+fn main() {
+    let asset = &base::levels::TUTORIAL_JSON;
+    assert_eq!(asset.relative_path, "levels/tutorial.json");
+    assert_eq!(asset.etag, r#""ef72bf15683ace1882477c8628d20e27""#);
+    assert_eq!(asset.cache_control, "public, max-age=3600");
+    assert_eq!(asset.fingerprinted_path, "levels/tutorial.ef72bf15.json");
+
+    let asset = &base::PHYSICAL_CONSTANTS_JSON;
+    assert_eq!(asset.cache_control, "public, max-age=31536000, immutable");
+
+    // For templates that refer to assets by their logical paths.
+    assert_eq!(
+        get_fingerprinted_path("physical_constants.json"),
+        Some("physical_constants.de90a823.json"),
+    );
+}
@@ -0,0 +1,17 @@
+#[iftree::include_file_tree(
+    "
+paths = '**'
+base_folder = 'examples/assets'
+serve = true
+"
+)]
+pub struct Asset;
+
+#[tokio::main]
+async fn main() {
+    let app = axum::Router::new().nest_service("/assets", service());
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:8080")
+        .await
+        .unwrap();
+    axum::serve(listener, app).await.unwrap();
+}
@@ -0,0 +1,29 @@
+#[iftree::include_file_tree("paths = '/examples/assets/**/*.json'")]
+pub struct Asset {
+    relative_path: &'static str,
+    contents_bytes: &'static [u8],
+    get_decompressed: fn() -> &'static [u8],
+    get_decompressed_str: fn() -> &'static str,
+}
+
+fn main() {
+    for asset in &ASSETS {
+        assert_eq!((asset.get_decompressed)(), asset.contents_bytes);
+        assert_eq!(
+            (asset.get_decompressed_str)().as_bytes(),
+            asset.contents_bytes
+        );
+    }
+
+    let asset = &base::examples::assets::configuration::MENU_JSON;
+    assert_eq!(
+        asset.relative_path,
+        "examples/assets/configuration/menu.json"
+    );
+
+    // Contents are decompressed once, later calls return the same contents.
+    assert!(std::ptr::eq(
+        (asset.get_decompressed)(),
+        (asset.get_decompressed)(),
+    ));
+}
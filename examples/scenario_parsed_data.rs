@@ -0,0 +1,31 @@
+#[iftree::include_file_tree("paths = '/examples/assets/**/*.json'")]
+pub struct Asset {
+    parsed_json: &'static AssetValue,
+}
+
+mod translations {
+    #[iftree::include_file_tree("paths = '/examples/assets/**/*.csv'")]
+    pub struct Asset {
+        pub parsed_csv: &'static AssetValue,
+    }
+}
+
+fn main() {
+    use base::examples::assets;
+
+    assert_eq!(
+        *assets::configuration::MENU_JSON.parsed_json,
+        AssetValue::String("Start"),
+    );
+    assert_eq!(
+        *assets::world::PHYSICAL_CONSTANTS_JSON.parsed_json,
+        AssetValue::Float(7e-3),
+    );
+
+    assert_eq!(
+        *translations::base::examples::assets::configuration::TRANSLATIONS_CSV.parsed_csv,
+        translations::AssetValue::Array(&[translations::AssetValue::Array(&[
+            translations::AssetValue::String("Hi {{name}}")
+        ])]),
+    );
+}
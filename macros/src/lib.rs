@@ -0,0 +1,12 @@
+//! Procedural macro of [Iftree](https://docs.rs/iftree).
+//!
+//! Depend on the crate `iftree` instead of using this crate directly.
+
+/// See the [crate documentation](https://docs.rs/iftree).
+#[proc_macro_attribute]
+pub fn include_file_tree(
+    parameters: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    iftree_codegen::include_file_tree(parameters.into(), item.into()).into()
+}
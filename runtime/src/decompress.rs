@@ -0,0 +1,18 @@
+/// Decompresses contents that were compressed with DEFLATE at compile time.
+pub fn decompress(contents: &[u8]) -> Vec<u8> {
+    miniz_oxide::inflate::decompress_to_vec(contents)
+        .expect("Contents are compressed at compile time")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let actual = decompress(&miniz_oxide::deflate::compress_to_vec(b"abc", 10));
+
+        let expected = b"abc";
+        assert_eq!(actual, expected);
+    }
+}
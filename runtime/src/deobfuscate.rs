@@ -0,0 +1,29 @@
+use chacha20::cipher::KeyIvInit;
+use chacha20::cipher::StreamCipher;
+use sha2::Digest;
+
+/// Decrypts contents that were encrypted with ChaCha20 at compile time, using a
+/// key derived from the SHA-256 hash of the given key string.
+pub fn deobfuscate(key: &str, nonce: &[u8; 12], contents: &[u8]) -> Vec<u8> {
+    let key: [u8; 32] = sha2::Sha256::digest(key).into();
+    let mut contents = contents.to_vec();
+    chacha20::ChaCha20::new(&key.into(), nonce.into()).apply_keystream(&mut contents);
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles() {
+        let nonce = [1; 12];
+        let encrypted = deobfuscate("key", &nonce, b"abc");
+
+        let actual = deobfuscate("key", &nonce, &encrypted);
+
+        assert_ne!(encrypted, b"abc");
+        let expected = b"abc";
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,312 @@
+#[cfg(feature = "glob")]
+use super::glob::Glob;
+#[cfg(feature = "glob")]
+use super::glob::PatternError;
+use std::cmp;
+use std::iter;
+use std::ops;
+use std::slice;
+
+/// A tree of files embedded by the macro `include_file_tree`.
+///
+/// The macro implements this trait for a generated unit struct `Assets`, so
+/// generic code can accept any embedded tree.
+///
+/// Relative paths use `/` as separator, like `"my_assets/style.css"`. Assets are
+/// sorted by their relative paths as strings, independent of the configured
+/// order of the array `ASSETS`.
+pub trait EmbeddedTree {
+    /// The asset type annotated with the macro.
+    type Asset: 'static;
+
+    /// All assets sorted by relative path, like the array `ASSETS_BY_PATH`.
+    fn assets(&self) -> &'static [&'static Self::Asset];
+
+    /// The relative path of each asset, in the same order as
+    /// [`assets`](Self::assets).
+    fn relative_paths(&self) -> &'static [&'static str];
+
+    /// Returns the number of assets.
+    fn len(&self) -> usize {
+        self.relative_paths().len()
+    }
+
+    /// Returns `true` if there are no assets.
+    fn is_empty(&self) -> bool {
+        self.relative_paths().is_empty()
+    }
+
+    /// Iterates over pairs of relative path and asset, sorted by relative path.
+    fn iter(&self) -> Iter<Self::Asset> {
+        self.relative_paths()
+            .iter()
+            .copied()
+            .zip(self.assets().iter().copied())
+    }
+
+    /// Looks up an asset by its relative path.
+    fn get(&self, relative_path: &str) -> Option<&'static Self::Asset> {
+        let index = self.relative_paths().binary_search(&relative_path).ok()?;
+        self.assets().get(index).copied()
+    }
+
+    /// Iterates over the immediate entries of a folder given by its relative
+    /// path, where `""` is the base folder.
+    ///
+    /// The iterator is empty if there is no such folder.
+    fn read_dir(&self, folder: &str) -> ReadDir<Self::Asset> {
+        let relative_paths = self.relative_paths();
+        let range = get_folder_range(relative_paths, folder);
+
+        ReadDir {
+            prefix_length: if folder.is_empty() {
+                0
+            } else {
+                folder.len() + 1
+            },
+            relative_paths: relative_paths[range.clone()].iter(),
+            assets: self.assets()[range].iter(),
+            last_folder: None,
+        }
+    }
+
+    /// Iterates over the assets selected by path patterns, sorted by relative
+    /// path.
+    ///
+    /// The patterns work like the
+    /// [`paths` configuration](https://docs.rs/iftree), with one pattern per
+    /// line. For example, `locales/*/messages.ftl` selects files `messages.ftl`
+    /// in any immediate subfolder of `locales`.
+    ///
+    /// Patterns that start with literal folders only search files in these
+    /// folders using binary search.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pattern is invalid.
+    #[cfg(feature = "glob")]
+    fn glob(&self, patterns: &str) -> Result<Glob<Self::Asset>, PatternError> {
+        Glob::new(self.relative_paths(), self.assets(), patterns)
+    }
+}
+
+/// Iterator returned by [`EmbeddedTree::iter`].
+pub type Iter<A> = iter::Zip<
+    iter::Copied<slice::Iter<'static, &'static str>>,
+    iter::Copied<slice::Iter<'static, &'static A>>,
+>;
+
+/// Iterator returned by [`EmbeddedTree::read_dir`].
+pub struct ReadDir<A: 'static> {
+    prefix_length: usize,
+    relative_paths: slice::Iter<'static, &'static str>,
+    assets: slice::Iter<'static, &'static A>,
+    last_folder: Option<&'static str>,
+}
+
+impl<A> Iterator for ReadDir<A> {
+    type Item = DirEntry<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let relative_path = *self.relative_paths.next()?;
+            let asset = *self.assets.next()?;
+
+            match relative_path[self.prefix_length..].find('/') {
+                None => {
+                    return Some(DirEntry::File {
+                        relative_path,
+                        asset,
+                    })
+                }
+
+                Some(index) => {
+                    let folder = &relative_path[..self.prefix_length + index];
+                    if self.last_folder != Some(folder) {
+                        self.last_folder = Some(folder);
+                        return Some(DirEntry::Folder {
+                            relative_path: folder,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An immediate entry of a folder.
+#[derive(Debug, cmp::PartialEq, Eq)]
+pub enum DirEntry<A: 'static> {
+    File {
+        relative_path: &'static str,
+        asset: &'static A,
+    },
+    Folder {
+        relative_path: &'static str,
+    },
+}
+
+impl<A> DirEntry<A> {
+    /// The relative path of this entry like `"my_assets/style.css"`.
+    pub fn relative_path(&self) -> &'static str {
+        match self {
+            DirEntry::File { relative_path, .. } | DirEntry::Folder { relative_path } => {
+                relative_path
+            }
+        }
+    }
+
+    /// The last component of the relative path like `"style.css"`.
+    pub fn name(&self) -> &'static str {
+        let relative_path = self.relative_path();
+        match relative_path.rfind('/') {
+            None => relative_path,
+            Some(index) => &relative_path[index + 1..],
+        }
+    }
+}
+
+/// Gets the range of paths in a folder, where `""` is the base folder.
+///
+/// As paths are sorted as strings, the paths in a folder are contiguous.
+pub(crate) fn get_folder_range(relative_paths: &[&str], folder: &str) -> ops::Range<usize> {
+    let start = if folder.is_empty() {
+        0
+    } else {
+        relative_paths.partition_point(|relative_path| {
+            let prefix = folder.bytes().chain(iter::once(b'/'));
+            relative_path.bytes().lt(prefix)
+        })
+    };
+    let end = start
+        + relative_paths[start..]
+            .partition_point(|relative_path| is_in_folder(relative_path, folder));
+    start..end
+}
+
+fn is_in_folder(relative_path: &str, folder: &str) -> bool {
+    folder.is_empty()
+        || relative_path
+            .strip_prefix(folder)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Tree;
+
+    impl EmbeddedTree for Tree {
+        type Asset = usize;
+
+        fn assets(&self) -> &'static [&'static usize] {
+            &[&0, &1, &2, &3, &4, &5, &6]
+        }
+
+        fn relative_paths(&self) -> &'static [&'static str] {
+            &["a.f", "a/b", "a/c.g", "a/c/d", "a/c/e", "h/i", "j"]
+        }
+    }
+
+    #[test]
+    fn handles_len() {
+        let actual = (Tree.len(), Tree.is_empty());
+
+        let expected = (7, false);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_iter() {
+        let actual = Tree.iter().skip(5).collect::<Vec<_>>();
+
+        let expected = vec![("h/i", &5), ("j", &6)];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_get() {
+        let actual = ["a/b", "a/c/e", "a.f", "j", "a", "a/c", "k", ""].map(|path| Tree.get(path));
+
+        let expected = [
+            Some(&1),
+            Some(&4),
+            Some(&0),
+            Some(&6),
+            None,
+            None,
+            None,
+            None,
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_read_dir_of_base_folder() {
+        let actual = Tree.read_dir("").collect::<Vec<_>>();
+
+        let expected = vec![
+            DirEntry::File {
+                relative_path: "a.f",
+                asset: &0,
+            },
+            DirEntry::Folder { relative_path: "a" },
+            DirEntry::Folder { relative_path: "h" },
+            DirEntry::File {
+                relative_path: "j",
+                asset: &6,
+            },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_read_dir_of_subfolder() {
+        let actual = Tree.read_dir("a").collect::<Vec<_>>();
+
+        let expected = vec![
+            DirEntry::File {
+                relative_path: "a/b",
+                asset: &1,
+            },
+            DirEntry::File {
+                relative_path: "a/c.g",
+                asset: &2,
+            },
+            DirEntry::Folder {
+                relative_path: "a/c",
+            },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_such_folder_it_reads_nothing() {
+        let actual = ["a/b", "a/x", "x", "a.f"].map(|folder| Tree.read_dir(folder).count());
+
+        let expected = [0, 0, 0, 0];
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "glob")]
+    #[test]
+    fn handles_glob() {
+        let actual = ["a/c/*", "/{a,h}/*", "/a/{b,c}/*"]
+            .map(|patterns| Tree.glob(patterns).unwrap().collect::<Vec<_>>());
+
+        let expected = [vec![&3, &4], vec![&1, &2, &5], vec![&3, &4]];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_entry_name() {
+        let actual = Tree
+            .read_dir("a")
+            .map(|entry| entry.name())
+            .collect::<Vec<_>>();
+
+        let expected = vec!["b", "c.g", "c"];
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,299 @@
+use super::filter;
+use super::normalize;
+use super::relative_path;
+use ignore::overrides;
+use std::fs;
+use std::path;
+
+/// Files in a base folder that are selected by path patterns at runtime.
+///
+/// The patterns are interpreted like the `paths` configuration, so this selects
+/// the same files as the macro would if expanded now.
+pub struct Filesystem {
+    base_folder: path::PathBuf,
+    paths: String,
+    filters: Vec<overrides::Override>,
+    order: Order,
+    text: Option<(overrides::Override, normalize::Normalization)>,
+}
+
+/// The order of relative paths, as configured with `order`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Order {
+    /// Like a depth-first walk over the folder tree, with the entries of each
+    /// folder ordered by name.
+    Tree,
+    /// By relative paths as strings.
+    Path,
+    /// By the first pattern that matches a file on its own, then in tree order.
+    Pattern,
+}
+
+impl Filesystem {
+    /// Selects files in an absolute `base_folder` by `paths` patterns, one per
+    /// line. Relative paths are listed in tree order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pattern is invalid.
+    pub fn new(base_folder: &str, paths: &str) -> Self {
+        let base_folder = path::PathBuf::from(base_folder);
+        let filter = filter::get(&base_folder, paths).unwrap();
+        Self {
+            base_folder,
+            paths: paths.into(),
+            filters: vec![filter],
+            order: Order::Tree,
+            text: None,
+        }
+    }
+
+    /// Lists relative paths in the given order instead.
+    pub fn with_order(self, order: Order) -> Self {
+        Self { order, ..self }
+    }
+
+    /// Additionally selects files by the `paths` patterns of a bundle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pattern is invalid.
+    pub fn with_paths(mut self, paths: &str) -> Self {
+        self.filters
+            .push(filter::get(&self.base_folder, paths).unwrap());
+        self
+    }
+
+    /// Normalizes the contents of files that match the `paths` patterns of the
+    /// `text` configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pattern is invalid.
+    pub fn with_text(self, paths: &str, normalization: normalize::Normalization) -> Self {
+        let filter = filter::get(&self.base_folder, paths).unwrap();
+        Self {
+            text: Some((filter, normalization)),
+            ..self
+        }
+    }
+
+    /// Reads the contents of a selected file given by its relative path.
+    ///
+    /// Returns `None` if the file is not selected, cannot be read, or the path
+    /// would leave the base folder.
+    pub fn get(&self, relative_path: &str) -> Option<Vec<u8>> {
+        let components = relative_path::get_components(relative_path)?;
+
+        if self
+            .filters
+            .iter()
+            .any(|filter| filter::is_selected(filter, &self.base_folder, &components))
+        {
+            let path = components
+                .iter()
+                .fold(self.base_folder.clone(), |path, component| {
+                    path.join(component)
+                });
+            if path.is_dir() {
+                None
+            } else {
+                let contents = fs::read(path).ok()?;
+                Some(match &self.text {
+                    Some((filter, normalization))
+                        if filter::is_selected(filter, &self.base_folder, &components) =>
+                    {
+                        normalize::normalize(&contents, *normalization)
+                    }
+                    _ => contents,
+                })
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Lists the relative paths of all selected files in the configured order,
+    /// which is the same as the one of the `ASSETS` array.
+    pub fn relative_paths(&self) -> Vec<String> {
+        let mut relative_paths = self
+            .filters
+            .iter()
+            .flat_map(|filter| self.walk(filter))
+            .collect::<Vec<_>>();
+
+        relative_paths.sort_by(|left, right| left.split('/').cmp(right.split('/')));
+        relative_paths.dedup();
+
+        match self.order {
+            Order::Tree => {}
+            Order::Path => relative_paths.sort(),
+            Order::Pattern => {
+                let filters = self
+                    .paths
+                    .lines()
+                    .filter(|pattern| !(pattern.is_empty() || pattern.starts_with(['!', '#'])))
+                    .map(|pattern| filter::get(&self.base_folder, pattern).unwrap())
+                    .collect::<Vec<_>>();
+
+                // Files not matched by any single pattern on their own come last.
+                relative_paths.sort_by_cached_key(|relative_path| {
+                    let path = self.base_folder.join(relative_path);
+                    filters
+                        .iter()
+                        .position(|filter| filter.matched(&path, false).is_whitelist())
+                        .unwrap_or(filters.len())
+                });
+            }
+        }
+
+        relative_paths
+    }
+
+    fn walk(&self, filter: &overrides::Override) -> Vec<String> {
+        ignore::WalkBuilder::new(&self.base_folder)
+            .standard_filters(false)
+            .overrides(filter.clone())
+            .build()
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                if entry.file_type()?.is_dir() {
+                    None
+                } else {
+                    let relative_path = entry.path().strip_prefix(&self.base_folder).ok()?;
+                    let components = relative_path
+                        .components()
+                        .map(|component| component.as_os_str().to_str())
+                        .collect::<Option<Vec<_>>>()?;
+                    Some(components.join("/"))
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_relative_paths() {
+        let base_folder = arrange();
+
+        let actual = get_filesystem(&base_folder, "/a/**\n!/a/d/\n/e").relative_paths();
+
+        let expected = vec!["a/b", "a/c/b", "e"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_order() {
+        let base_folder = arrange();
+
+        let actual = [Order::Tree, Order::Path, Order::Pattern].map(|order| {
+            get_filesystem(&base_folder, "/f\n/a*/**\n/a.b")
+                .with_order(order)
+                .relative_paths()
+        });
+
+        let expected = [
+            vec!["a/b", "a/c/b", "a/d/b", "a.b", "f"],
+            vec!["a.b", "a/b", "a/c/b", "a/d/b", "f"],
+            vec!["f", "a/b", "a/c/b", "a/d/b", "a.b"],
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_bundle_paths() {
+        let base_folder = arrange();
+        let filesystem = get_filesystem(&base_folder, "/a/**\n!/a/d/")
+            .with_paths("/f")
+            .with_paths("/a/**");
+
+        let actual = filesystem.relative_paths();
+
+        let expected = vec!["a/b", "a/c/b", "a/d/b", "f"];
+        assert_eq!(actual, expected);
+        let actual = ["a/d/b", "f", "e"].map(|relative_path| filesystem.get(relative_path));
+        let expected = [Some(b"a/d/b".to_vec()), Some(b"f".to_vec()), None];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_get() {
+        let base_folder = arrange();
+        let filesystem = get_filesystem(&base_folder, "/a/**\n!/a/d/\n/e");
+
+        let actual = ["a/b", "a/c/b", "e", "a/d/b", "f", "a/c", "a"]
+            .map(|relative_path| filesystem.get(relative_path));
+
+        let expected = [
+            Some(b"a/b".to_vec()),
+            Some(b"a/c/b".to_vec()),
+            Some(b"e".to_vec()),
+            None,
+            None,
+            None,
+            None,
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_text() {
+        let base_folder = arrange();
+        fs::write(base_folder.path().join("a/b"), b"\xEF\xBB\xBFx\r\ny\n").unwrap();
+        fs::write(base_folder.path().join("e"), b"\xEF\xBB\xBFx\r\ny\n").unwrap();
+        let filesystem = get_filesystem(&base_folder, "/a/**\n/e").with_text(
+            "/a/*",
+            normalize::Normalization {
+                line_ending: Some(normalize::LineEnding::Crlf),
+                strip_bom: true,
+            },
+        );
+
+        let actual = ["a/b", "e"].map(|relative_path| filesystem.get(relative_path));
+
+        let expected = [
+            Some(b"x\r\ny\r\n".to_vec()),
+            Some(b"\xEF\xBB\xBFx\r\ny\n".to_vec()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_path_traversal_it_gets_nothing() {
+        let base_folder = arrange();
+        let filesystem = get_filesystem(base_folder.path().join("a"), "**");
+
+        let actual = ["../e", "c/../b", "./b", "/b", "b/", "", "c//b"]
+            .map(|relative_path| filesystem.get(relative_path));
+
+        let expected = [None, None, None, None, None, None, None];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_patterns_it_selects_nothing() {
+        let base_folder = arrange();
+
+        let actual = get_filesystem(&base_folder, "").relative_paths();
+
+        assert!(actual.is_empty());
+    }
+
+    fn arrange() -> tempfile::TempDir {
+        let base_folder = tempfile::tempdir().unwrap();
+        for relative_path in ["a/b", "a/c/b", "a/d/b", "a.b", "e", "f"] {
+            let path = base_folder.path().join(relative_path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, relative_path).unwrap();
+        }
+        base_folder
+    }
+
+    fn get_filesystem(base_folder: impl AsRef<path::Path>, paths: &str) -> Filesystem {
+        Filesystem::new(base_folder.as_ref().to_str().unwrap(), paths)
+    }
+}
@@ -0,0 +1,71 @@
+use ignore::overrides;
+use std::path;
+
+/// Builds a filter from path patterns like the `paths` configuration, one per
+/// line, relative to a root folder.
+pub fn get(root: &path::Path, paths: &str) -> Result<overrides::Override, ignore::Error> {
+    let mut builder = overrides::OverrideBuilder::new(root);
+    for pattern in paths.lines() {
+        builder.add(pattern)?;
+    }
+    let filter = builder.build()?;
+
+    if filter.is_empty() {
+        overrides::OverrideBuilder::new(root).add("!*")?.build()
+    } else {
+        Ok(filter)
+    }
+}
+
+/// Whether a file given by its path components is selected, which is the case if
+/// the file matches and none of its folders is excluded.
+pub fn is_selected(filter: &overrides::Override, root: &path::Path, components: &[&str]) -> bool {
+    match components.split_last() {
+        None => false,
+
+        Some((filename, folders)) => {
+            let mut path = root.to_path_buf();
+            for folder in folders {
+                path.push(folder);
+                if filter.matched(&path, true).is_ignore() {
+                    return false;
+                }
+            }
+            path.push(filename);
+
+            filter.matched(&path, false).is_whitelist()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_is_selected() {
+        let filter = get(path::Path::new(""), "/a/**\n!/a/d/\n*.e").unwrap();
+
+        let actual = [
+            vec!["a", "b"],
+            vec!["a", "c", "b"],
+            vec!["a", "d", "b"],
+            vec!["f", "g.e"],
+            vec!["f"],
+            vec![],
+        ]
+        .map(|components| is_selected(&filter, path::Path::new(""), &components));
+
+        let expected = [true, true, false, true, false, false];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_patterns_it_selects_nothing() {
+        let filter = get(path::Path::new(""), "").unwrap();
+
+        let actual = is_selected(&filter, path::Path::new(""), &["a"]);
+
+        assert!(!actual);
+    }
+}
@@ -0,0 +1,18 @@
+/// A selected folder in the array `FOLDERS`, as generated with
+/// `include_folders = true`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FolderInfo {
+    /// The relative path like `"x/my_assets"`.
+    pub relative_path: &'static str,
+    /// The number of immediate subfolders.
+    pub folder_count: usize,
+    /// The number of immediate files.
+    pub file_count: usize,
+}
+
+impl FolderInfo {
+    /// Whether the folder has no entries at all.
+    pub fn is_empty(&self) -> bool {
+        self.folder_count == 0 && self.file_count == 0
+    }
+}
@@ -0,0 +1,185 @@
+use super::embedded_tree;
+use super::filter;
+use ignore::overrides;
+use std::error;
+use std::fmt;
+use std::path;
+use std::slice;
+
+/// Iterator returned by [`EmbeddedTree::glob`](super::EmbeddedTree::glob).
+pub struct Glob<A: 'static> {
+    filter: overrides::Override,
+    relative_paths: slice::Iter<'static, &'static str>,
+    assets: slice::Iter<'static, &'static A>,
+}
+
+/// Error returned by [`EmbeddedTree::glob`](super::EmbeddedTree::glob) if a
+/// pattern is invalid.
+#[derive(Debug)]
+pub struct PatternError(ignore::Error);
+
+impl<A> Glob<A> {
+    /// Matches paths that are sorted as strings against patterns.
+    pub(super) fn new(
+        relative_paths: &'static [&'static str],
+        assets: &'static [&'static A],
+        patterns: &str,
+    ) -> Result<Self, PatternError> {
+        let filter = filter::get(path::Path::new(""), patterns).map_err(PatternError)?;
+        let range = embedded_tree::get_folder_range(relative_paths, &get_prefix(patterns));
+
+        Ok(Self {
+            filter,
+            relative_paths: relative_paths[range.clone()].iter(),
+            assets: assets[range].iter(),
+        })
+    }
+}
+
+impl<A> Iterator for Glob<A> {
+    type Item = &'static A;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let relative_path = *self.relative_paths.next()?;
+            let asset = *self.assets.next()?;
+
+            let components = relative_path.split('/').collect::<Vec<_>>();
+            if filter::is_selected(&self.filter, path::Path::new(""), &components) {
+                return Some(asset);
+            }
+        }
+    }
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "Invalid pattern: {}", self.0)
+    }
+}
+
+impl error::Error for PatternError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// Gets a folder that contains all files possibly selected by the patterns,
+/// based on the literal folders the patterns start with.
+fn get_prefix(patterns: &str) -> String {
+    patterns
+        .lines()
+        .filter(|pattern| !(pattern.is_empty() || pattern.starts_with(['!', '#'])))
+        .map(get_literal_folders)
+        .reduce(|left, right| {
+            left.into_iter()
+                .zip(right)
+                .take_while(|(left, right)| left == right)
+                .map(|(left, _)| left)
+                .collect()
+        })
+        .unwrap_or_default()
+        .join("/")
+}
+
+fn get_literal_folders(pattern: &str) -> Vec<&str> {
+    let pattern = pattern.strip_suffix('/').unwrap_or(pattern);
+
+    // Like in `.gitignore` files, a pattern without a slash at its beginning or
+    // middle matches at any level.
+    if !pattern.contains('/') {
+        return vec![];
+    }
+
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+    let mut components = pattern.split('/').collect::<Vec<_>>();
+    components.pop();
+    components
+        .into_iter()
+        .take_while(|component| {
+            !(component.is_empty() || component.contains(['*', '?', '[', '\\', '{', '}']))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_prefix() {
+        let actual = [
+            "locales/*/messages.ftl",
+            "/locales/en/**",
+            "locales/en/messages.ftl",
+            "/a\n/a/b/c\n!/d/e",
+            "/a/b/c\n/a/d/e",
+            "*.ftl",
+            "/a/b/c\n*.ftl",
+            "**/a/b",
+            "/a/b*/c",
+            "/{a,b}/c",
+            "/a/{b,c}/d",
+            "a/",
+            "/a/b/",
+            "",
+        ]
+        .map(get_prefix);
+
+        let expected = [
+            "locales",
+            "locales/en",
+            "locales/en",
+            "",
+            "a",
+            "",
+            "",
+            "",
+            "a",
+            "",
+            "a",
+            "",
+            "a",
+            "",
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_matches() {
+        static RELATIVE_PATHS: [&str; 6] = [
+            "a.ftl",
+            "locales/de/messages.ftl",
+            "locales/de/other.ftl",
+            "locales/en/messages.ftl",
+            "locales/x/y/messages.ftl",
+            "other/messages.ftl",
+        ];
+        static ASSETS: [&usize; 6] = [&0, &1, &2, &3, &4, &5];
+
+        let actual = [
+            "locales/*/messages.ftl",
+            "*.ftl\n!/locales/",
+            "locales/**/messages.ftl",
+            "",
+        ]
+        .map(|patterns| {
+            Glob::new(&RELATIVE_PATHS, &ASSETS, patterns)
+                .unwrap()
+                .collect::<Vec<_>>()
+        });
+
+        let expected = [vec![&1, &3], vec![&0, &5], vec![&1, &3, &4], vec![]];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_invalid_pattern_it_errs() {
+        static RELATIVE_PATHS: [&str; 1] = ["a"];
+        static ASSETS: [&usize; 1] = [&0];
+
+        let actual = Glob::new(&RELATIVE_PATHS, &ASSETS, "a\n[b");
+
+        assert!(actual.is_err());
+    }
+}
@@ -0,0 +1,63 @@
+//! Runtime support of [Iftree](https://docs.rs/iftree).
+//!
+//! This crate is reexported by the crate `iftree` as `iftree::runtime`, which is
+//! used by generated code.
+
+#[cfg(feature = "compression")]
+mod decompress;
+#[cfg(feature = "obfuscate")]
+mod deobfuscate;
+mod embedded_tree;
+#[cfg(feature = "dev-mode")]
+mod filesystem;
+#[cfg(any(feature = "dev-mode", feature = "glob"))]
+mod filter;
+mod folder_info;
+#[cfg(feature = "glob")]
+mod glob;
+mod normalize;
+mod overlay;
+mod relative_path;
+#[cfg(feature = "tower")]
+mod service;
+mod tree;
+mod web;
+
+#[cfg(feature = "compression")]
+pub use decompress::decompress;
+#[cfg(feature = "obfuscate")]
+pub use deobfuscate::deobfuscate;
+pub use embedded_tree::DirEntry;
+pub use embedded_tree::EmbeddedTree;
+pub use embedded_tree::Iter;
+pub use embedded_tree::ReadDir;
+#[cfg(feature = "dev-mode")]
+pub use filesystem::Filesystem;
+#[cfg(feature = "dev-mode")]
+pub use filesystem::Order;
+pub use folder_info::FolderInfo;
+#[cfg(feature = "glob")]
+pub use glob::Glob;
+#[cfg(feature = "glob")]
+pub use glob::PatternError;
+pub use normalize::normalize;
+pub use normalize::LineEnding;
+pub use normalize::Normalization;
+pub use overlay::Overlay;
+pub use relative_path::get_lookup_key;
+#[cfg(feature = "tower")]
+pub use service::Response;
+#[cfg(feature = "tower")]
+pub use service::ServeAssets;
+#[cfg(feature = "tower")]
+pub use service::ServedFile;
+#[cfg(feature = "tower")]
+pub use service::Variant;
+pub use tree::Entry;
+pub use tree::File;
+pub use tree::Folder;
+pub use tree::Walk;
+pub use web::TrailingSlash;
+pub use web::Web;
+pub use web::WebPath;
+pub use web::FINGERPRINT_LENGTH;
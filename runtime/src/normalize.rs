@@ -0,0 +1,89 @@
+/// How to normalize text files, as configured with `text`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Normalization {
+    /// The line ending to convert all line endings to, if any.
+    pub line_ending: Option<LineEnding>,
+    /// Whether to remove a UTF-8 byte order mark at the start of a file.
+    pub strip_bom: bool,
+}
+
+/// A line ending, as configured with `text.normalize_line_endings`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LineEnding {
+    /// `\r\n`
+    Crlf,
+    /// `\n`
+    Lf,
+}
+
+/// Normalizes text file contents the same way as at compile time.
+pub fn normalize(contents: &[u8], normalization: Normalization) -> Vec<u8> {
+    let contents = match contents.strip_prefix(BYTE_ORDER_MARK) {
+        Some(rest) if normalization.strip_bom => rest,
+        _ => contents,
+    };
+
+    match normalization.line_ending {
+        None => contents.to_vec(),
+
+        Some(line_ending) => {
+            let mut normalized = Vec::with_capacity(contents.len());
+            for (index, &byte) in contents.iter().enumerate() {
+                if byte == b'\r' && contents.get(index + 1) == Some(&b'\n') {
+                    continue;
+                }
+                if byte == b'\n' && line_ending == LineEnding::Crlf {
+                    normalized.push(b'\r');
+                }
+                normalized.push(byte);
+            }
+            normalized
+        }
+    }
+}
+
+const BYTE_ORDER_MARK: &[u8] = b"\xEF\xBB\xBF";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_line_endings() {
+        let actual = [None, Some(LineEnding::Lf), Some(LineEnding::Crlf)].map(|line_ending| {
+            normalize(
+                b"a\r\nb\nc\rd",
+                Normalization {
+                    line_ending,
+                    strip_bom: false,
+                },
+            )
+        });
+
+        let expected = [
+            b"a\r\nb\nc\rd".to_vec(),
+            b"a\nb\nc\rd".to_vec(),
+            b"a\r\nb\r\nc\rd".to_vec(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_byte_order_mark() {
+        let actual = [false, true].map(|strip_bom| {
+            normalize(
+                b"\xEF\xBB\xBFa\xEF\xBB\xBF",
+                Normalization {
+                    line_ending: None,
+                    strip_bom,
+                },
+            )
+        });
+
+        let expected = [
+            b"\xEF\xBB\xBFa\xEF\xBB\xBF".to_vec(),
+            b"a\xEF\xBB\xBF".to_vec(),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,109 @@
+use super::relative_path;
+use std::env;
+use std::fs;
+use std::path;
+
+/// A folder with files that take precedence over embedded files.
+pub struct Overlay {
+    folder: path::PathBuf,
+}
+
+impl Overlay {
+    /// Uses the folder given by an environment variable, if it is set and not
+    /// empty.
+    pub fn from_env(variable: &str) -> Option<Self> {
+        let folder = env::var_os(variable)?;
+        if folder.is_empty() {
+            None
+        } else {
+            Some(Self::new(folder))
+        }
+    }
+
+    /// Uses the given folder.
+    pub fn new(folder: impl Into<path::PathBuf>) -> Self {
+        Self {
+            folder: folder.into(),
+        }
+    }
+
+    /// Reads the contents of a file given by its relative path.
+    ///
+    /// Returns `None` if there is no such file, or the path would leave the
+    /// folder, including via symbolic links.
+    pub fn get(&self, relative_path: &str) -> Option<Vec<u8>> {
+        let components = relative_path::get_components(relative_path)?;
+        let folder = fs::canonicalize(&self.folder).ok()?;
+        let path = fs::canonicalize(folder.join(components.join("/"))).ok()?;
+
+        if path.starts_with(&folder) && path.is_file() {
+            fs::read(path).ok()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_get() {
+        let folder = arrange();
+        let overlay = Overlay::new(folder.path().join("overlay"));
+
+        let actual = ["a", "b/c", "d", "b"].map(|relative_path| overlay.get(relative_path));
+
+        let expected = [Some(b"a".to_vec()), Some(b"b/c".to_vec()), None, None];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_path_traversal_it_gets_nothing() {
+        let folder = arrange();
+        let overlay = Overlay::new(folder.path().join("overlay"));
+
+        let actual = ["../secret", "b/../../secret", "/secret", "./a", "b//c"]
+            .map(|relative_path| overlay.get(relative_path));
+
+        let expected = [None, None, None, None, None];
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn given_symbolic_link_out_of_folder_it_gets_nothing() {
+        let folder = arrange();
+        std::os::unix::fs::symlink(
+            folder.path().join("secret"),
+            folder.path().join("overlay").join("link"),
+        )
+        .unwrap();
+        let overlay = Overlay::new(folder.path().join("overlay"));
+
+        let actual = overlay.get("link");
+
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn given_missing_folder_it_gets_nothing() {
+        let folder = arrange();
+        let overlay = Overlay::new(folder.path().join("missing"));
+
+        let actual = overlay.get("a");
+
+        assert_eq!(actual, None);
+    }
+
+    fn arrange() -> tempfile::TempDir {
+        let folder = tempfile::tempdir().unwrap();
+        for relative_path in ["overlay/a", "overlay/b/c", "secret"] {
+            let path = folder.path().join(relative_path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, relative_path.trim_start_matches("overlay/")).unwrap();
+        }
+        folder
+    }
+}
@@ -0,0 +1,98 @@
+use std::borrow;
+use std::path;
+
+/// Splits a relative path like `"a/b"` into its components, or returns `None` if
+/// any component is not a plain name (like `""`, `"."`, or `".."`).
+pub fn get_components(relative_path: &str) -> Option<Vec<&str>> {
+    relative_path
+        .split('/')
+        .map(|component| {
+            let mut components = path::Path::new(component).components();
+            match (components.next(), components.next()) {
+                (Some(path::Component::Normal(_)), None) => Some(component),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Gets the key to look up a requested path like `"/Images/./Logo.PNG"` with the
+/// configuration `lookup`, or returns `None` if the path is rejected.
+///
+/// Normalization drops empty components (as in `"a//b"` or `"/a"`) and `"."`
+/// components, and rejects `".."` components. Case folding maps to lowercase.
+pub fn get_lookup_key(
+    relative_path: &str,
+    normalize: bool,
+    case_insensitive: bool,
+) -> Option<borrow::Cow<'_, str>> {
+    let relative_path = if normalize {
+        let components = relative_path
+            .split('/')
+            .filter(|component| !(component.is_empty() || *component == "."))
+            .map(|component| (component != "..").then_some(component))
+            .collect::<Option<Vec<_>>>()?;
+        let normalized = components.join("/");
+        if normalized == relative_path {
+            borrow::Cow::Borrowed(relative_path)
+        } else {
+            borrow::Cow::Owned(normalized)
+        }
+    } else {
+        borrow::Cow::Borrowed(relative_path)
+    };
+
+    Some(if case_insensitive {
+        relative_path.to_lowercase().into()
+    } else {
+        relative_path
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_valid_paths() {
+        let actual = ["a", "a/b", ".a/b.c"].map(get_components);
+
+        let expected = [
+            Some(vec!["a"]),
+            Some(vec!["a", "b"]),
+            Some(vec![".a", "b.c"]),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_invalid_path_it_returns_none() {
+        let actual = ["", "/a", "a/", "a//b", "./a", "a/..", "../a"].map(get_components);
+
+        let expected = [None, None, None, None, None, None, None];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_lookup_key() {
+        let actual = [
+            ("/Images/./Logo.PNG", true, true),
+            ("a//b/", true, false),
+            ("A//b", false, true),
+            ("a/../b", true, true),
+            ("a/../b", false, false),
+        ]
+        .map(|(path, normalize, case_insensitive)| {
+            get_lookup_key(path, normalize, case_insensitive).map(borrow::Cow::into_owned)
+        });
+
+        let expected = [
+            Some("images/logo.png".into()),
+            Some("a/b".into()),
+            Some("a//b".into()),
+            None,
+            Some("a/../b".into()),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
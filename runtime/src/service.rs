@@ -0,0 +1,726 @@
+use crate::web;
+use bytes::Bytes;
+use http::header;
+use http_body_util::Full;
+use std::convert;
+use std::future;
+use std::str;
+use std::task;
+
+/// A file served by [`ServeAssets`], with metadata computed at compile time.
+#[derive(Debug)]
+pub struct ServedFile {
+    /// The relative path like `"my_assets/style.css"`.
+    pub relative_path: &'static str,
+    /// The raw file contents.
+    pub contents: &'static [u8],
+    /// The media type like `"text/css"`.
+    pub media_type: &'static str,
+    /// The strong entity tag including quotes, derived from a hash of the
+    /// contents.
+    pub etag: &'static str,
+    /// The last modification time as an HTTP date, if available.
+    pub last_modified: Option<&'static str>,
+    /// The `Cache-Control` policy, if configured.
+    pub cache_control: Option<&'static str>,
+    /// The relative path with a content hash like `"my_assets/app.3f9a1c2b.js"`,
+    /// if the file is fingerprinted.
+    pub fingerprinted_path: Option<&'static str>,
+    /// Precompressed variants in order of preference, like a file
+    /// `"my_assets/style.css.br"`.
+    pub variants: &'static [Variant],
+}
+
+/// A precompressed variant of a [`ServedFile`].
+#[derive(Debug)]
+pub struct Variant {
+    /// The content coding like `"br"` or `"gzip"`.
+    pub encoding: &'static str,
+    /// The compressed contents.
+    pub contents: &'static [u8],
+    /// The strong entity tag of the compressed contents.
+    pub etag: &'static str,
+}
+
+/// A `tower::Service` that serves embedded files over HTTP, usable with axum.
+///
+/// The request path like `/my_assets/style.css` is looked up as a relative path
+/// or as a fingerprinted path. Responses support conditional requests via
+/// `If-None-Match`, single byte ranges via `Range`, and precompressed variants
+/// via `Accept-Encoding`.
+#[derive(Clone, Copy, Debug)]
+pub struct ServeAssets {
+    files: &'static [ServedFile],
+    resolve_path: Option<fn(&str) -> Option<&'static str>>,
+    web: Option<&'static web::Web>,
+}
+
+impl ServeAssets {
+    /// Serves files sorted by relative path.
+    pub fn new(files: &'static [ServedFile]) -> Self {
+        Self {
+            files,
+            resolve_path: None,
+            web: None,
+        }
+    }
+
+    /// Maps request paths to relative paths before the lookup, like with the
+    /// generated function `resolve_path`.
+    pub fn resolve_with(self, resolve_path: fn(&str) -> Option<&'static str>) -> Self {
+        Self {
+            resolve_path: Some(resolve_path),
+            ..self
+        }
+    }
+
+    /// Resolves index files, a fallback, and trailing slashes of request paths,
+    /// like with the generated static `WEB`.
+    pub fn web(self, web: &'static web::Web) -> Self {
+        Self {
+            web: Some(web),
+            ..self
+        }
+    }
+
+    /// Looks up a file by its request path like `/my_assets/style.css`.
+    pub fn get(&self, path: &str) -> Option<&'static ServedFile> {
+        match self.resolve(path)? {
+            web::WebPath::File(relative_path) => self.find(relative_path),
+            web::WebPath::Redirect(_) => None,
+        }
+    }
+
+    fn resolve(&self, path: &str) -> Option<web::WebPath> {
+        let path = decode_path(path.strip_prefix('/').unwrap_or(path))?;
+        let get = |relative_path: &str| {
+            let file = match self.resolve_path {
+                None => self.find(relative_path),
+                Some(resolve_path) => match resolve_path(relative_path) {
+                    None => self.find_fingerprinted(relative_path),
+                    Some(relative_path) => self.find_exact(relative_path),
+                },
+            }?;
+            Some(
+                file.fingerprinted_path
+                    .filter(|fingerprinted_path| *fingerprinted_path == relative_path)
+                    .unwrap_or(file.relative_path),
+            )
+        };
+        match self.web {
+            None => get(&path).map(web::WebPath::File),
+            Some(web) => web.resolve(&path, get),
+        }
+    }
+
+    /// Finds a file by its relative path or its fingerprinted path.
+    fn find(&self, path: &str) -> Option<&'static ServedFile> {
+        self.find_exact(path)
+            .or_else(|| self.find_fingerprinted(path))
+    }
+
+    fn find_fingerprinted(&self, fingerprinted_path: &str) -> Option<&'static ServedFile> {
+        get_unfingerprinted_paths(fingerprinted_path)
+            .into_iter()
+            .filter_map(|relative_path| self.find_exact(&relative_path))
+            .find(|file| file.fingerprinted_path == Some(fingerprinted_path))
+    }
+
+    fn find_exact(&self, relative_path: &str) -> Option<&'static ServedFile> {
+        let index = self
+            .files
+            .binary_search_by(|file| file.relative_path.cmp(relative_path))
+            .ok()?;
+        Some(&self.files[index])
+    }
+
+    fn respond(&self, method: &http::Method, path: &str, headers: &http::HeaderMap) -> Response {
+        if !(method == http::Method::GET || method == http::Method::HEAD) {
+            return http::Response::builder()
+                .status(http::StatusCode::METHOD_NOT_ALLOWED)
+                .header(header::ALLOW, "GET, HEAD")
+                .body(Full::default())
+                .unwrap();
+        }
+
+        match self.resolve(path) {
+            None => respond_not_found(),
+
+            // The fallback of `Web` options may be missing from the files.
+            Some(web::WebPath::File(path)) => match self.find(path) {
+                None => respond_not_found(),
+
+                Some(file) => {
+                    // Only fingerprinted paths are immutable, not the plain path.
+                    let cache_control = match file.fingerprinted_path {
+                        Some(fingerprinted_path) if fingerprinted_path != path => Some("no-cache"),
+                        _ => file.cache_control,
+                    };
+                    respond_with_file(file, cache_control, method == http::Method::HEAD, headers)
+                }
+            },
+
+            Some(web::WebPath::Redirect(location)) => http::Response::builder()
+                .status(http::StatusCode::PERMANENT_REDIRECT)
+                .header(header::LOCATION, location)
+                .body(Full::default())
+                .unwrap(),
+        }
+    }
+}
+
+/// Response of [`ServeAssets`].
+pub type Response = http::Response<Full<Bytes>>;
+
+impl<B> tower_service::Service<http::Request<B>> for ServeAssets {
+    type Response = Response;
+    type Error = convert::Infallible;
+    type Future = future::Ready<Result<Response, convert::Infallible>>;
+
+    fn poll_ready(
+        &mut self,
+        _: &mut task::Context<'_>,
+    ) -> task::Poll<Result<(), convert::Infallible>> {
+        task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        future::ready(Ok(self.respond(
+            request.method(),
+            request.uri().path(),
+            request.headers(),
+        )))
+    }
+}
+
+fn respond_not_found() -> Response {
+    http::Response::builder()
+        .status(http::StatusCode::NOT_FOUND)
+        .body(Full::default())
+        .unwrap()
+}
+
+fn respond_with_file(
+    file: &ServedFile,
+    cache_control: Option<&str>,
+    is_head: bool,
+    headers: &http::HeaderMap,
+) -> Response {
+    let range = get_header(headers, header::RANGE);
+
+    // Ranges refer to the identity encoding for simplicity.
+    let variant = match range {
+        None => get_header(headers, header::ACCEPT_ENCODING)
+            .and_then(|accept_encoding| choose_variant(file.variants, accept_encoding)),
+        Some(_) => None,
+    };
+    let (contents, etag) = match variant {
+        None => (file.contents, file.etag),
+        Some(variant) => (variant.contents, variant.etag),
+    };
+
+    let mut builder = http::Response::builder()
+        .header(header::CONTENT_TYPE, file.media_type)
+        .header(header::ETAG, etag)
+        .header(header::ACCEPT_RANGES, "bytes");
+    if let Some(last_modified) = file.last_modified {
+        builder = builder.header(header::LAST_MODIFIED, last_modified);
+    }
+    if let Some(cache_control) = cache_control {
+        builder = builder.header(header::CACHE_CONTROL, cache_control);
+    }
+    if !file.variants.is_empty() {
+        builder = builder.header(header::VARY, "Accept-Encoding");
+    }
+    if let Some(variant) = variant {
+        builder = builder.header(header::CONTENT_ENCODING, variant.encoding);
+    }
+
+    if get_header(headers, header::IF_NONE_MATCH)
+        .is_some_and(|if_none_match| is_etag_matched(if_none_match, etag))
+    {
+        return builder
+            .status(http::StatusCode::NOT_MODIFIED)
+            .body(Full::default())
+            .unwrap();
+    }
+
+    let is_range_applicable =
+        get_header(headers, header::IF_RANGE).is_none_or(|if_range| if_range == etag);
+    let (status, contents) = match range.filter(|_| is_range_applicable) {
+        None => (http::StatusCode::OK, contents),
+
+        Some(range) => match get_range(range, contents.len()) {
+            None => (http::StatusCode::OK, contents),
+
+            Some(Err(())) => {
+                return builder
+                    .status(http::StatusCode::RANGE_NOT_SATISFIABLE)
+                    .header(header::CONTENT_RANGE, format!("bytes */{}", contents.len()))
+                    .body(Full::default())
+                    .unwrap()
+            }
+
+            Some(Ok((start, end))) => {
+                builder = builder.header(
+                    header::CONTENT_RANGE,
+                    format!("bytes {start}-{}/{}", end - 1, contents.len()),
+                );
+                (http::StatusCode::PARTIAL_CONTENT, &contents[start..end])
+            }
+        },
+    };
+
+    builder
+        .status(status)
+        .header(header::CONTENT_LENGTH, contents.len())
+        .body(if is_head {
+            Full::default()
+        } else {
+            Full::new(Bytes::from_static(contents))
+        })
+        .unwrap()
+}
+
+fn get_header(headers: &http::HeaderMap, name: header::HeaderName) -> Option<&str> {
+    headers.get(name)?.to_str().ok()
+}
+
+/// Gets candidates of the relative path for a fingerprinted path like
+/// `"app.3f9a1c2b.js"` or `"LICENSE.3f9a1c2b"` by dropping a hash component.
+fn get_unfingerprinted_paths(path: &str) -> Vec<String> {
+    let (folder, filename) = match path.rsplit_once('/') {
+        None => ("", path),
+        Some((folder, _)) => (&path[..=folder.len()], &path[folder.len() + 1..]),
+    };
+    let components = filename.split('.').collect::<Vec<_>>();
+
+    (1..components.len())
+        .rev()
+        .take(2)
+        .filter(|&index| {
+            let component = components[index];
+            component.len() == web::FINGERPRINT_LENGTH
+                && component.bytes().all(|byte| byte.is_ascii_hexdigit())
+        })
+        .map(|index| {
+            let mut components = components.clone();
+            components.remove(index);
+            format!("{folder}{}", components.join("."))
+        })
+        .collect()
+}
+
+/// Decodes percent-encoded bytes like `%20`.
+fn decode_path(path: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut rest = path.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let digits = str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(digits, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Chooses the variant with the highest quality value, preferring earlier
+/// variants on a tie.
+fn choose_variant(variants: &'static [Variant], accept_encoding: &str) -> Option<&'static Variant> {
+    let qualities = accept_encoding
+        .split(',')
+        .filter_map(|item| {
+            let mut parameters = item.split(';').map(str::trim);
+            let coding = parameters.next().filter(|coding| !coding.is_empty())?;
+            let quality = parameters
+                .find_map(|parameter| parameter.strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.parse::<f32>().ok())?;
+            Some((coding, quality))
+        })
+        .collect::<Vec<_>>();
+    let get_quality = |encoding: &str| {
+        qualities
+            .iter()
+            .find(|(coding, _)| coding.eq_ignore_ascii_case(encoding))
+            .or_else(|| qualities.iter().find(|(coding, _)| *coding == "*"))
+            .map_or(0.0, |(_, quality)| *quality)
+    };
+
+    variants
+        .iter()
+        .map(|variant| (variant, get_quality(variant.encoding)))
+        .filter(|(_, quality)| *quality > 0.0)
+        .fold(
+            None,
+            |best: Option<(&Variant, f32)>, (variant, quality)| match best {
+                Some((_, best_quality)) if best_quality >= quality => best,
+                _ => Some((variant, quality)),
+            },
+        )
+        .map(|(variant, _)| variant)
+}
+
+/// Compares entity tags weakly as required for `If-None-Match`.
+fn is_etag_matched(if_none_match: &str, etag: &str) -> bool {
+    let etag = etag.strip_prefix("W/").unwrap_or(etag);
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|other| other == "*" || other.strip_prefix("W/").unwrap_or(other) == etag)
+}
+
+/// Gets a single byte range as a half-open interval.
+///
+/// Returns `None` to ignore the header, like for multiple ranges, and
+/// `Some(Err(()))` if the range is not satisfiable.
+fn get_range(range: &str, length: usize) -> Option<Result<(usize, usize), ()>> {
+    let range = range.trim().strip_prefix("bytes=")?;
+    if range.contains(',') {
+        return None;
+    }
+    let (start, end) = range.trim().split_once('-')?;
+
+    let (start, end) = if start.is_empty() {
+        let suffix = end.parse::<usize>().ok()?;
+        (length.saturating_sub(suffix), length)
+    } else {
+        let start = start.parse::<usize>().ok()?;
+        let end = if end.is_empty() {
+            length
+        } else {
+            let end = end.parse::<usize>().ok()?;
+            if end < start {
+                return None;
+            }
+            end.saturating_add(1).min(length)
+        };
+        (start, end)
+    };
+
+    Some(if start < end {
+        Ok((start, end))
+    } else {
+        Err(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body::Body;
+    use std::pin;
+    use tower_service::Service;
+
+    static FILES: [ServedFile; 3] = [
+        ServedFile {
+            relative_path: "a b.txt",
+            contents: b"0123456789",
+            media_type: "text/plain",
+            etag: "\"a\"",
+            last_modified: Some("Sun, 06 Nov 1994 08:49:37 GMT"),
+            cache_control: None,
+            fingerprinted_path: None,
+            variants: &[],
+        },
+        ServedFile {
+            relative_path: "c/d.css",
+            contents: b"d",
+            media_type: "text/css",
+            etag: "\"d\"",
+            last_modified: None,
+            cache_control: None,
+            fingerprinted_path: None,
+            variants: &[
+                Variant {
+                    encoding: "br",
+                    contents: b"d.br",
+                    etag: "\"d.br\"",
+                },
+                Variant {
+                    encoding: "gzip",
+                    contents: b"d.gz",
+                    etag: "\"d.gz\"",
+                },
+            ],
+        },
+        ServedFile {
+            relative_path: "e.js",
+            contents: b"e",
+            media_type: "text/javascript",
+            etag: "\"e\"",
+            last_modified: None,
+            cache_control: Some("immutable"),
+            fingerprinted_path: Some("e.0123abcd.js"),
+            variants: &[],
+        },
+    ];
+
+    #[test]
+    fn handles_get() {
+        let actual = call(http::Request::get("/a%20b.txt"));
+
+        let expected = (
+            200,
+            "content-type: text/plain\netag: \"a\"\naccept-ranges: bytes\nlast-modified: Sun, 06 Nov 1994 08:49:37 GMT\ncontent-length: 10".into(),
+            b"0123456789".to_vec(),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_head() {
+        let actual = call(http::Request::head("/c/d.css"));
+
+        let expected = (
+            200,
+            "content-type: text/css\netag: \"d\"\naccept-ranges: bytes\nvary: Accept-Encoding\ncontent-length: 1".into(),
+            vec![],
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_such_file_it_responds_not_found() {
+        let actual =
+            ["/c", "/c/", "/x", "/a%2", "/a%ff"].map(|path| call(http::Request::get(path)).0);
+
+        let expected = [404, 404, 404, 404, 404];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_other_method_it_responds_method_not_allowed() {
+        let actual = call(http::Request::post("/c/d.css"));
+
+        let expected = (405, "allow: GET, HEAD".into(), vec![]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_if_none_match() {
+        let actual = ["\"d\"", "\"x\", W/\"d\"", "*", "\"x\""].map(|if_none_match| {
+            call(http::Request::get("/c/d.css").header(header::IF_NONE_MATCH, if_none_match)).0
+        });
+
+        let expected = [304, 304, 304, 200];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_range() {
+        let actual = [
+            "bytes=2-4",
+            "bytes=7-",
+            "bytes=-3",
+            "bytes=8-20",
+            "bytes=0-18446744073709551615",
+            "bytes=10-",
+            "bytes=0-1,3-4",
+            "lines=1-2",
+        ]
+        .map(|range| {
+            let (status, headers, body) =
+                call(http::Request::get("/a%20b.txt").header(header::RANGE, range));
+            let content_range = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-range: "))
+                .map(String::from);
+            (status, content_range, String::from_utf8(body).unwrap())
+        });
+
+        let expected = [
+            (206, Some("bytes 2-4/10".into()), "234".into()),
+            (206, Some("bytes 7-9/10".into()), "789".into()),
+            (206, Some("bytes 7-9/10".into()), "789".into()),
+            (206, Some("bytes 8-9/10".into()), "89".into()),
+            (206, Some("bytes 0-9/10".into()), "0123456789".into()),
+            (416, Some("bytes */10".into()), String::new()),
+            (200, None, "0123456789".into()),
+            (200, None, "0123456789".into()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_if_range() {
+        let actual = ["\"a\"", "\"x\""].map(|if_range| {
+            call(
+                http::Request::get("/a%20b.txt")
+                    .header(header::RANGE, "bytes=0-0")
+                    .header(header::IF_RANGE, if_range),
+            )
+            .0
+        });
+
+        let expected = [206, 200];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_accept_encoding() {
+        let actual = [
+            "gzip, br",
+            "gzip",
+            "br;q=0.5, gzip",
+            "br;q=0, *",
+            "identity",
+            "GZIP",
+        ]
+        .map(|accept_encoding| {
+            let (_, _, body) = call(
+                http::Request::get("/c/d.css").header(header::ACCEPT_ENCODING, accept_encoding),
+            );
+            String::from_utf8(body).unwrap()
+        });
+
+        let expected = ["d.br", "d.gz", "d.gz", "d.gz", "d", "d.gz"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_variant_it_sets_encoding_headers() {
+        let actual = call(http::Request::get("/c/d.css").header(header::ACCEPT_ENCODING, "br"));
+
+        let expected = (
+            200,
+            "content-type: text/css\netag: \"d.br\"\naccept-ranges: bytes\nvary: Accept-Encoding\ncontent-encoding: br\ncontent-length: 4".into(),
+            b"d.br".to_vec(),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_resolve_path() {
+        let service = ServeAssets::new(&FILES).resolve_with(|path| match path {
+            "D.CSS" => Some("c/d.css"),
+            _ => None,
+        });
+
+        let actual = ["/D.CSS", "/c/d.css"].map(|path| service.get(path).is_some());
+
+        let expected = [true, false];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_web() {
+        static WEB: web::Web = web::Web {
+            index_files: &["d.css"],
+            fallback: Some("a b.txt"),
+            trailing_slash: web::TrailingSlash::Redirect,
+        };
+        let service = ServeAssets::new(&FILES).web(&WEB);
+
+        let actual = ["/c/", "/c", "/x", "/c/d.css/"].map(|path| {
+            let (status, headers, body) = call_service(service, http::Request::get(path));
+            let location = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("location: "))
+                .map(String::from);
+            (status, location, body.len())
+        });
+
+        let expected = [
+            (200, None, 1),
+            (308, Some("c/".into()), 0),
+            (200, None, 10),
+            (308, Some("../d.css".into()), 0),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_fallback_of_no_file_it_responds_not_found() {
+        static WEB: web::Web = web::Web {
+            index_files: &[],
+            fallback: Some("x"),
+            trailing_slash: web::TrailingSlash::Ignore,
+        };
+        let service = ServeAssets::new(&FILES).web(&WEB);
+
+        let actual =
+            ["/", "/x", "/y/"].map(|path| call_service(service, http::Request::get(path)).0);
+
+        let expected = [404, 404, 404];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_fingerprinted_path() {
+        let actual = ["/e.0123abcd.js", "/e.js", "/e.0123abce.js", "/e.0123abcd"].map(|path| {
+            let (status, headers, _) = call(http::Request::get(path));
+            let cache_control = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("cache-control: "))
+                .map(String::from);
+            (status, cache_control)
+        });
+
+        let expected = [
+            (200, Some("immutable".into())),
+            (200, Some("no-cache".into())),
+            (404, None),
+            (404, None),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_unfingerprinted_paths() {
+        let actual = [
+            "a/b.0123abcd.js",
+            "LICENSE.0123abcd",
+            "a.0123abcd.0123abcd",
+            "a.b.js",
+            "0123abcd",
+        ]
+        .map(get_unfingerprinted_paths);
+
+        let expected = [
+            vec!["a/b.js".to_string()],
+            vec!["LICENSE".into()],
+            vec!["a.0123abcd".into(), "a.0123abcd".into()],
+            vec![],
+            vec![],
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    fn call(request: http::request::Builder) -> (u16, String, Vec<u8>) {
+        call_service(ServeAssets::new(&FILES), request)
+    }
+
+    fn call_service(
+        mut service: ServeAssets,
+        request: http::request::Builder,
+    ) -> (u16, String, Vec<u8>) {
+        let response = service
+            .call(request.body(()).unwrap())
+            .into_inner()
+            .unwrap();
+
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| format!("{name}: {}", value.to_str().unwrap()))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut body = pin::pin!(response.into_body());
+        let waker = task::Waker::noop();
+        let body = match body
+            .as_mut()
+            .poll_frame(&mut task::Context::from_waker(waker))
+        {
+            task::Poll::Ready(Some(Ok(frame))) => frame.into_data().unwrap().to_vec(),
+            _ => vec![],
+        };
+
+        (status, headers, body)
+    }
+}
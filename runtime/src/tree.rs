@@ -0,0 +1,305 @@
+use std::fmt;
+use std::ptr;
+
+/// A folder of the static tree `TREE` generated by the macro
+/// `include_file_tree`.
+///
+/// Navigating the tree does not allocate.
+pub struct Folder<A: 'static> {
+    /// The last component of the relative path like `"my_assets"`, or `""` for
+    /// the base folder.
+    pub name: &'static str,
+    /// The relative path like `"x/my_assets"`, or `""` for the base folder.
+    pub relative_path: &'static str,
+    /// The containing folder, or `None` for the base folder.
+    pub parent: Option<&'static Folder<A>>,
+    /// The immediate entries, sorted by name.
+    pub entries: &'static [Entry<A>],
+}
+
+/// A file of the static tree `TREE`.
+pub struct File<A: 'static> {
+    /// The last component of the relative path like `"style.css"`.
+    pub name: &'static str,
+    /// The relative path like `"my_assets/style.css"`.
+    pub relative_path: &'static str,
+    /// The respective element of the array `ASSETS`.
+    pub asset: &'static A,
+    /// The containing folder.
+    pub parent: &'static Folder<A>,
+}
+
+/// An entry of a folder, which is either a file or a subfolder.
+#[derive(Debug)]
+pub enum Entry<A: 'static> {
+    File(File<A>),
+    Folder(&'static Folder<A>),
+}
+
+impl<A> Folder<A> {
+    /// Looks up an entry by its path relative to this folder like
+    /// `"world/levels/tutorial.json"`.
+    pub fn get(&self, relative_path: &str) -> Option<&'static Entry<A>> {
+        let (first, rest) = match relative_path.split_once('/') {
+            None => (relative_path, None),
+            Some((first, rest)) => (first, Some(rest)),
+        };
+        let index = self
+            .entries
+            .binary_search_by(|entry| entry.name().cmp(first))
+            .ok()?;
+        let entry = &self.entries[index];
+
+        match (entry, rest) {
+            (_, None) => Some(entry),
+            (Entry::Folder(folder), Some(rest)) => folder.get(rest),
+            (Entry::File(_), Some(_)) => None,
+        }
+    }
+
+    /// Returns the entries of a folder given by its path relative to this folder
+    /// like `"world/levels"`, where `""` is this folder itself.
+    pub fn read_dir(&self, relative_path: &str) -> Option<&'static [Entry<A>]> {
+        if relative_path.is_empty() {
+            Some(self.entries)
+        } else {
+            match self.get(relative_path)? {
+                Entry::File(_) => None,
+                Entry::Folder(folder) => Some(folder.entries),
+            }
+        }
+    }
+
+    /// Iterates over all entries below this folder, depth-first with each folder
+    /// before its entries.
+    pub fn walk(&'static self) -> Walk<A> {
+        Walk {
+            root: self,
+            folder: self,
+            position: 0,
+        }
+    }
+
+    /// Returns the containing folder, or `None` for the base folder.
+    pub fn parent(&self) -> Option<&'static Folder<A>> {
+        self.parent
+    }
+}
+
+impl<A> File<A> {
+    /// Returns the containing folder.
+    pub fn parent(&self) -> &'static Folder<A> {
+        self.parent
+    }
+}
+
+impl<A> Entry<A> {
+    /// The last component of the relative path.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Entry::File(file) => file.name,
+            Entry::Folder(folder) => folder.name,
+        }
+    }
+
+    /// The relative path.
+    pub fn relative_path(&self) -> &'static str {
+        match self {
+            Entry::File(file) => file.relative_path,
+            Entry::Folder(folder) => folder.relative_path,
+        }
+    }
+
+    /// Returns the containing folder.
+    pub fn parent(&self) -> Option<&'static Folder<A>> {
+        match self {
+            Entry::File(file) => Some(file.parent),
+            Entry::Folder(folder) => folder.parent,
+        }
+    }
+}
+
+// Parents are omitted to avoid cycles.
+impl<A: fmt::Debug> fmt::Debug for Folder<A> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("Folder")
+            .field("name", &self.name)
+            .field("relative_path", &self.relative_path)
+            .field("entries", &self.entries)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: fmt::Debug> fmt::Debug for File<A> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("File")
+            .field("name", &self.name)
+            .field("relative_path", &self.relative_path)
+            .field("asset", &self.asset)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Iterator returned by [`Folder::walk`].
+pub struct Walk<A: 'static> {
+    root: &'static Folder<A>,
+    folder: &'static Folder<A>,
+    position: usize,
+}
+
+impl<A> Iterator for Walk<A> {
+    type Item = &'static Entry<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.folder.entries.get(self.position) {
+                self.position += 1;
+                if let Entry::Folder(folder) = entry {
+                    self.folder = folder;
+                    self.position = 0;
+                }
+                return Some(entry);
+            }
+
+            if ptr::eq(self.folder, self.root) {
+                return None;
+            }
+            let parent = self.folder.parent?;
+            let name = self.folder.name;
+            self.position = parent
+                .entries
+                .binary_search_by(|entry| entry.name().cmp(name))
+                .ok()?
+                + 1;
+            self.folder = parent;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ASSETS: [usize; 3] = [0, 1, 2];
+
+    static TREE: Folder<usize> = {
+        static FOLDER_0: Folder<usize> = Folder {
+            name: "a",
+            relative_path: "a",
+            parent: Some(&TREE),
+            entries: &[
+                Entry::File(File {
+                    name: "b",
+                    relative_path: "a/b",
+                    asset: &ASSETS[0],
+                    parent: &FOLDER_0,
+                }),
+                Entry::Folder(&FOLDER_1),
+            ],
+        };
+
+        static FOLDER_1: Folder<usize> = Folder {
+            name: "c",
+            relative_path: "a/c",
+            parent: Some(&FOLDER_0),
+            entries: &[Entry::File(File {
+                name: "d",
+                relative_path: "a/c/d",
+                asset: &ASSETS[1],
+                parent: &FOLDER_1,
+            })],
+        };
+
+        Folder {
+            name: "",
+            relative_path: "",
+            parent: None,
+            entries: &[
+                Entry::Folder(&FOLDER_0),
+                Entry::File(File {
+                    name: "e",
+                    relative_path: "e",
+                    asset: &ASSETS[2],
+                    parent: &TREE,
+                }),
+            ],
+        }
+    };
+
+    #[test]
+    fn handles_get() {
+        let actual = ["a/b", "a/c", "a/c/d", "e", "a/x", "e/x", "", "a/"]
+            .map(|path| TREE.get(path).map(|entry| entry.relative_path()));
+
+        let expected = [
+            Some("a/b"),
+            Some("a/c"),
+            Some("a/c/d"),
+            Some("e"),
+            None,
+            None,
+            None,
+            None,
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_read_dir() {
+        let actual = ["", "a", "a/c", "e", "x"].map(|path| {
+            TREE.read_dir(path)
+                .map(|entries| entries.iter().map(Entry::name).collect::<Vec<_>>())
+        });
+
+        let expected = [
+            Some(vec!["a", "e"]),
+            Some(vec!["b", "c"]),
+            Some(vec!["d"]),
+            None,
+            None,
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_walk() {
+        let actual = TREE.walk().map(Entry::relative_path).collect::<Vec<_>>();
+
+        let expected = vec!["a", "a/b", "a/c", "a/c/d", "e"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_walk_of_subfolder() {
+        let Some(Entry::Folder(folder)) = TREE.get("a") else {
+            panic!("expected folder");
+        };
+
+        let actual = folder.walk().map(Entry::relative_path).collect::<Vec<_>>();
+
+        let expected = vec!["a/b", "a/c", "a/c/d"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_debug() {
+        let actual = format!("{:?}", TREE.get("a/c").unwrap());
+
+        let expected = r#"Folder(Folder { name: "c", relative_path: "a/c", entries: [File(File { name: "d", relative_path: "a/c/d", asset: 1, .. })], .. })"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn handles_parent() {
+        let actual = ["a", "a/b", "a/c/d", "e"].map(|path| {
+            TREE.get(path)
+                .and_then(Entry::parent)
+                .map(|folder| folder.relative_path)
+        });
+
+        let expected = [Some(""), Some("a"), Some("a/c"), Some("")];
+        assert_eq!(actual, expected);
+    }
+}
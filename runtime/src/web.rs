@@ -0,0 +1,174 @@
+/// Options to resolve request paths of a website, as configured with `[web]`.
+#[derive(Clone, Copy, Debug)]
+pub struct Web {
+    /// Filenames like `"index.html"` to look up for folder paths, in order.
+    pub index_files: &'static [&'static str],
+    /// The relative path of a file to serve for unknown paths, if any.
+    pub fallback: Option<&'static str>,
+    /// How to handle a trailing slash that does not match the kind of a path.
+    pub trailing_slash: TrailingSlash,
+}
+
+/// Handling of a path like `"docs"` that is a folder or a path like `"a.txt/"`
+/// that is a file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TrailingSlash {
+    /// Resolves the path as if the trailing slash was right.
+    Ignore,
+    /// Redirects to the path with the trailing slash added or removed.
+    Redirect,
+}
+
+/// The result of resolving a request path with [`Web`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WebPath {
+    /// Serves the file with the given relative path.
+    File(&'static str),
+    /// Redirects to the given location, which is relative to the request path.
+    Redirect(String),
+}
+
+/// The number of hexadecimal digits of the hash in a fingerprinted path like
+/// `"app.3f9a1c2b.js"`.
+pub const FINGERPRINT_LENGTH: usize = 8;
+
+impl Web {
+    /// Resolves a request path like `"docs/"` without leading slash, where `get`
+    /// maps a relative path to the one of a selected file, if any.
+    ///
+    /// The empty path and paths with a trailing slash are folders. Returns
+    /// `None` if neither a file nor the fallback applies.
+    pub fn resolve(
+        &self,
+        path: &str,
+        get: impl Fn(&str) -> Option<&'static str>,
+    ) -> Option<WebPath> {
+        let (folder, is_folder) = match path.strip_suffix('/') {
+            None => (path, path.is_empty()),
+            Some(folder) => (folder, true),
+        };
+        let is_redirect = self.trailing_slash == TrailingSlash::Redirect;
+        let name = folder.rsplit('/').next().unwrap_or_default();
+
+        if !folder.is_empty() {
+            if let Some(file) = get(folder) {
+                return Some(if is_folder && is_redirect {
+                    WebPath::Redirect(format!("../{name}"))
+                } else {
+                    WebPath::File(file)
+                });
+            }
+        }
+
+        if let Some(file) = self.get_index_file(folder, &get) {
+            return Some(if !is_folder && is_redirect {
+                WebPath::Redirect(format!("{name}/"))
+            } else {
+                WebPath::File(file)
+            });
+        }
+
+        self.fallback.map(WebPath::File)
+    }
+
+    fn get_index_file(
+        &self,
+        folder: &str,
+        get: &impl Fn(&str) -> Option<&'static str>,
+    ) -> Option<&'static str> {
+        self.index_files.iter().find_map(|index_file| {
+            if folder.is_empty() {
+                get(index_file)
+            } else {
+                get(&format!("{folder}/{index_file}"))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RELATIVE_PATHS: [&str; 4] = ["a.txt", "docs/index.html", "index.html", "spa.html"];
+    const PATHS: [&str; 7] = ["", "a.txt", "a.txt/", "docs", "docs/", "b", "b/"];
+
+    fn get(relative_path: &str) -> Option<&'static str> {
+        RELATIVE_PATHS
+            .into_iter()
+            .find(|other| *other == relative_path)
+    }
+
+    fn resolve(web: Web, paths: [&str; 7]) -> [Option<WebPath>; 7] {
+        paths.map(|path| web.resolve(path, get))
+    }
+
+    #[test]
+    fn handles_index_files_and_fallback() {
+        let actual = resolve(
+            Web {
+                index_files: &["index.htm", "index.html"],
+                fallback: Some("spa.html"),
+                trailing_slash: TrailingSlash::Ignore,
+            },
+            PATHS,
+        );
+
+        let expected = [
+            Some(WebPath::File("index.html")),
+            Some(WebPath::File("a.txt")),
+            Some(WebPath::File("a.txt")),
+            Some(WebPath::File("docs/index.html")),
+            Some(WebPath::File("docs/index.html")),
+            Some(WebPath::File("spa.html")),
+            Some(WebPath::File("spa.html")),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_redirect_it_redirects_on_mismatched_trailing_slash() {
+        let actual = resolve(
+            Web {
+                index_files: &["index.html"],
+                fallback: None,
+                trailing_slash: TrailingSlash::Redirect,
+            },
+            PATHS,
+        );
+
+        let expected = [
+            Some(WebPath::File("index.html")),
+            Some(WebPath::File("a.txt")),
+            Some(WebPath::Redirect("../a.txt".into())),
+            Some(WebPath::Redirect("docs/".into())),
+            Some(WebPath::File("docs/index.html")),
+            None,
+            None,
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn given_no_index_files_it_does_not_resolve_folders() {
+        let actual = resolve(
+            Web {
+                index_files: &[],
+                fallback: None,
+                trailing_slash: TrailingSlash::Ignore,
+            },
+            PATHS,
+        );
+
+        let expected = [
+            None,
+            Some(WebPath::File("a.txt")),
+            Some(WebPath::File("a.txt")),
+            None,
+            None,
+            None,
+            None,
+        ];
+        assert_eq!(actual, expected);
+    }
+}
@@ -25,6 +25,7 @@ def _test_rust():
         [
             "cargo",
             "clippy",
+            "--workspace",
             "--all-features",
             "--all-targets",
             "--",
@@ -36,8 +37,9 @@ def _test_rust():
         check=True,
     )
 
-    subprocess.run(["cargo", "check"], check=True)
-    subprocess.run(["cargo", "test"], check=True)
+    subprocess.run(["cargo", "check", "--workspace"], check=True)
+    subprocess.run(["cargo", "test", "--workspace"], check=True)
+    subprocess.run(["cargo", "test", "--workspace", "--all-features"], check=True)
 
     examples = [path.stem for path in pathlib.Path("examples").glob("*.rs")]
     for example in sorted(examples):
@@ -47,13 +49,13 @@ def _test_rust():
 
 def _run_example(name, extra_arguments):
     subprocess.run(
-        ["cargo", "build", "--example", name] + extra_arguments,
+        ["cargo", "build", "--all-features", "--example", name] + extra_arguments,
         check=True,
     )
 
     try:
         subprocess.run(
-            ["cargo", "run", "--example", name] + extra_arguments,
+            ["cargo", "run", "--all-features", "--example", name] + extra_arguments,
             check=True,
             timeout=_EXAMPLE_TIMEOUT_IN_SECONDS.get(name),
         )
@@ -63,6 +65,7 @@ def _run_example(name, extra_arguments):
 
 _EXAMPLE_TIMEOUT_IN_SECONDS = {
     "library_actix_web": 2,
+    "library_axum": 2,
     "library_rocket": 2,
     "library_tide": 2,
     "library_warp": 2,
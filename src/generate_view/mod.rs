@@ -1,7 +0,0 @@
-mod get_default_initializer;
-mod get_forest;
-mod get_visitors;
-mod main;
-mod sanitize_name;
-
-pub use main::main;
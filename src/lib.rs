@@ -157,6 +157,11 @@
 //! asset type is generated without further configuration. See
 //! [example](https://github.com/evolutics/iftree/blob/main/examples/basics_standard_fields.rs).
 //!
+//! - **`cache_control`**`: &'static str`
+//!
+//!   The `Cache-Control` policy of the file, see
+//!   [`web.cache` configuration](#webcache).
+//!
 //! - **`contents_bytes`**`: &'static [u8]`
 //!
 //!   File contents as a byte array, using
@@ -167,6 +172,18 @@
 //!   File contents interpreted as a UTF-8 string, using
 //!   [`std::include_str`](https://doc.rust-lang.org/std/macro.include_str.html).
 //!
+//! - **`etag`**`: &'static str`
+//!
+//!   A strong entity tag including its quotes like
+//!   `"ca978112ca1bbdcafac231b39a23dc4d"`, derived from a SHA-256 hash of the
+//!   file contents at compile time.
+//!
+//! - **`fingerprinted_path`**`: &'static str`
+//!
+//!   The relative path with a content hash like `my_assets/app.3f9a1c2b.js` if
+//!   the file is fingerprinted, otherwise the relative path, see
+//!   [`web.cache` configuration](#webcache).
+//!
 //! - **`get_bytes`**`: fn() -> std::borrow::Cow<'static, [u8]>`
 //!
 //!   In debug builds (that is, when
@@ -182,12 +199,58 @@
 //!   using
 //!   [`std::include_bytes`](https://doc.rust-lang.org/std/macro.include_bytes.html).
 //!
+//! - **`get_decompressed`**`: fn() -> &'static [u8]`
+//!
+//!   File contents compressed at compile time and decompressed on the first call
+//!   at runtime, which keeps large assets small in the binary. Later calls return
+//!   the same decompressed contents.
+//!
+//!   This needs the feature `compression` of this crate, which you enable in your
+//!   manifest like `iftree = { version = "1.0", features = ["compression"] }`. No
+//!   further dependency is needed in your crate. See
+//!   [example](https://github.com/evolutics/iftree/blob/main/examples/scenario_compression.rs).
+//!
+//! - **`get_decompressed_str`**`: fn() -> &'static str`
+//!
+//!   Same as `get_decompressed` but for the file contents interpreted as a UTF-8
+//!   string, which is checked at compile time.
+//!
 //! - **`get_str`**`: fn() -> std::borrow::Cow<'static, str>`
 //!
 //!   Same as `get_bytes` but for the file contents interpreted as a UTF-8 string,
 //!   using
 //!   [`std::include_str`](https://doc.rust-lang.org/std/macro.include_str.html).
 //!
+//! - **`parsed_csv`**, **`parsed_json`**, **`parsed_toml`**, **`parsed_yaml`**`:
+//!   &'static AssetValue`
+//!
+//!   File contents parsed at compile time in the respective format. A malformed
+//!   file fails the build with an error pointing to the location in the file.
+//!
+//!   If any of these fields is used, a type of parsed data is generated next to the
+//!   `ASSETS` array. It is named after your asset type with the suffix `Value`, like
+//!   `AssetValue` for a type `Asset`:
+//!
+//!   ```ignore
+//!   pub enum AssetValue {
+//!       Null,
+//!       Boolean(bool),
+//!       Integer(i64),
+//!       Float(f64),
+//!       String(&'static str),
+//!       Array(&'static [AssetValue]),
+//!       Table(&'static [(&'static str, AssetValue)]),
+//!   }
+//!   ```
+//!
+//!   CSV files become an array of records, each an array of strings. TOML dates
+//!   and times become strings. Integers out of range of `i64` fail the build.
+//!
+//!   Each format needs a feature of this crate, which you enable in your manifest
+//!   like `iftree = { version = "1.0", features = ["format-json"] }`. The features
+//!   are `format-csv`, `format-json`, `format-toml`, and `format-yaml`. See
+//!   [example](https://github.com/evolutics/iftree/blob/main/examples/scenario_parsed_data.rs).
+//!
 //! - **`relative_path`**`: &'static str`
 //!
 //!   File path relative to the base folder, which is the folder with your manifest
@@ -234,6 +297,120 @@
 //! For even more control over code generation, there is the concept of
 //! [visitors](#template-visitors).
 //!
+//! ## Generic access
+//!
+//! With [`embedded_tree = true`](#embedded_tree), a unit struct `Assets` is
+//! generated along with the `ASSETS` array that implements the trait
+//! `iftree::runtime::EmbeddedTree`. This way, generic code like servers or template
+//! loaders can accept any embedded tree:
+//!
+//! - `len()` returns the number of files.
+//! - `iter()` iterates over pairs of relative path and asset.
+//! - `get(relative_path)` looks up an asset like `"my_assets/style.css"`.
+//! - `read_dir(folder)` iterates over the files and subfolders directly in a
+//!   folder like `"my_assets"`, where `""` is the base folder.
+//!
+//! Lookups use binary search over the elements of `ASSETS` sorted by relative path
+//! as strings, independent of the [`order` configuration](#order). With
+//! [`assets_by_path = true`](#assets_by_path), these are also available as an array
+//! `ASSETS_BY_PATH` along with a shortcut function `get_by_path(relative_path)`.
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/basics_embedded_tree.rs).
+//!
+//! ## Folder tree
+//!
+//! To navigate the folder hierarchy at runtime, a static variable
+//! `TREE: iftree::runtime::Folder<…>` is generated for the base folder with
+//! [`tree = true`](#tree). Each
+//! folder has a `name`, a `relative_path`, and `entries` sorted by name, where an
+//! entry is either a file (referring to its element of `ASSETS`) or a subfolder.
+//! Navigation does not allocate:
+//!
+//! - `get("world/levels/tutorial.json")` looks up a file or folder.
+//! - `read_dir("world/levels")` returns the entries of a folder.
+//! - `walk()` iterates over all entries below a folder, depth-first.
+//! - `parent()` returns the containing folder.
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/basics_tree.rs).
+//!
+//! ## Glob matching
+//!
+//! With [`glob = true`](#glob), a function
+//! `glob(patterns: &str) -> Result<impl Iterator<Item = &'static MyAsset>, …>` is
+//! generated to select assets at runtime. The patterns work exactly like the
+//! [`paths` configuration](#paths), with one pattern per line. For example,
+//! `locales/*/messages.ftl` selects files `messages.ftl` in any immediate
+//! subfolder of `locales`. An invalid pattern results in an error of type
+//! `iftree::runtime::PatternError`.
+//!
+//! Patterns that start with literal folders like `locales/` only search files in
+//! these folders using binary search. The same is available generically as
+//! `EmbeddedTree::glob`.
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/basics_glob.rs).
+//!
+//! ## Serving files
+//!
+//! With [`serve = true`](#serve), a function
+//! `service() -> iftree::runtime::ServeAssets` is generated. This is a
+//! `tower::Service` that serves the files over HTTP, for example with
+//! [axum](https://github.com/tokio-rs/axum):
+//!
+//! ```ignore
+//! let app = axum::Router::new().nest_service("/assets", service());
+//! ```
+//!
+//! A request path like `/my_assets/style.css` is looked up as a relative path. To
+//! avoid work at runtime, the following is computed at compile time and stored in
+//! a generated array `SERVED_FILES`:
+//!
+//! - The `Content-Type` header from the filename extension.
+//! - The `ETag` header from a SHA-256 hash of the contents.
+//! - The `Last-Modified` header from the file modification time.
+//! - Precompressed variants of a file like `style.css` from files `style.css.br`,
+//!   `style.css.zst`, or `style.css.gz` that are selected, too.
+//!
+//! Responses support conditional requests via `If-None-Match`, single byte ranges
+//! via `Range`, and precompressed variants via `Accept-Encoding`. With the
+//! [`lookup` configuration](#lookup), request paths are resolved accordingly.
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/library_axum.rs).
+//!
+//! ## Build scripts
+//!
+//! With the crate feature `build`, the same code as with the attribute macro can
+//! be generated ahead of time by a build script. This is useful to inspect or
+//! reuse the generated code, or to avoid the procedural macro. For example, with
+//! `iftree` as a build dependency, a `build.rs` like
+//!
+//! ```ignore
+//! fn main() {
+//!     let path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("assets.rs");
+//!     iftree::Builder::from_toml(
+//!         "paths = '/my_assets/**'",
+//!         "pub struct MyAsset { contents_str: &'static str }",
+//!     )
+//!     .generate_to(path)
+//!     .unwrap();
+//! }
+//! ```
+//!
+//! generates code that is included with
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/assets.rs"));
+//! ```
+//!
+//! The configuration is the same as for the attribute macro. The generated file
+//! also contains the asset type definition. Cargo reruns the build script when a
+//! selected file changes, when files are added to or removed from a folder that is
+//! searched in full, or when the environment variable of `root_folder_variable` or
+//! `obfuscate.key_env` changes.
+//!
 //! ## Name sanitization
 //!
 //! When generating identifiers based on paths, names are sanitized. For example, a
@@ -268,6 +445,21 @@
 //!
 //! To inspect the generated code, there is a [`debug` configuration](#debug).
 //!
+//! To iterate on a configuration without rebuilding your crate, the command-line
+//! tool [`cargo-iftree`](https://crates.io/crates/cargo-iftree) previews the
+//! selected files, the forest with sanitized identifiers, identifier collisions,
+//! and the formatted generated code. It uses the same code generation as the
+//! attribute macro. For example, run this in the folder of your `Cargo.toml`:
+//!
+//! ```text
+//! cargo install cargo-iftree
+//! cargo iftree "paths = '/my_assets/**'"
+//! ```
+//!
+//! Pass your asset type with an option like
+//! `--type 'pub struct MyAsset { contents_str: &str }'` for the generated code to
+//! match yours.
+//!
 //! # Recipes
 //!
 //! Here are example solutions for given problems.
@@ -289,6 +481,7 @@
 //!   [`include_flate`](https://github.com/evolutics/iftree/blob/main/examples/library_include_flate.rs)
 //! - File server with
 //!   [Actix Web](https://github.com/evolutics/iftree/blob/main/examples/library_actix_web.rs)
+//! - File server with axum, see [serving files](#serving-files)
 //! - File server with
 //!   [Rocket](https://github.com/evolutics/iftree/blob/main/examples/library_rocket.rs)
 //! - File server with
@@ -341,6 +534,14 @@
 //! The `iftree::include_file_tree` macro is configured via a
 //! [TOML](https://toml.io) string with the following fields.
 //!
+//! ## `assets_by_path`
+//!
+//! Whether to generate the array `ASSETS_BY_PATH` and the function
+//! `get_by_path(relative_path)` for lookups by relative path, as described in
+//! [generic access](#generic-access).
+//!
+//! **Default**: `false`
+//!
 //! ## `base_folder`
 //!
 //! Path patterns are interpreted as relative to this folder.
@@ -357,6 +558,73 @@
 //! See
 //! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_base_folder.rs).
 //!
+//! ## `bundles`
+//!
+//! Named groups of files that are only embedded if a
+//! [`cfg` predicate](https://doc.rust-lang.org/reference/conditional-compilation.html)
+//! holds, like optional modules behind crate features:
+//!
+//! ```toml
+//! paths = '/public/**'
+//!
+//! [bundles.admin]
+//! paths = '/admin/**'
+//! cfg = 'feature = "admin-ui"'
+//! ```
+//!
+//! The files of a bundle are selected in addition to those of the
+//! [`paths` configuration](#paths), using the same pattern language. All files
+//! share the same asset type, `ASSETS`, and
+//! [`base` hierarchy](#templateidentifiers).
+//! The entries of disabled bundles are compiled out like with the
+//! [`cfg` configuration](#cfg), which also lists the limitations.
+//!
+//! A file selected by `paths` is always embedded. Otherwise, a file matched by
+//! multiple bundles is embedded if any of them is enabled.
+//!
+//! In [development mode](#dev_mode), the files of enabled bundles are read from
+//! the file system as well.
+//!
+//! **Default**: `{}`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_bundles.rs).
+//!
+//! ## `cfg`
+//!
+//! Conditional compilation of selected files. Each rule pairs path patterns with a
+//! [`cfg` predicate](https://doc.rust-lang.org/reference/conditional-compilation.html):
+//!
+//! ```toml
+//! [[cfg]]
+//! paths = '/debug/**'
+//! predicate = 'debug_assertions'
+//!
+//! [[cfg]]
+//! paths = '/win/**'
+//! predicate = 'target_os = "windows"'
+//! ```
+//!
+//! A file matched by a rule is only embedded if its predicate holds. A file matched
+//! by multiple rules needs all their predicates to hold. Predicates like
+//! `feature = "…"` refer to the crate features of your crate.
+//!
+//! The `paths` patterns work like in the [`validate` configuration](#validate).
+//!
+//! The generated code stays consistent for each configuration: the length of
+//! `ASSETS` as well as the indices of its elements are computed as constant
+//! expressions. For the same reason, an `'index'` passed via
+//! [`template.initializer_arguments`](#templateinitializer_arguments) is a constant
+//! expression instead of a `usize` literal for files after a conditional file.
+//!
+//! This cannot be combined with [`template` visitors](#template-visitors) or
+//! `storage = 'packed'`.
+//!
+//! **Default**: `[]`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_cfg.rs).
+//!
 //! ## `debug`
 //!
 //! Whether to generate a string variable `DEBUG` with debug information such as the
@@ -367,9 +635,230 @@
 //! See
 //! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_debug.rs).
 //!
+//! ## `deduplicate`
+//!
+//! Whether to embed identical file contents only once.
+//!
+//! If enabled, files are compared by contents at compile time. Each contents that
+//! occurs in multiple files is stored once in a private static variable `BLOBS`,
+//! which all corresponding [standard fields](#standard-fields) `contents_bytes`,
+//! `contents_str`, `get_bytes`, and `get_str` refer to.
+//!
+//! With the [`debug` configuration](#debug), the debug information also reports
+//! the number of bytes saved.
+//!
+//! **Default**: `false`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_deduplicate.rs).
+//!
+//! ## `dev_mode`
+//!
+//! Whether to serve files from disk at runtime during development. This needs the
+//! crate feature `dev-mode`.
+//!
+//! With `dev_mode = 'filesystem'`, the following functions are generated next to
+//! `ASSETS`:
+//!
+//! - `get_asset(relative_path: &str) -> Option<Cow<'static, [u8]>>` looks up the
+//!   contents of a file by its relative path like `"my_assets/style.css"`.
+//! - `asset_relative_paths() -> Vec<Cow<'static, str>>` lists the relative paths
+//!   of all selected files in the same [`order`](#order) as `ASSETS`.
+//! - `iter_assets()` iterates over pairs of relative path and contents.
+//!
+//! In debug builds (`cfg(debug_assertions)`), these functions walk the
+//! [base folder](#base_folder) at runtime with the same [`paths`](#paths)
+//! patterns, including those of enabled [`bundles`](#bundles). Thus, files that
+//! are edited, added, or removed after compilation are taken into account without
+//! a rebuild. Files are normalized like at compile time according to the
+//! [`text` configuration](#text). The patterns are compiled only once, on first
+//! use.
+//!
+//! In release builds, the functions use the file contents embedded at compile
+//! time.
+//!
+//! Note that `ASSETS` and the `base` module always refer to the embedded files.
+//!
+//! **Default**: none
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_dev_mode.rs).
+//!
+//! ## `embedded_tree`
+//!
+//! Whether to generate the unit struct `Assets` for
+//! [generic access](#generic-access).
+//!
+//! **Default**: `false`
+//!
+//! ## `folder_functions`
+//!
+//! Whether to generate these functions in each module of the
+//! [identifiers](#templateidentifiers), like `base::x::y`:
+//!
+//! - `path()`: The relative folder path as a `&'static str`, like `"x/y"` (empty
+//!   for `base`).
+//! - `all()`: The files underneath the folder (recursively) as a subslice
+//!   `&'static [Asset]` of the `ASSETS` array.
+//!
+//! Their lowercase names cannot collide with the generated variables.
+//!
+//! The files of each folder must be contiguous in `ASSETS`, which an
+//! [`order`](#order) other than `'tree'` may prevent. Such a folder makes the build
+//! fail.
+//!
+//! **Default**: `false`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_folder_functions.rs).
+//!
+//! ## `glob`
+//!
+//! Whether to generate the function `glob` for
+//! [glob matching](#glob-matching). This needs the crate feature `glob`. The
+//! generated items are:
+//!
+//! - `glob(patterns)`, the function itself.
+//! - `Assets`, the unit struct of [generic access](#generic-access), which the
+//!   function uses even without [`embedded_tree = true`](#embedded_tree).
+//!
+//! **Default**: `false`
+//!
+//! ## `include_folders`
+//!
+//! Whether to also include folders that are matched by the
+//! [`paths` configuration](#paths) on their own, even if they contain no selected
+//! files. For instance, with `paths = '/assets/**'`, each folder under `assets` is
+//! included, while `assets` itself is not.
+//!
+//! Included folders are part of the [folder tree](#folder-tree), the
+//! [identifier modules](#templateidentifiers), and the input of
+//! [visitors](#template-visitors), where empty folders are passed to a
+//! `visit_empty_folder` macro if configured. Additionally, an array
+//! `FOLDERS: [iftree::runtime::FolderInfo; N]` lists all folders below the base
+//! folder in depth-first order, each with its `relative_path`, `folder_count`
+//! (immediate subfolders), and `file_count` (immediate files).
+//!
+//! **Default**: `false`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_include_folders.rs).
+//!
+//! ## `lookup`
+//!
+//! Options to look up files by sloppy paths, like `"/Images/Logo.PNG"` for a file
+//! `images/logo.png`:
+//!
+//! - `case_insensitive`: Whether to ignore case, which maps paths to lowercase.
+//!   If two files collide this way, the build fails.
+//! - `normalize`: Whether to drop empty components (as in `"a//b"` or `"/a"`) and
+//!   `"."` components, and to reject paths with `".."` components.
+//!
+//! If any option is enabled, a function
+//! `resolve_path(relative_path: &str) -> Option<&'static str>` is generated that
+//! returns the relative path of the matching file. The lookup keys are computed at
+//! compile time, so `resolve_path` only processes the requested path before a
+//! binary search. Also, the function `get_by_path` of
+//! [`assets_by_path`](#assets_by_path) resolves paths like this. To apply these
+//! options to `get_asset` of [`dev_mode`](#dev_mode), call it like
+//! `get_asset(resolve_path(relative_path)?)`.
+//!
+//! **Default**: all options are `false`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_lookup.rs).
+//!
+//! ## `obfuscate`
+//!
+//! Options to embed the contents of some files encrypted, so they cannot be
+//! extracted from the binary with tools like `strings`:
+//!
+//! ```toml
+//! [obfuscate]
+//! paths = '/licenses/**'
+//! key_env = 'ASSET_KEY'
+//! ```
+//!
+//! - `paths`: Path patterns of the files to obfuscate. They work like in the
+//!   [`validate` configuration](#validate). This is required.
+//! - `key_env`: Name of an environment variable with the key, read when building.
+//!   This is required.
+//!
+//! The matched files are encrypted with ChaCha20 at compile time, using a key
+//! derived from the SHA-256 hash of the environment variable. In release builds,
+//! the [standard fields](#standard-fields) `get_bytes` and `get_str` decrypt them
+//! on each call.
+//!
+//! This is obfuscation rather than protection of secrets, as the key is embedded
+//! in the binary, too.
+//!
+//! As the plaintext must not be embedded, the standard fields `contents_bytes`,
+//! `contents_str`, `get_decompressed`, `get_decompressed_str`, and `parsed_…` are
+//! not supported if any file is obfuscated. Neither are
+//! [`deduplicate`](#deduplicate), `storage = 'packed'`, [`dev_mode`](#dev_mode),
+//! and [`overlay_env`](#overlay_env). Obfuscated files are not served by the
+//! generated `service`. With a [`template.initializer`](#templateinitializer)
+//! macro, you are responsible to not include obfuscated files yourself.
+//!
+//! This requires the crate feature `obfuscate`.
+//!
+//! **Default**: No files are obfuscated.
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_obfuscate.rs).
+//!
+//! ## `order`
+//!
+//! The order of the `ASSETS` array, which also determines the index of each file:
+//!
+//! - `'tree'`: Files are ordered like a depth-first walk over the folder tree, with
+//!   the entries of each folder ordered by name. For instance, `a/b` comes before
+//!   `a.txt` because folder `a` comes before file `a.txt`.
+//! - `'path'`: Files are ordered by their relative paths as strings. For instance,
+//!   `a.txt` comes before `a/b` because `.` comes before `/`.
+//! - `'pattern'`: Files are ordered by the first line of the
+//!   [`paths` configuration](#paths) that matches them on its own, in the order
+//!   these lines are declared. Ties are broken by tree order. This is useful for
+//!   bundles that depend on a certain order, like concatenated stylesheets.
+//!
+//! Custom [visitors](#template-visitors) still visit the files in tree order, but
+//! they are passed indices according to this order.
+//!
+//! **Default**: `'tree'`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_order.rs).
+//!
+//! ## `overlay_env`
+//!
+//! An environment variable that names an overlay folder whose files take
+//! precedence over the embedded files at runtime. This lets operators customize
+//! some files of a self-contained binary without a rebuild.
+//!
+//! This generates the functions `get_asset`, `asset_relative_paths`, and
+//! `iter_assets` as described for [`dev_mode`](#dev_mode). Given a relative path
+//! like `"my_assets/style.css"`, `get_asset` first looks for the file at this path
+//! in the overlay folder. Otherwise, it falls back to the embedded contents (or the
+//! files on disk in [development mode](#dev_mode)).
+//!
+//! Files in the overlay folder are found even if they are not selected by
+//! [`paths`](#paths), but `asset_relative_paths` and `iter_assets` still list only
+//! the selected files. Paths that would leave the overlay folder, like via `..` or
+//! symbolic links, are rejected. If the environment variable is unset or empty,
+//! the overlay is ignored.
+//!
+//! Note that `ASSETS` and the `base` module always refer to the embedded files.
+//!
+//! **Default**: none
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_overlay_env.rs).
+//!
 //! ## `paths`
 //!
-//! A string with a path pattern per line to filter files.
+//! A string with a path pattern per line to filter files. Alternatively, an array
+//! with a pattern per item like `['/assets/**', '!*.bak']` works the same. This
+//! holds for every option with path patterns.
 //!
 //! It works like a `.gitignore` file with inverted meaning:
 //!
@@ -405,6 +894,45 @@
 //!
 //! **Default**: `"CARGO_MANIFEST_DIR"`
 //!
+//! ## `serve`
+//!
+//! Whether to generate the function `service` and the array `SERVED_FILES` for
+//! [serving files](#serving-files). This needs the crate feature `tower`.
+//!
+//! **Default**: `false`
+//!
+//! ## `storage`
+//!
+//! How file contents are embedded:
+//!
+//! - `'files'`: Each file is included separately.
+//! - `'packed'`: All selected files are concatenated at compile time into a single
+//!   private static variable `BLOB`. The [standard fields](#standard-fields)
+//!   `contents_bytes`, `contents_str`, `get_bytes`, and `get_str` then refer to
+//!   slices of it. With many small files, this can speed up compilation and
+//!   reduce the number of symbols.
+//!
+//! For packed storage, an optional `alignment` (a power of two) can be given, in
+//! which case the contents of each file start at a multiple of this alignment:
+//!
+//! ```toml
+//! storage = 'packed'
+//! alignment = 16
+//! ```
+//!
+//! If combined with [deduplication](#deduplicate), identical contents are packed
+//! only once. The debug information of [`debug`](#debug) leaves out the contents
+//! of `BLOB`.
+//!
+//! Packed files are not included one by one, so the compiler does not notice when
+//! they change. To regenerate the code on changes, use a
+//! [build script](#build-scripts), which tracks the selected files.
+//!
+//! **Default**: `'files'`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_storage.rs).
+//!
 //! ## `template.identifiers`
 //!
 //! Whether to generate an identifier per file.
@@ -415,6 +943,9 @@
 //!
 //! Each variable is a reference to the corresponding element of the `ASSETS` array.
 //!
+//! With [`folder_functions`](#folder_functions), each of these modules also
+//! contains functions `all()` and `path()`.
+//!
 //! Generated identifiers are subject to [name sanitization](#name-sanitization).
 //! Because of this, two files may map to the same identifier, causing an error
 //! about a name being defined multiple times. The code generation does not try to
@@ -437,6 +968,9 @@
 //! 1. Relative file path as a string literal. Path components are separated by `/`.
 //! 1. Absolute file path as a string literal.
 //!
+//! Other arguments can be configured with
+//! [`template.initializer_arguments`](#templateinitializer_arguments).
+//!
 //! As an output, the macro must return a
 //! [constant expression](https://doc.rust-lang.org/reference/const_eval.html#constant-expressions).
 //!
@@ -446,6 +980,27 @@
 //! See
 //! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_template_initializer.rs).
 //!
+//! ## `template.initializer_arguments`
+//!
+//! The arguments passed to the [`template.initializer` macro](#templateinitializer),
+//! in the given order. Each argument is one of these:
+//!
+//! - `'name'`: Filename as a string literal.
+//! - `'id'`: [Sanitized](#name-sanitization) filename as an identifier.
+//! - `'index'`: Zero-based index of the file among the selected files as a `usize`
+//!   literal, according to the [`order` configuration](#order).
+//! - `'relative_path'`: Relative file path as a string literal. Path components
+//!   are separated by `/`.
+//! - `'absolute_path'`: Absolute file path as a string literal.
+//! - `'size'`: File size in bytes as a `usize` literal.
+//!
+//! This requires `template.initializer` to be configured, too.
+//!
+//! **Default**: `['relative_path', 'absolute_path']`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_template_initializer_arguments.rs).
+//!
 //! ## `template` visitors
 //!
 //! This is the most flexible customization of the code generation process.
@@ -473,16 +1028,41 @@
 //! - `visit_file`:
 //!   1. Filename as a string literal.
 //!   1. [Sanitized](#name-sanitization) filename as an identifier.
-//!   1. Zero-based index of the file among the selected files as a `usize` literal.
+//!   1. Zero-based index of the file among the selected files as a `usize` literal,
+//!      according to the [`order` configuration](#order).
 //!   1. Relative file path as a string literal. Path components are separated by
 //!      `/`.
 //!   1. Absolute file path as a string literal.
 //!
+//! With `extended_inputs = true`, the macros are passed further inputs, inserted
+//! before the outputs of the visitor (or appended, for `visit_file`):
+//!
+//! - `visit_base`:
+//!   1. Index range of all selected files as a `usize` range expression like
+//!      `0usize..3usize`.
+//! - `visit_folder`:
+//!   1. Depth of the folder as a `usize` literal, where the entries of the base
+//!      folder have depth 0.
+//!   1. Relative folder path as a string literal. Path components are separated by
+//!      `/`.
+//!   1. Number of files underneath the folder (recursively) as a `usize` literal.
+//!   1. Index range of the files underneath the folder as a `usize` range
+//!      expression. If an [`order`](#order) other than `'tree'` scatters these
+//!      files, the build fails.
+//! - `visit_file`:
+//!   1. Depth of the file as a `usize` literal.
+//!
 //! The `visit_folder` macro is optional. If missing, the outputs of the
 //! `visit_file` calls are directly passed as an input to the `visit_base` call.
 //! This is useful to generate flat structures such as arrays. Similarly, the
 //! `visit_base` macro is optional.
 //!
+//! With [`include_folders`](#include_folders), a folder may be empty. For such a
+//! folder, an optional `visit_empty_folder` macro is called instead of
+//! `visit_folder`, with the folder name as a string literal and the sanitized
+//! folder name as an identifier. With `extended_inputs = true`, these are followed
+//! by the depth and the relative folder path.
+//!
 //! You can configure multiple visitors. They are applied in order.
 //!
 //! To plug in visitors, add this to your configuration for each visitor:
@@ -501,34 +1081,179 @@
 //! - [Basic](https://github.com/evolutics/iftree/blob/main/examples/configuration_template_visitors.rs)
 //! - [Nesting](https://github.com/evolutics/iftree/blob/main/examples/configuration_template_visitors_nesting.rs)
 //! - [Emulation of default code generation](https://github.com/evolutics/iftree/blob/main/examples/configuration_template_visitors_emulation.rs)
+//! - [Extended inputs](https://github.com/evolutics/iftree/blob/main/examples/configuration_template_visitors_extended_inputs.rs)
+//!
+//! ## `text`
+//!
+//! Normalization of text files, so their contents do not depend on how a checkout
+//! converts line endings, for instance.
+//!
+//! ```toml
+//! [text]
+//! paths = '*.md'
+//! normalize_line_endings = 'lf'
+//! strip_bom = true
+//! ```
+//!
+//! These fields are supported:
+//!
+//! - `paths`: Patterns selecting the files to normalize. They work like the
+//!   [`paths` configuration](#paths) but are relative to the
+//!   [base folder](#base_folder). This is required.
+//! - `normalize_line_endings`: Convert all line endings to `'lf'` (`\n`) or
+//!   `'crlf'` (`\r\n`). A lone `\r` is kept as is. By default, line endings are
+//!   not changed.
+//! - `strip_bom`: Whether to remove a UTF-8 byte order mark at the start of a
+//!   file. **Default**: `false`
+//!
+//! Normalization applies to the [standard fields](#standard-fields)
+//! `contents_bytes`, `contents_str`, `get_bytes`, `get_decompressed`,
+//! `get_decompressed_str`, `get_str`, and `parsed_…`.
+//! Instead of including the file, the normalized contents are embedded as a
+//! literal. In debug builds, `get_bytes` and `get_str` normalize what they read
+//! from the file system the same way.
+//!
+//! **Default**: No files are normalized.
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_text.rs).
+//!
+//! ## `tree`
+//!
+//! Whether to generate the static variable `TREE` for navigating the
+//! [folder tree](#folder-tree).
+//!
+//! **Default**: `false`
+//!
+//! ## `validate`
+//!
+//! Checks on the contents of selected files, run at compile time. A file that fails
+//! a check makes the macro expansion fail with an error listing all such files.
+//!
+//! Each check pairs path patterns with either a built-in validator or an external
+//! command:
+//!
+//! ```toml
+//! [[validate]]
+//! paths = '*.json'
+//! validator = 'json'
+//!
+//! [[validate]]
+//! paths = '/shaders/**'
+//! command = ['glslangValidator']
+//! ```
+//!
+//! The `paths` patterns work like the [`paths` configuration](#paths) but are
+//! relative to the [base folder](#base_folder). They only filter among the selected
+//! files.
+//!
+//! The built-in validators are:
+//!
+//! - `utf8`: The file is valid UTF-8.
+//! - `json`: The file is valid JSON. This requires the crate feature
+//!   `format-json`.
+//! - `toml`: The file is valid TOML. This requires the crate feature
+//!   `format-toml`.
+//! - `xml`: The file is well-formed XML. This requires the crate feature
+//!   `format-xml`.
+//!
+//! A `command` is run with the absolute file path appended as last argument. The
+//! check fails if the command exits with a nonzero status, in which case its
+//! standard error output is reported.
+//!
+//! **Default**: `[]`
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_validate.rs).
+//!
+//! ## `web`
+//!
+//! Options to resolve request paths of a website, like a single-page app:
+//!
+//! ```toml
+//! [web]
+//! index_files = ['index.html']
+//! fallback = 'index.html'
+//! trailing_slash = 'redirect'
+//! ```
+//!
+//! - `index_files`: Filenames to look up in order for a folder path like `""` or
+//!   `"docs/"`. Each must be the filename of a selected file.
+//! - `fallback`: The relative path of a selected file to resolve unknown paths to,
+//!   like client-side routes. It must not be affected by [`cfg`](#cfg),
+//!   [`bundles`](#bundles), or [`obfuscate`](#obfuscate).
+//! - `trailing_slash`: How to handle a path like `"docs"` that is a folder with an
+//!   index file or a path like `"a.txt/"` that is a file. With `'ignore'`, the
+//!   trailing slash does not matter. With `'redirect'`, a redirect adds or removes
+//!   the trailing slash.
+//!
+//! The referenced files are checked at compile time, so a misspelled index file or
+//! fallback makes the build fail.
+//!
+//! A static variable `WEB` and a function
+//! `resolve_web_path(path: &str) -> Option<iftree::runtime::WebPath>` are
+//! generated. The result is either a file with its relative path, for example to
+//! pass to `get_by_path`, or a redirect location relative to the request path. The
+//! generated [service](#serving-files) honors these options, too, with redirects
+//! via status 308. Paths are resolved after the [`lookup` configuration](#lookup).
+//!
+//! **Default**: none, with `index_files = []`, no `fallback`, and
+//! `trailing_slash = 'ignore'` if only some options are given
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_web.rs).
+//!
+//! ## `web.cache`
+//!
+//! Options to precompute HTTP caching metadata per file:
+//!
+//! ```toml
+//! [web.cache]
+//! fingerprint = ['*.js', '*.css']
+//!
+//! [[web.cache.rules]]
+//! paths = '*.html'
+//! cache_control = 'no-cache'
+//! ```
+//!
+//! - `fingerprint`: Path patterns of files to fingerprint. The fingerprinted path
+//!   of a file like `app.js` inserts a hash of its contents before the filename
+//!   extension, like `app.3f9a1c2b.js`. As such a path changes with the contents,
+//!   it can be cached forever.
+//! - `rules`: A list of `Cache-Control` policies, each for the files matching its
+//!   `paths` patterns. The first matching rule applies. Otherwise, fingerprinted
+//!   files get `'public, max-age=31536000, immutable'` and others `'no-cache'`.
+//!
+//! Path patterns work like the [`paths` configuration](#paths) but are relative to
+//! the [base folder](#base_folder).
+//!
+//! This metadata is available via the [standard fields](#standard-fields)
+//! `cache_control`, `etag`, and `fingerprinted_path`. These fields can be used
+//! without this configuration, too, with the defaults above.
+//!
+//! Also, an array `FINGERPRINTED_PATHS` of pairs of relative path and
+//! fingerprinted path is generated, sorted by relative path, as well as a function
+//! `get_fingerprinted_path(relative_path: &str) -> Option<&'static str>` to refer to
+//! assets in templates. The generated [service](#serving-files) serves files by
+//! their fingerprinted paths, too, and sets the `Cache-Control` header. If a
+//! fingerprinted file is requested by its plain path, `'no-cache'` is used.
+//!
+//! **Default**: none
+//!
+//! See
+//! [example](https://github.com/evolutics/iftree/blob/main/examples/configuration_web_cache.rs).
 //!
 //! # Further resources
 //!
 //! - [Changelog](https://github.com/evolutics/iftree/blob/main/CHANGELOG.md)
 //! - [Latest revision of this documentation](https://github.com/evolutics/iftree/blob/main/README.md)
 
-mod generate_view;
-mod go;
-mod list_files;
-mod model;
-mod parse;
-mod print;
-
-/// See the [module level documentation](self).
-#[proc_macro_attribute]
-pub fn include_file_tree(
-    parameters: proc_macro::TokenStream,
-    item: proc_macro::TokenStream,
-) -> proc_macro::TokenStream {
-    let configuration = syn::parse_macro_input!(parameters);
-    let item2 = item.clone().into();
-    let type_ = syn::parse_macro_input!(item);
-
-    match go::main(configuration, item2, type_) {
-        Err(error) => panic!("{error}"),
-        Ok(code) => code.into(),
-    }
-}
+#[cfg(feature = "build")]
+pub use iftree_codegen::Builder;
+#[cfg(feature = "build")]
+pub use iftree_codegen::Error as BuildError;
+pub use iftree_macros::include_file_tree;
+pub use iftree_runtime as runtime;
 
 #[cfg(test)]
 mod tests {
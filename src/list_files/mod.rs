@@ -1,6 +0,0 @@
-mod get_base_folder;
-mod get_paths;
-mod get_raw_paths;
-mod main;
-
-pub use main::main;
@@ -1,4 +0,0 @@
-mod error;
-mod main;
-
-pub use main::*;
@@ -1,8 +0,0 @@
-mod count_files;
-mod main;
-mod print_forest;
-mod print_initializer;
-mod print_populator;
-mod print_with_debug;
-
-pub use main::main;
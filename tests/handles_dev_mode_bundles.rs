@@ -0,0 +1,29 @@
+#[iftree::include_file_tree(
+    "
+paths = '/credits.md'
+base_folder = 'examples/assets'
+dev_mode = 'filesystem'
+template.identifiers = false
+
+[bundles.enabled]
+paths = '/configuration/menu.json'
+cfg = 'test'
+
+[bundles.disabled]
+paths = '/world/**'
+cfg = 'not(test)'
+"
+)]
+pub struct Asset;
+
+#[test]
+fn main() {
+    assert!(get_asset("credits.md").is_some());
+    assert!(get_asset("configuration/menu.json").is_some());
+    assert!(get_asset("world/physical_constants.json").is_none());
+
+    assert_eq!(
+        asset_relative_paths(),
+        vec!["configuration/menu.json", "credits.md"],
+    );
+}
@@ -0,0 +1,24 @@
+#[iftree::include_file_tree(
+    "
+paths = '/credits.md'
+base_folder = 'examples/assets'
+dev_mode = 'filesystem'
+template.identifiers = false
+
+[text]
+paths = '*.md'
+normalize_line_endings = 'crlf'
+"
+)]
+pub struct Asset {
+    contents_str: &'static str,
+}
+
+#[test]
+fn main() {
+    assert_eq!(ASSETS[0].contents_str, "Boo Far\r\n");
+    assert_eq!(
+        get_asset("credits.md").as_deref(),
+        Some(&b"Boo Far\r\n"[..])
+    );
+}
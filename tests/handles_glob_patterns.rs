@@ -0,0 +1,35 @@
+#[iftree::include_file_tree(
+    "
+paths = '/examples/assets/**'
+glob = true
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+pub mod selected {
+    #[iftree::include_file_tree(
+        "
+paths = '/examples/assets/{configuration,world}/*'
+"
+    )]
+    pub struct Asset {
+        pub relative_path: &'static str,
+    }
+}
+
+#[test]
+fn main() {
+    let actual = glob("/examples/assets/{configuration,world}/*")
+        .unwrap()
+        .map(|asset| asset.relative_path)
+        .collect::<Vec<_>>();
+
+    let expected = selected::ASSETS
+        .iter()
+        .map(|asset| asset.relative_path)
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+    assert!(!actual.is_empty());
+}
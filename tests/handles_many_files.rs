@@ -28,3 +28,145 @@ fn main() {
         "g/f/e/d/c/b/a/0",
     );
 }
+
+mod packed {
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    #[iftree::include_file_tree(
+        "
+paths = '**'
+base_folder = 'tests/many_files'
+storage = 'packed'
+alignment = 8
+"
+    )]
+    pub struct Asset {
+        relative_path: &'static str,
+        contents_bytes: &'static [u8],
+        contents_str: &'static str,
+        get_bytes: fn() -> std::borrow::Cow<'static, [u8]>,
+    }
+
+    #[test]
+    fn main() {
+        assert_eq!(ASSETS.len(), super::ASSETS.len());
+
+        let mut offset: usize = 0;
+        for (asset, unpacked_asset) in ASSETS.iter().zip(&super::ASSETS) {
+            assert_eq!(asset.relative_path, unpacked_asset.relative_path);
+            assert_eq!(asset.contents_str, unpacked_asset.contents_str);
+            assert_eq!((asset.get_bytes)(), unpacked_asset.contents_str.as_bytes());
+            assert_eq!(asset.contents_bytes.as_ptr().align_offset(8), 0);
+
+            offset = offset.next_multiple_of(8);
+            let start = asset.contents_bytes.as_ptr() as usize - BLOB.as_ptr() as usize;
+            assert_eq!(start, offset);
+            offset += asset.contents_bytes.len();
+        }
+        assert_eq!(offset, BLOB.len());
+
+        assert_eq!(base::e::c::b::_1.contents_str, "e/c/b/1\n");
+    }
+
+    #[test]
+    fn handles_thousands_of_files_correctly() {
+        let dependent = arrange();
+
+        let status = process::Command::new("cargo")
+            .current_dir(dependent.path())
+            .arg("run")
+            .status()
+            .unwrap();
+
+        assert!(status.success());
+        dependent.close().unwrap();
+    }
+
+    const FILE_COUNT: usize = 5000;
+
+    fn arrange() -> tempfile::TempDir {
+        let dependent = tempfile::tempdir().unwrap();
+        arrange_manifest(&dependent);
+        arrange_assets(&dependent);
+        arrange_source(&dependent);
+        dependent
+    }
+
+    fn arrange_manifest(dependent: &tempfile::TempDir) {
+        let dependency = env::var("CARGO_MANIFEST_DIR").unwrap();
+        let contents = format!(
+            "[package]
+name = 'dependent'
+version = '0.1.0'
+edition = '2021'
+
+[dependencies]
+iftree = {{ path = {dependency:?} }}",
+        );
+        fs::write(dependent.path().join("Cargo.toml"), contents).unwrap();
+    }
+
+    fn arrange_assets(dependent: &tempfile::TempDir) {
+        for index in 0..FILE_COUNT {
+            let folder = dependent
+                .path()
+                .join("assets")
+                .join((index / 100).to_string());
+            fs::create_dir_all(&folder).unwrap();
+            // Contents are unique and of varying length to exercise alignment.
+            let contents = format!("{index}\n").repeat(index % 7 + 1);
+            fs::write(folder.join(format!("{index}.txt")), contents).unwrap();
+        }
+    }
+
+    fn arrange_source(dependent: &tempfile::TempDir) {
+        fs::create_dir(dependent.path().join("src")).unwrap();
+        let contents = format!(
+            r#"#[iftree::include_file_tree(
+    "
+paths = '/assets/**'
+storage = 'packed'
+alignment = 8
+debug = true
+"
+)]
+pub struct Asset {{
+    relative_path: &'static str,
+    contents_bytes: &'static [u8],
+    get_bytes: fn() -> std::borrow::Cow<'static, [u8]>,
+}}
+
+mod unpacked {{
+    #[iftree::include_file_tree("paths = '/assets/**'")]
+    pub struct Asset {{
+        pub relative_path: &'static str,
+        pub contents_bytes: &'static [u8],
+    }}
+}}
+
+fn main() {{
+    assert_eq!(ASSETS.len(), {FILE_COUNT});
+
+    let mut offset: usize = 0;
+    for (asset, unpacked_asset) in ASSETS.iter().zip(&unpacked::ASSETS) {{
+        assert_eq!(asset.relative_path, unpacked_asset.relative_path);
+        assert_eq!(asset.contents_bytes, unpacked_asset.contents_bytes);
+        assert_eq!((asset.get_bytes)(), unpacked_asset.contents_bytes);
+
+        offset = offset.next_multiple_of(8);
+        let start = asset.contents_bytes.as_ptr() as usize - BLOB.as_ptr() as usize;
+        assert_eq!(start, offset);
+        offset += asset.contents_bytes.len();
+    }}
+    assert_eq!(offset, BLOB.len());
+
+    assert!(DEBUG.contains("static BLOB"));
+    assert!(!DEBUG.contains("4999\\n4999\\n"));
+}}
+"#,
+        );
+        fs::write(dependent.path().join("src").join("main.rs"), contents).unwrap();
+    }
+}
@@ -0,0 +1,22 @@
+#[iftree::include_file_tree(
+    "
+paths = '**'
+base_folder = 'tests/reserved_names'
+folder_functions = true
+"
+)]
+pub struct Asset {
+    relative_path: &'static str,
+}
+
+#[test]
+fn main() {
+    assert_eq!(base::ALL.relative_path, "all");
+    assert_eq!(base::PATH.relative_path, "path");
+    assert_eq!(base::sub::ALL.relative_path, "sub/all");
+
+    assert_eq!(base::all().len(), 3);
+    assert_eq!(base::path(), "");
+    assert_eq!(base::sub::all().len(), 1);
+    assert_eq!(base::sub::path(), "sub");
+}
@@ -0,0 +1,86 @@
+use std::env;
+use std::fs;
+use std::process;
+
+#[test]
+fn main() {
+    let dependent = arrange();
+
+    let status = process::Command::new("cargo")
+        .current_dir(dependent.path())
+        .arg("run")
+        .status()
+        .unwrap();
+
+    assert!(status.success());
+
+    let output = process::Command::new("cargo")
+        .current_dir(dependent.path())
+        .args(["build", "--verbose"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Fresh dependent"), "{stderr}");
+    dependent.close().unwrap();
+}
+
+fn arrange() -> tempfile::TempDir {
+    let dependent = tempfile::tempdir().unwrap();
+    arrange_manifest(&dependent);
+    arrange_build_script(&dependent);
+    arrange_source(&dependent);
+    dependent
+}
+
+fn arrange_manifest(dependent: &tempfile::TempDir) {
+    let dependency = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let contents = format!(
+        "[package]
+name = 'dependent'
+version = '0.1.0'
+edition = '2021'
+
+[dependencies]
+iftree = {{ path = {dependency:?} }}
+
+[build-dependencies]
+iftree = {{ path = {dependency:?}, features = ['build'] }}",
+    );
+    fs::write(dependent.path().join("Cargo.toml"), contents).unwrap();
+}
+
+fn arrange_build_script(dependent: &tempfile::TempDir) {
+    fs::write(
+        dependent.path().join("build.rs"),
+        "use std::env;
+use std::path;
+
+fn main() {
+    let path = path::Path::new(&env::var(\"OUT_DIR\").unwrap()).join(\"assets.rs\");
+    iftree::Builder::from_toml(
+        \"paths = '/src/**'\",
+        \"pub struct Asset { relative_path: &'static str, contents_str: &'static str }\",
+    )
+    .generate_to(path)
+    .unwrap();
+}",
+    )
+    .unwrap();
+}
+
+fn arrange_source(dependent: &tempfile::TempDir) {
+    fs::create_dir(dependent.path().join("src")).unwrap();
+    fs::write(
+        dependent.path().join("src").join("main.rs"),
+        "include!(concat!(env!(\"OUT_DIR\"), \"/assets.rs\"));
+
+fn main() {
+    assert_eq!(ASSETS.len(), 1);
+    assert_eq!(base::src::MAIN_RS.relative_path, \"src/main.rs\");
+    assert_eq!(base::src::MAIN_RS.contents_str, include_str!(\"main.rs\"));
+}",
+    )
+    .unwrap();
+}
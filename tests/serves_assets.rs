@@ -0,0 +1,128 @@
+use std::net;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+
+#[iftree::include_file_tree(
+    "
+paths = '**'
+base_folder = 'tests/served_files'
+serve = true
+template.identifiers = false
+"
+)]
+pub struct Asset;
+
+pub mod web {
+    #[iftree::include_file_tree(
+        "
+paths = '**'
+base_folder = 'tests/served_files'
+serve = true
+template.identifiers = false
+
+[web]
+index_files = ['index.html']
+fallback = 'index.html'
+trailing_slash = 'redirect'
+
+[web.cache]
+fingerprint = ['*.css']
+"
+    )]
+    pub struct Asset;
+}
+
+#[tokio::test]
+async fn main() {
+    let address = serve(service()).await;
+
+    let response = request(address, "GET /static/index.html", &[]).await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("\r\ncontent-type: text/html\r\n"));
+    assert!(response.contains("\r\nlast-modified: "));
+    assert!(response.ends_with("\r\n\r\n<!doctype html>\n<title>Home</title>\n"));
+
+    let etag = get_header(&response, "etag");
+    let response = request(
+        address,
+        "GET /static/index.html",
+        &[("If-None-Match", &etag)],
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 304 Not Modified\r\n"));
+
+    let response = request(address, "GET /static/style.css", &[("Range", "bytes=0-3")]).await;
+    assert!(response.starts_with("HTTP/1.1 206 Partial Content\r\n"));
+    assert!(response.contains("\r\ncontent-range: bytes 0-3/22\r\n"));
+    assert!(response.ends_with("\r\n\r\nbody"));
+
+    let response = request(
+        address,
+        "GET /static/style.css",
+        &[("Accept-Encoding", "gzip, deflate")],
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("\r\ncontent-type: text/css\r\n"));
+    assert!(response.contains("\r\ncontent-encoding: gzip\r\n"));
+    assert!(response.contains("\r\nvary: Accept-Encoding\r\n"));
+
+    let response = request(address, "GET /static/missing.css", &[]).await;
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+}
+
+#[tokio::test]
+async fn handles_web() {
+    let address = serve(web::service()).await;
+
+    let response = request(address, "GET /static/", &[]).await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.ends_with("\r\n\r\n<!doctype html>\n<title>Home</title>\n"));
+
+    let response = request(address, "GET /static/app/route", &[]).await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("\r\ncontent-type: text/html\r\n"));
+
+    let response = request(address, "GET /static/style.css/", &[]).await;
+    assert!(response.starts_with("HTTP/1.1 308 Permanent Redirect\r\n"));
+    assert_eq!(get_header(&response, "location"), "../style.css");
+
+    let path = web::get_fingerprinted_path("style.css").unwrap();
+    let response = request(address, &format!("GET /static/{path}"), &[]).await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert_eq!(
+        get_header(&response, "cache-control"),
+        "public, max-age=31536000, immutable",
+    );
+}
+
+async fn serve(service: iftree::runtime::ServeAssets) -> net::SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let app = axum::Router::new().nest_service("/static", service);
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    address
+}
+
+async fn request(address: net::SocketAddr, request_line: &str, headers: &[(&str, &str)]) -> String {
+    let mut request =
+        format!("{request_line} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n");
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    request.push_str("\r\n");
+
+    let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = vec![];
+    stream.read_to_end(&mut response).await.unwrap();
+    String::from_utf8_lossy(&response).into()
+}
+
+fn get_header(response: &str, name: &str) -> String {
+    response
+        .lines()
+        .find_map(|line| line.strip_prefix(&format!("{name}: ")))
+        .unwrap()
+        .into()
+}